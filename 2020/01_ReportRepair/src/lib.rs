@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+const TARGET: usize = 2020;
+
+/// Some entries of a report that add up to a target, in the order they appear.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct KSum {
+    pub indices: Vec<usize>,
+    pub values: Vec<usize>,
+}
+impl KSum {
+    pub fn product(&self) -> usize {
+        self.values.iter().product()
+    }
+}
+
+/// Every way to pick `k` entries of `report` that add up to `target`, ordered by their indices.
+///
+/// Entries are grouped by value first, so the search runs over distinct values rather than
+/// lines: no more than `target+1` of them can be part of an answer however long the report is.
+/// Sets of values that work are found by fixing all but the last two and closing in on those
+/// from either end of the sorted values, then expanded back into the entries that hold them.
+pub fn find_k_sum(report: &[usize], k: usize, target: usize) -> Vec<KSum> {
+    let mut entries = HashMap::<usize, Vec<usize>>::new();
+
+    for (index, &value) in report.iter().enumerate() {
+        if value <= target { entries.entry(value).or_insert_with(Vec::<usize>::new).push(index); }
+    }
+
+    let mut values: Vec<(usize, usize)> = entries.iter().map(|(&value, indices)| (value, indices.len())).collect();
+    values.sort();
+
+    let mut sets = Vec::<Vec<usize>>::new();
+    find_values(&values, 0, k, target, &mut Vec::<usize>::new(), &mut sets);
+
+    let mut result = Vec::<KSum>::new();
+
+    for set in sets {
+        // each distinct value with how many times it's used, which are next to each other
+        let mut groups = Vec::<(usize, usize)>::new();
+
+        for &i in &set {
+            match groups.last_mut() {
+                Some(last) if last.0 == values[i].0 => last.1 += 1,
+                _ => groups.push((values[i].0, 1)),
+            }
+        }
+
+        expand(report, &entries, &groups, &mut Vec::<usize>::new(), &mut result);
+    }
+
+    result.sort_by(|a, b| a.indices.cmp(&b.indices));
+    result
+}
+
+/// Collects every non-decreasing list of `k` positions in `values`, from `start` on and with each
+/// used no more often than its value appears, whose values add up to `target`.
+fn find_values(values: &[(usize, usize)], start: usize, k: usize, target: usize, chosen: &mut Vec<usize>, sets: &mut Vec<Vec<usize>>) {
+    // how many more times a value can be picked, given that only the latest value chosen can
+    // have been picked already
+    let available = |chosen: &Vec<usize>, i: usize| values[i].1 - chosen.iter().rev().take_while(|&&x| x == i).count();
+
+    match k {
+        0 => if target == 0 { sets.push(chosen.clone()); },
+        1 => {
+            if let Ok(i) = values[start.min(values.len())..].binary_search_by(|x| x.0.cmp(&target)) {
+                if available(chosen, start+i) > 0 { sets.push(with(chosen, &[start+i])); }
+            }
+        },
+        2 => {
+            if start >= values.len() { return; }
+
+            let (mut low, mut high) = (start, values.len()-1);
+
+            while low <= high {
+                let sum = values[low].0 + values[high].0;
+
+                if sum <= target {
+                    if sum == target && low < high && available(chosen, low) > 0 { sets.push(with(chosen, &[low, high])); }
+                    if sum == target && low == high && available(chosen, low) > 1 { sets.push(with(chosen, &[low, low])); }
+
+                    low += 1;
+                }
+                else if high == 0 { break; }
+                else { high -= 1; }
+            }
+        },
+        _ => {
+            for i in start..values.len() {
+                // everything after this is at least as big
+                if values[i].0.saturating_mul(k) > target { break; }
+                if available(chosen, i) == 0 { continue; }
+
+                chosen.push(i);
+                find_values(values, i, k-1, target - values[i].0, chosen, sets);
+                chosen.pop();
+            }
+        },
+    }
+}
+
+fn with(chosen: &[usize], picked: &[usize]) -> Vec<usize> {
+    let mut set = chosen.to_vec();
+    set.extend_from_slice(picked);
+    set
+}
+
+/// Turns a set of values, each with how many times it's used, into every choice of entries
+/// holding them.
+fn expand(report: &[usize], entries: &HashMap<usize, Vec<usize>>, groups: &[(usize, usize)], picked: &mut Vec<usize>, result: &mut Vec<KSum>) {
+    let (value, count) = match groups.first() {
+        Some(&group) => group,
+        None => {
+            let mut indices = picked.clone();
+            indices.sort();
+
+            let values = indices.iter().map(|&x| report[x]).collect();
+            result.push(KSum { indices, values });
+            return;
+        },
+    };
+
+    let indices = &entries[&value];
+    let mut choice: Vec<usize> = (0..count).collect();
+
+    // every `count` of `indices`, a combination at a time
+    loop {
+        picked.extend(choice.iter().map(|&x| indices[x]));
+        expand(report, entries, &groups[1..], picked, result);
+        picked.truncate(picked.len()-count);
+
+        match (0..count).rev().find(|&i| choice[i] < indices.len()-count+i) {
+            Some(i) => {
+                choice[i] += 1;
+                for j in i+1..count { choice[j] = choice[j-1]+1; }
+            },
+            None => break,
+        }
+    }
+}
+
+fn read_report(input: &mut Reader) -> Result<Vec<usize>, ParseError> {
+    let mut result = Vec::<usize>::new();
+
+    while let Some(line) = input.next_line() {
+        result.push(line.parse(line.text(), "an expense entry")?);
+    }
+
+    if result.len() == 0 { Err(input.end("an expense entry")) }
+    else { Ok(result) }
+}
+
+pub struct ReportRepair;
+impl Solution for ReportRepair {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_report(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(report: &Self::Input) -> Answer {
+        find_k_sum(report, 2, TARGET).first().map_or(Answer::None, |x| x.product().into())
+    }
+    fn part2(report: &Self::Input) -> Answer {
+        find_k_sum(report, 3, TARGET).first().map_or(Answer::None, |x| x.product().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1721
+979
+366
+299
+675
+1456
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(ReportRepair::solve(EXAMPLE, 1), Ok(Answer::from(514579)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(ReportRepair::solve(EXAMPLE, 2), Ok(Answer::from(241861950)));
+    }
+    #[test]
+    fn every_combination() {
+        let report = [5, 3, 2, 5, 0, 7, 3, 1, 5, 8, 2, 4, 6, 3, 9, 1, 10, 5];
+
+        for k in 0..=6 {
+            for target in 0..=25 {
+                let mut expected = Vec::<KSum>::new();
+
+                for mask in 0..1u32 << report.len() {
+                    if mask.count_ones() as usize != k { continue; }
+
+                    let indices: Vec<usize> = (0..report.len()).filter(|&i| mask & 1 << i != 0).collect();
+                    let values: Vec<usize> = indices.iter().map(|&i| report[i]).collect();
+
+                    if values.iter().sum::<usize>() == target { expected.push(KSum { indices, values }); }
+                }
+
+                expected.sort_by(|a, b| a.indices.cmp(&b.indices));
+
+                assert_eq!(find_k_sum(&report, k, target), expected, "k = {}, target = {}", k, target);
+            }
+        }
+    }
+    #[test]
+    fn long_reports() {
+        // 1000 and any of the 200 copies of 1020 are the only pairs, and nothing smaller than
+        // 1011 is left to make up anything bigger
+        let mut report: Vec<usize> = (0..200_000).map(|i| 1011 + i % 1000).collect();
+        report[12345] = 1000;
+
+        let pairs = find_k_sum(&report, 2, TARGET);
+
+        assert_eq!(pairs.len(), 200);
+        assert!(pairs.iter().all(|x| x.values.contains(&1000) && x.values.contains(&1020)));
+
+        for k in 3..=6 { assert_eq!(find_k_sum(&report, k, TARGET), vec![]); }
+    }
+}
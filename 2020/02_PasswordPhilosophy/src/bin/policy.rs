@@ -0,0 +1,47 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use common::Solution;
+use passwordphilosophy::{policy_named, PasswordPhilosophy};
+
+const USAGE: &str = "usage: policy <policy> [<passwords>]
+    <policy> is count or positions, combined with ! (not), & (and) and | (or), like 'count&!positions'
+    <passwords> defaults to this day's input.txt";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.len() == 0 || args.len() > 2 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let policy = policy_named(&args[0]).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(2);
+    });
+
+    let path = args.get(1).map_or(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input.txt"), PathBuf::from);
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("couldn't open {}: {}", path.display(), e);
+        process::exit(1);
+    });
+
+    let entries = PasswordPhilosophy::parse(&text).unwrap_or_else(|e| {
+        eprintln!("{}: {}", path.display(), e);
+        process::exit(1);
+    });
+
+    let mut accepted = 0;
+
+    for entry in &entries {
+        match policy.check(entry) {
+            Ok(_) => accepted += 1,
+            Err(report) => println!("{}: {}", entry.to_string(), report),
+        }
+    }
+
+    println!("{} of {} passwords accepted", accepted, entries.len());
+}
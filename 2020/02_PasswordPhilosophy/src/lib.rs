@@ -0,0 +1,106 @@
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+mod policy;
+pub use policy::{policy_named, And, Count, Not, Or, Policy, Positions};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Range(pub usize, pub usize);
+impl Range {
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let (low, high) = line.split_once(s, "-", "a range like 1-3")?;
+
+        Ok(Self(line.parse(low, "a number")?, line.parse(high, "a number")?))
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PolicyCheck {
+    pub range: Range,
+    pub character: char,
+    pub password: String,
+}
+impl PolicyCheck {
+    pub fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let (policy, password) = line.split_once(s, ": ", "a policy and password like 1-3 a: abcde")?;
+        let (range, character) = line.split_once(policy, " ", "a policy like 1-3 a")?;
+
+        let range = Range::from_str(range, line)?;
+        let mut chars = character.chars();
+
+        let character = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return Err(line.error(character, "a single character")),
+        };
+
+        Ok(Self { range, character, password: password.to_string() })
+    }
+    pub fn to_string(&self) -> String {
+        format!("{}-{} {}: {}", self.range.0, self.range.1, self.character, self.password)
+    }
+}
+
+fn read_policies(input: &mut Reader) -> Result<Vec<PolicyCheck>, ParseError> {
+    let mut policies = Vec::<PolicyCheck>::new();
+
+    while let Some(line) = input.next_line() {
+        policies.push(PolicyCheck::from_str(line.text(), &line)?);
+    }
+
+    if policies.len() == 0 { Err(input.end("a password policy")) }
+    else { Ok(policies) }
+}
+
+pub struct PasswordPhilosophy;
+impl Solution for PasswordPhilosophy {
+    type Input = Vec<PolicyCheck>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_policies(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(policies: &Self::Input) -> Answer {
+        policies.iter().filter(|x| Count.accepts(x)).count().into()
+    }
+    fn part2(policies: &Self::Input) -> Answer {
+        policies.iter().filter(|x| Positions.accepts(x)).count().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+";
+
+    #[test]
+    fn malformed_policy() {
+        let error = PasswordPhilosophy::parse("1-3 a: abcde\n1-x b: cdefg\n").unwrap_err();
+
+        assert_eq!(error.to_string(), "line 2, column 3: expected a number, found \"x\"");
+    }
+    #[test]
+    fn part1_example() {
+        assert_eq!(PasswordPhilosophy::solve(EXAMPLE, 1), Ok(Answer::from(2)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(PasswordPhilosophy::solve(EXAMPLE, 2), Ok(Answer::from(1)));
+    }
+    #[test]
+    fn explanations() {
+        let entries = PasswordPhilosophy::parse(EXAMPLE).unwrap();
+        let reports = |name: &str| entries.iter().map(|x| policy_named(name).unwrap().check(x)).collect::<Vec<_>>();
+
+        assert_eq!(reports("count")[1], Err("char 'b' appears 0 times, need 1..=3".to_string()));
+        assert_eq!(reports("positions")[2], Err("positions 2 and 9 hold 'c' and 'c', need exactly one 'c'".to_string()));
+        assert_eq!(reports("count & !positions"), vec![
+            Err("not: positions 1 and 3 hold 'a' and 'c', need exactly one 'a'".to_string()),
+            Err("char 'b' appears 0 times, need 1..=3".to_string()),
+            Ok("char 'c' appears 9 times, need 2..=9 and not: positions 2 and 9 hold 'c' and 'c', need exactly one 'c'".to_string()),
+        ]);
+        assert_eq!(reports("positions|!!count").iter().filter(|x| x.is_ok()).count(), 2);
+        assert_eq!(policy_named("count&length").err(), Some("unknown policy \"length\", expected count or positions".to_string()));
+    }
+}
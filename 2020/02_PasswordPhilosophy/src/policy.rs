@@ -0,0 +1,113 @@
+use super::PolicyCheck;
+
+/// A rule for which passwords an entry's policy allows, and why.
+pub trait Policy {
+    /// `Ok` with why the entry's password is allowed, or `Err` with why it isn't.
+    fn check(&self, entry: &PolicyCheck) -> Result<String, String>;
+    fn accepts(&self, entry: &PolicyCheck) -> bool {
+        self.check(entry).is_ok()
+    }
+}
+
+/// The sled rental place's policy: the character appears as many times as the range allows.
+pub struct Count;
+impl Policy for Count {
+    fn check(&self, entry: &PolicyCheck) -> Result<String, String> {
+        let count = entry.password.chars().filter(|&x| x == entry.character).count();
+        let report = format!("char {:?} appears {} times, need {}..={}", entry.character, count, entry.range.0, entry.range.1);
+
+        if entry.range.0 <= count && count <= entry.range.1 { Ok(report) }
+        else { Err(report) }
+    }
+}
+
+/// The toboggan rental place's policy: exactly one of the two positions, counting from 1, holds
+/// the character.
+pub struct Positions;
+impl Policy for Positions {
+    fn check(&self, entry: &PolicyCheck) -> Result<String, String> {
+        let at = |position: usize| if position == 0 { None } else { entry.password.chars().nth(position-1) };
+        let (first, second) = (at(entry.range.0), at(entry.range.1));
+        let show = |c: Option<char>| c.map_or("nothing".to_string(), |x| format!("{:?}", x));
+        let report = format!("positions {} and {} hold {} and {}, need exactly one {:?}",
+                             entry.range.0, entry.range.1, show(first), show(second), entry.character);
+
+        if (first == Some(entry.character)) != (second == Some(entry.character)) { Ok(report) }
+        else { Err(report) }
+    }
+}
+
+/// Every policy allows the password.
+pub struct And(pub Vec<Box<dyn Policy>>);
+impl Policy for And {
+    fn check(&self, entry: &PolicyCheck) -> Result<String, String> {
+        let (passed, failed) = partition(&self.0, entry);
+
+        if failed.len() == 0 { Ok(passed.join(" and ")) }
+        else { Err(failed.join(" and ")) }
+    }
+}
+
+/// At least one policy allows the password.
+pub struct Or(pub Vec<Box<dyn Policy>>);
+impl Policy for Or {
+    fn check(&self, entry: &PolicyCheck) -> Result<String, String> {
+        let (passed, failed) = partition(&self.0, entry);
+
+        if passed.len() > 0 { Ok(passed.join(" or ")) }
+        else { Err(failed.join(" and ")) }
+    }
+}
+
+/// The policy doesn't allow the password.
+pub struct Not(pub Box<dyn Policy>);
+impl Policy for Not {
+    fn check(&self, entry: &PolicyCheck) -> Result<String, String> {
+        match self.0.check(entry) {
+            Ok(report) => Err(format!("not: {}", report)),
+            Err(report) => Ok(format!("not: {}", report)),
+        }
+    }
+}
+
+fn partition(policies: &[Box<dyn Policy>], entry: &PolicyCheck) -> (Vec<String>, Vec<String>) {
+    let mut passed = Vec::<String>::new();
+    let mut failed = Vec::<String>::new();
+
+    for policy in policies {
+        match policy.check(entry) {
+            Ok(report) => passed.push(report),
+            Err(report) => failed.push(report),
+        }
+    }
+
+    (passed, failed)
+}
+
+/// A policy by name: `count` or `positions`, with `!` in front to negate one, `&` to require
+/// several and `|` to allow any of several, like `count&!positions|positions`. `&` binds tighter.
+pub fn policy_named(name: &str) -> Result<Box<dyn Policy>, String> {
+    let mut any = Vec::<Box<dyn Policy>>::new();
+
+    for term in name.split('|') {
+        let mut all = Vec::<Box<dyn Policy>>::new();
+
+        for factor in term.split('&') {
+            let factor = factor.trim();
+            let negations = factor.chars().take_while(|&x| x == '!').count();
+            let base = factor[negations..].trim();
+
+            let policy: Box<dyn Policy> = match base {
+                "count" => Box::new(Count),
+                "positions" => Box::new(Positions),
+                _ => return Err(format!("unknown policy {:?}, expected count or positions", base)),
+            };
+
+            all.push(if negations % 2 == 1 { Box::new(Not(policy)) } else { policy });
+        }
+
+        any.push(if all.len() == 1 { all.pop().unwrap() } else { Box::new(And(all)) });
+    }
+
+    Ok(if any.len() == 1 { any.pop().unwrap() } else { Box::new(Or(any)) })
+}
@@ -0,0 +1,159 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Edges, Grid};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Terrain {
+    Snow,
+    Tree,
+}
+impl Terrain {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Self::Tree),
+            '.' => Some(Self::Snow),
+            _ => None,
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Self::Tree => '#',
+            Self::Snow => '.',
+        }
+    }
+}
+
+/// The slopes with the fewest and the most trees in the way, and how many that is.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Survey {
+    pub fewest_trees: usize,
+    pub fewest: Vec<(usize, usize)>,
+    pub most_trees: usize,
+    pub most: Vec<(usize, usize)>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Slope {
+    terrain: Grid<Terrain>,
+}
+impl Slope {
+    /// Every cell the toboggan lands on going `right` and `down` at a time from the top left
+    /// corner, until it passes the bottom of the map. Going nowhere goes nowhere.
+    pub fn path(&self, right: usize, down: usize) -> Vec<Coordinate> {
+        let mut position = Coordinate(0,0);
+        let mut path = Vec::<Coordinate>::new();
+
+        if down == 0 { return path; }
+
+        while let Some(next) = self.terrain.offset(position, right as isize, down as isize) {
+            position = next;
+            path.push(position);
+        }
+
+        path
+    }
+    pub fn slope(&self, right: usize, down: usize) -> usize {
+        self.path(right, down).into_iter().filter(|&x| self.terrain[x] == Terrain::Tree).count()
+    }
+    /// Tries every slope going up to `max_right` right and between 1 and `max_down` down. Slopes
+    /// that are multiples of each other land on different cells, so each is tried separately.
+    pub fn survey(&self, max_right: usize, max_down: usize) -> Option<Survey> {
+        let mut survey: Option<Survey> = None;
+
+        for down in 1..=max_down {
+            for right in 0..=max_right {
+                let trees = self.slope(right, down);
+                let survey = survey.get_or_insert_with(|| Survey { fewest_trees: trees, fewest: vec![], most_trees: trees, most: vec![] });
+
+                if trees < survey.fewest_trees { survey.fewest_trees = trees; survey.fewest.clear(); }
+                if trees == survey.fewest_trees { survey.fewest.push((right, down)); }
+                if trees > survey.most_trees { survey.most_trees = trees; survey.most.clear(); }
+                if trees == survey.most_trees { survey.most.push((right, down)); }
+            }
+        }
+
+        survey
+    }
+    /// The map with the cells the toboggan lands on marked, `O` for snow and `X` for trees.
+    pub fn render_path(&self, right: usize, down: usize) -> String {
+        let mut map = self.terrain.map(|x| x.to_char());
+
+        for position in self.path(right, down) {
+            map[position] = match self.terrain[position] {
+                Terrain::Snow => 'O',
+                Terrain::Tree => 'X',
+            };
+        }
+
+        map.render(|&x| x)
+    }
+}
+
+fn read_slope(input: &mut Reader) -> Result<Slope, ParseError> {
+    let terrain = Grid::read(input, "'#' or '.'", Terrain::from_char)?.with_edges(Edges::WrapX);
+
+    Ok(Slope { terrain })
+}
+
+pub struct TobogganTrajectory;
+impl Solution for TobogganTrajectory {
+    type Input = Slope;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_slope(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(slope: &Self::Input) -> Answer {
+        slope.slope(3, 1).into()
+    }
+    fn part2(slope: &Self::Input) -> Answer {
+        [
+            slope.slope(1,1),
+            slope.slope(3,1),
+            slope.slope(5,1),
+            slope.slope(7,1),
+            slope.slope(1,2),
+        ].iter().product::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(TobogganTrajectory::solve(EXAMPLE, 1), Ok(Answer::from(7)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(TobogganTrajectory::solve(EXAMPLE, 2), Ok(Answer::from(336)));
+    }
+    #[test]
+    fn survey() {
+        let slope = TobogganTrajectory::parse(EXAMPLE).unwrap();
+        assert_eq!(slope.survey(7, 2).unwrap(), Survey { fewest_trees: 0, fewest: vec![(5, 2)], most_trees: 7, most: vec![(3, 1)] });
+        assert_eq!(slope.survey(7, 0), None);
+        assert_eq!(slope.slope(3, 0), 0);
+    }
+    #[test]
+    fn render_path() {
+        let slope = TobogganTrajectory::parse(EXAMPLE).unwrap();
+        let map = slope.render_path(3, 1);
+        let rows: Vec<&str> = map.lines().collect();
+
+        assert_eq!(&rows[..5], &["..##.......", "#..O#...#..", ".#....X..#.", "..#.#...#O#", ".X...##..#."]);
+        assert_eq!(map.matches('X').count(), 7);
+    }
+}
@@ -0,0 +1,55 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use common::{Reader, Solution};
+use passportprocessing::{PassportProcessing, Schema};
+
+const USAGE: &str = "usage: validate [<schema> [<passports>]]
+    lists every field each passport gets wrong
+    <schema> defaults to this day's schema.toml, <passports> to its input.txt";
+
+fn read(path: &PathBuf) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("couldn't open {}: {}", path.display(), e);
+        process::exit(1);
+    })
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.len() > 2 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let directory = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let schema_path = args.get(0).map_or(directory.join("schema.toml"), PathBuf::from);
+    let passports_path = args.get(1).map_or(directory.join("input.txt"), PathBuf::from);
+
+    let schema = Schema::read(&mut Reader::new(read(&schema_path).as_bytes())).unwrap_or_else(|e| {
+        eprintln!("{}: {}", schema_path.display(), e);
+        process::exit(1);
+    });
+
+    let passports = PassportProcessing::parse(&read(&passports_path)).unwrap_or_else(|e| {
+        eprintln!("{}: {}", passports_path.display(), e);
+        process::exit(1);
+    });
+
+    let mut valid = 0;
+
+    for (i, passport) in passports.iter().enumerate() {
+        let violations = schema.violations(passport);
+
+        if violations.len() == 0 { valid += 1; continue; }
+
+        println!("passport {}:", i+1);
+
+        for violation in violations { println!("    {}", violation.to_string()); }
+    }
+
+    println!("{} of {} passports valid", valid, passports.len());
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+mod pattern;
+mod schema;
+pub use pattern::{Pattern, PatternError};
+pub use schema::{Field, Rule, Schema, Violation};
+
+pub type Passport = HashMap<String,String>;
+
+const SCHEMA: &str = include_str!("../schema.toml");
+
+/// The rules in schema.toml.
+pub fn default_schema() -> Schema {
+    Schema::read(&mut Reader::new(SCHEMA.as_bytes())).expect("schema.toml should be valid")
+}
+
+pub fn read_passports(input: &mut Reader) -> Result<Vec<Passport>, ParseError> {
+    let mut passports = Vec::<Passport>::new();
+
+    while let Some(group) = input.next_group() {
+        let mut passport = Passport::new();
+
+        for line in group.lines() {
+            for entry in line.text().split_whitespace() {
+                let (key, value) = line.split_once(entry, ":", "a field like key:value")?;
+                passport.insert(key.to_string(), value.to_string());
+            }
+        }
+
+        passports.push(passport);
+    }
+
+    if passports.len() == 0 { Err(input.end("a passport")) }
+    else { Ok(passports) }
+}
+
+pub struct PassportProcessing;
+impl Solution for PassportProcessing {
+    type Input = Vec<Passport>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_passports(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(passports: &Self::Input) -> Answer {
+        let schema = default_schema();
+
+        passports.iter().filter(|x| schema.missing(x).len() == 0).count().into()
+    }
+    fn part2(passports: &Self::Input) -> Answer {
+        let schema = default_schema();
+
+        passports.iter().filter(|x| schema.violations(x).len() == 0).count().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+
+    const INVALID: &str = "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+";
+
+    const VALID: &str = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(PassportProcessing::solve(EXAMPLE, 1), Ok(Answer::from(2)));
+    }
+    #[test]
+    fn part2_examples() {
+        assert_eq!(PassportProcessing::solve(INVALID, 2), Ok(Answer::from(0)));
+        assert_eq!(PassportProcessing::solve(VALID, 2), Ok(Answer::from(4)));
+    }
+    #[test]
+    fn every_violation() {
+        let schema = default_schema();
+        let passports = PassportProcessing::parse(INVALID).unwrap();
+        let violations = |i: usize| schema.violations(&passports[i]).iter().map(|x| x.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(violations(0), vec!["eyr: 1972 isn't in 2020..=2030", "hgt: \"170\" has no unit, expected cm or in", "pid: \"186cm\" doesn't match [0-9]{9}"]);
+        assert_eq!(violations(3), vec![
+            "byr: 2007 isn't in 1920..=2002",
+            "iyr: 2023 isn't in 2010..=2020",
+            "eyr: 2038 isn't in 2020..=2030",
+            "hgt: 59cm isn't in 150..=193cm",
+            "hcl: \"74454a\" doesn't match #[0-9a-f]{6}",
+            "ecl: \"zzz\" isn't one of amb, blu, brn, gry, grn, hzl or oth",
+            "pid: \"3556412378\" doesn't match [0-9]{9}",
+        ]);
+        assert_eq!(schema.missing(&PassportProcessing::parse(EXAMPLE).unwrap()[1]), vec![Violation { field: "hgt".to_string(), problem: "missing".to_string() }]);
+    }
+    #[test]
+    fn longest_unit() {
+        let schema = Schema::read(&mut Reader::new("[tm]\nrule = \"units\"\nin = [1, 5]\nmin = [10, 20]\n".as_bytes())).unwrap();
+        let passports = PassportProcessing::parse("tm:12min\n\ntm:3in\n\ntm:30min\n").unwrap();
+        let violations = |i: usize| schema.violations(&passports[i]).iter().map(|x| x.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(violations(0), Vec::<String>::new());
+        assert_eq!(violations(1), Vec::<String>::new());
+        assert_eq!(violations(2), vec!["tm: 30min isn't in 10..=20min"]);
+    }
+    #[test]
+    fn bad_schemas() {
+        let read = |text: &str| Schema::read(&mut Reader::new(text.as_bytes())).unwrap_err().to_string();
+
+        assert_eq!(read("[byr]\nrule = \"range\"\nmin = 1920\n"), "line 1, column 2: expected max = an integer, found \"byr\"");
+        assert_eq!(read("[hcl]\nrule = \"pattern\"\npattern = \"#[0-9a-f{6}\"\n"), "line 3, column 1: expected a valid pattern (offset 11: pattern ended, expected ] to close the class), found \"pattern\"");
+        assert_eq!(read("[ecl]\nrule = \"enum\"\nvalues = [\"amb\", 3]\n"), "line 3, column 1: expected a list of strings, found \"values\"");
+        assert_eq!(read("[ecl]\nvalues = [\"amb\" \"blu\"]\n"), "line 2, column 17: expected , or ], found \"\\\"\"");
+        assert_eq!(read("[ecl]\nvalues = [\"amb\", 3x]\n"), "line 2, column 18: expected a string, integer, boolean or list, found \"3x\"");
+        assert_eq!(read("[ecl]\nvalues = [\"amb\"\n"), "line 2: input ended, expected , or ]");
+        assert_eq!(read("[ecl]\nvalues = \"amb\n"), "line 2: input ended, expected a closing \"");
+        assert_eq!(read("min = 3\n"), "line 1, column 1: expected a table like [byr], found \"min = 3\"");
+        assert_eq!(read("[byr]\nrule = \"range\"\nmin = 2002\nmax = 1920\n"), "line 4, column 1: expected a max of at least 2002, found \"max\"");
+        assert_eq!(read("[hgt]\nrule = \"units\"\ncm = [193, 150]\n"), "line 3, column 1: expected a unit's bounds like [150, 193], found \"cm\"");
+        assert_eq!(read("[byr]\n[iyr]\n# again\n[ byr ]\n"), "line 4, column 3: expected a field that isn't already in the schema, found \"byr\"");
+    }
+    #[test]
+    fn patterns() {
+        let pattern = Pattern::new("a.?[x-z]+\\+{2,3}b*").unwrap();
+
+        assert!(pattern.matches("ay++"));
+        assert!(pattern.matches("a!zyx+++bbb"));
+        assert!(!pattern.matches("a!zyx++++"));
+        assert!(!pattern.matches("a!!z++"));
+        assert_eq!(Pattern::new("[a-").unwrap_err().to_string(), "offset 3: pattern ended, expected the end of the range");
+        assert_eq!(Pattern::new("*a").unwrap_err().to_string(), "offset 0: expected something to repeat before it, found \"*\"");
+        assert_eq!(Pattern::new("[z-a]"), Err(PatternError::BadToken { offset: 3, token: "a".to_string(), expected: "the end of a range from 'z'".to_string() }));
+        assert_eq!(Pattern::new("a{3,x}").unwrap_err().to_string(), "offset 2: expected a count like 3 or bounds like 2,5, found \"3,x\"");
+
+        // backtracking over every way to split the a's between the stars would take forever
+        let stars = Pattern::new(&"a*".repeat(30)).unwrap();
+        assert!(stars.matches(&"a".repeat(100)));
+        assert!(!stars.matches(&format!("{}b", "a".repeat(100))));
+    }
+}
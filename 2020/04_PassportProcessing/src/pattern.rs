@@ -0,0 +1,166 @@
+use std::fmt;
+
+/// A character, or a set of them.
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum Class {
+    Any,
+    Char(char),
+    /// Inclusive ranges, with single characters as ranges of one.
+    Set(Vec<(char, char)>),
+}
+impl Class {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Char(x) => *x == c,
+            Self::Set(ranges) => ranges.iter().any(|&(low, high)| low <= c && c <= high),
+        }
+    }
+}
+
+/// Why a pattern couldn't be read. Offsets count characters from 0.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum PatternError {
+    /// `token`, starting at `offset`, doesn't have the `expected` form.
+    BadToken { offset: usize, token: String, expected: String },
+    /// The pattern stopped at `offset`, its length, while `expected` was still to come.
+    UnexpectedEnd { offset: usize, expected: String },
+}
+impl PatternError {
+    fn bad_token(offset: usize, token: &str, expected: &str) -> Self {
+        Self::BadToken { offset, token: token.to_string(), expected: expected.to_string() }
+    }
+    fn unexpected_end(offset: usize, expected: &str) -> Self {
+        Self::UnexpectedEnd { offset, expected: expected.to_string() }
+    }
+}
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BadToken { offset, token, expected } =>
+                write!(f, "offset {}: expected {}, found {:?}", offset, expected, token),
+            Self::UnexpectedEnd { offset, expected } =>
+                write!(f, "offset {}: pattern ended, expected {}", offset, expected),
+        }
+    }
+}
+impl std::error::Error for PatternError {}
+
+/// A small regular expression: `.`, classes like `[0-9a-f]`, `\` to escape the next character,
+/// and `?`, `*`, `+`, `{n}` or `{n,m}` to repeat. There's no alternation or grouping, and a
+/// pattern has to match the whole text.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Pattern {
+    text: String,
+    /// Each class with the fewest and most times it can repeat.
+    atoms: Vec<(Class, usize, usize)>,
+}
+impl Pattern {
+    pub fn new(text: &str) -> Result<Self, PatternError> {
+        let length = text.chars().count();
+        let mut chars = text.chars().enumerate().peekable();
+        let mut atoms = Vec::<(Class, usize, usize)>::new();
+        let end = |expected: &str| PatternError::unexpected_end(length, expected);
+
+        while let Some((offset, c)) = chars.next() {
+            let class = match c {
+                '.' => Class::Any,
+                '\\' => Class::Char(chars.next().ok_or_else(|| end("a character to escape"))?.1),
+                '[' => {
+                    let mut ranges = Vec::<(char, char)>::new();
+
+                    loop {
+                        let low = match chars.next().ok_or_else(|| end("] to close the class"))?.1 {
+                            ']' => break,
+                            '\\' => chars.next().ok_or_else(|| end("a character to escape"))?.1,
+                            x => x,
+                        };
+
+                        if chars.peek().map(|x| x.1) == Some('-') {
+                            chars.next();
+
+                            let (offset, high) = chars.next().ok_or_else(|| end("the end of the range"))?;
+
+                            if high == ']' || high < low {
+                                return Err(PatternError::bad_token(offset, &high.to_string(), &format!("the end of a range from {:?}", low)));
+                            }
+
+                            ranges.push((low, high));
+                        }
+                        else { ranges.push((low, low)); }
+                    }
+
+                    Class::Set(ranges)
+                },
+                '?' | '*' | '+' | '{' => return Err(PatternError::bad_token(offset, &c.to_string(), "something to repeat before it")),
+                ']' | '}' => return Err(PatternError::bad_token(offset, &c.to_string(), "a character, escaped if it's a bracket")),
+                x => Class::Char(x),
+            };
+
+            let (min, max) = match chars.peek().map(|x| x.1) {
+                Some('?') => (0, 1),
+                Some('*') => (0, usize::MAX),
+                Some('+') => (1, usize::MAX),
+                Some('{') => {
+                    chars.next();
+
+                    let start = chars.peek().map_or(length, |x| x.0);
+                    let mut bounds = String::new();
+
+                    loop {
+                        match chars.next().ok_or_else(|| end("} to close the repeat"))?.1 {
+                            '}' => break,
+                            x => bounds.push(x),
+                        }
+                    }
+
+                    let bad = || PatternError::bad_token(start, &bounds, "a count like 3 or bounds like 2,5");
+                    let (min, max) = match bounds.split_once(',') {
+                        Some((min, max)) => (min.parse().map_err(|_| bad())?, max.parse().map_err(|_| bad())?),
+                        None => { let n = bounds.parse().map_err(|_| bad())?; (n, n) },
+                    };
+
+                    if min > max { return Err(PatternError::bad_token(start, &bounds, "bounds with the fewest first")); }
+
+                    atoms.push((class, min, max));
+                    continue;
+                },
+                _ => (1, 1),
+            };
+
+            if (min, max) != (1, 1) { chars.next(); }
+
+            atoms.push((class, min, max));
+        }
+
+        Ok(Self { text: text.to_string(), atoms })
+    }
+    pub fn matches(&self, text: &str) -> bool {
+        let chars: Vec<char> = text.chars().collect();
+        let mut failed = vec![false; (self.atoms.len()+1) * (chars.len()+1)];
+
+        self.matches_from(0, 0, &chars, &mut failed)
+    }
+    pub fn to_string(&self) -> String {
+        self.text.clone()
+    }
+    /// Whether the atoms from `atom` on match all of `chars` from `position` on, trying the most
+    /// repeats of each first. Every pair of atom and position that fails is marked in `failed`,
+    /// so none is tried twice however many ways there are to get to it.
+    fn matches_from(&self, atom: usize, position: usize, chars: &[char], failed: &mut Vec<bool>) -> bool {
+        let (class, min, max) = match self.atoms.get(atom) {
+            Some((class, min, max)) => (class, *min, *max),
+            None => return position == chars.len(),
+        };
+        let index = atom * (chars.len()+1) + position;
+
+        if failed[index] { return false; }
+
+        let most = chars[position..].iter().take(max).take_while(|&&x| class.matches(x)).count();
+        let matched = (min..=most).rev().any(|n| self.matches_from(atom+1, position+n, chars, failed));
+
+        if !matched { failed[index] = true; }
+
+        matched
+    }
+}
@@ -0,0 +1,300 @@
+use common::{Line, ParseError, Reader};
+
+use super::{Passport, Pattern};
+
+/// A value in a schema file.
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum Value {
+    Integer(i64),
+    Text(String),
+    Boolean(bool),
+    List(Vec<Value>),
+}
+impl Value {
+    /// Reads a value out of `s`, a slice of `line`, returning it and whatever's left after it.
+    fn read<'a>(line: &Line, s: &'a str) -> Result<(Self, &'a str), ParseError> {
+        let s = s.trim_start();
+
+        if let Some(rest) = s.strip_prefix('"') {
+            let mut text = String::new();
+            let mut chars = rest.char_indices();
+
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '"' => return Ok((Self::Text(text), &rest[i+1..])),
+                    '\\' => text.push(chars.next().ok_or_else(|| line.end("an escaped character"))?.1),
+                    x => text.push(x),
+                }
+            }
+
+            Err(line.end("a closing \""))
+        }
+        else if let Some(mut rest) = s.strip_prefix('[') {
+            let mut values = Vec::<Value>::new();
+
+            loop {
+                rest = rest.trim_start();
+
+                if let Some(after) = rest.strip_prefix(']') { return Ok((Self::List(values), after)); }
+                if rest.is_empty() { return Err(line.end("a value or ]")); }
+
+                let (value, after) = Self::read(line, rest)?;
+                values.push(value);
+                rest = after.trim_start();
+
+                if let Some(after) = rest.strip_prefix(',') { rest = after; }
+                else if rest.is_empty() { return Err(line.end(", or ]")); }
+                else if !rest.starts_with(']') { return Err(line.error(first(rest), ", or ]")); }
+            }
+        }
+        else {
+            let end = s.find(|c: char| c == ',' || c == ']' || c.is_whitespace()).unwrap_or(s.len());
+            let expected = "a string, integer, boolean or list";
+            let value = match &s[..end] {
+                "" if s.is_empty() => return Err(line.end(expected)),
+                "" => return Err(line.error(first(s), expected)),
+                "true" => Self::Boolean(true),
+                "false" => Self::Boolean(false),
+                x => Self::Integer(line.parse(x, expected)?),
+            };
+
+            Ok((value, &s[end..]))
+        }
+    }
+    fn integer(&self) -> Option<i64> {
+        if let Self::Integer(x) = self { Some(*x) } else { None }
+    }
+    fn text(&self) -> Option<&str> {
+        if let Self::Text(x) = self { Some(x) } else { None }
+    }
+    fn range(&self) -> Option<(i64, i64)> {
+        match self {
+            Self::List(x) if x.len() == 2 => Some((x[0].integer()?, x[1].integer()?)),
+            _ => None,
+        }
+    }
+}
+
+/// What a field's value has to look like.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Rule {
+    Any,
+    /// An integer between the two, inclusive.
+    Range(i64, i64),
+    /// An integer followed by one of the units, between that unit's bounds.
+    Units(Vec<(String, i64, i64)>),
+    Pattern(Pattern),
+    OneOf(Vec<String>),
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Field {
+    pub name: String,
+    pub required: bool,
+    pub rule: Rule,
+}
+impl Field {
+    /// Why `value` doesn't follow the field's rule, if it doesn't.
+    fn problem(&self, value: &str) -> Option<String> {
+        match &self.rule {
+            Rule::Any => None,
+            Rule::Range(low, high) => match value.parse::<i64>() {
+                Ok(x) if *low <= x && x <= *high => None,
+                Ok(x) => Some(format!("{} isn't in {}..={}", x, low, high)),
+                Err(_) => Some(format!("{:?} isn't a number", value)),
+            },
+            Rule::Units(units) => {
+                // the longest unit that fits, so "in" doesn't take "12min" from "min"
+                let (unit, low, high) = match units.iter().filter(|x| value.ends_with(&x.0)).max_by_key(|x| x.0.len()) {
+                    Some(unit) => unit,
+                    None => {
+                        let names: Vec<&str> = units.iter().map(|x| x.0.as_str()).collect();
+                        return Some(format!("{:?} has no unit, expected {}", value, list(&names)));
+                    },
+                };
+
+                match value[..value.len()-unit.len()].parse::<i64>() {
+                    Ok(x) if *low <= x && x <= *high => None,
+                    Ok(x) => Some(format!("{}{} isn't in {}..={}{}", x, unit, low, high, unit)),
+                    Err(_) => Some(format!("{:?} isn't a number of {}", value, unit)),
+                }
+            },
+            Rule::Pattern(pattern) => {
+                if pattern.matches(value) { None }
+                else { Some(format!("{:?} doesn't match {}", value, pattern.to_string())) }
+            },
+            Rule::OneOf(values) => {
+                if values.iter().any(|x| x == value) { return None; }
+
+                let names: Vec<&str> = values.iter().map(|x| x.as_str()).collect();
+                Some(format!("{:?} isn't one of {}", value, list(&names)))
+            },
+        }
+    }
+}
+
+/// A field a passport got wrong.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Violation {
+    pub field: String,
+    pub problem: String,
+}
+impl Violation {
+    pub fn to_string(&self) -> String {
+        format!("{}: {}", self.field, self.problem)
+    }
+}
+
+/// The fields a passport has, read from a subset of TOML: a table per field with `key = value`
+/// lines under it, and comments on lines of their own. See schema.toml for the keys.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+impl Schema {
+    pub fn read(input: &mut Reader) -> Result<Self, ParseError> {
+        let mut tables = Vec::<(usize, String, Vec<(usize, String)>)>::new();
+
+        while let Some(line) = input.next_line() {
+            let text = line.text().trim();
+
+            if text.starts_with('#') { continue; }
+
+            if let Some(name) = text.strip_prefix('[') {
+                let name = name.strip_suffix(']').ok_or_else(|| line.error(text, "a table like [byr]"))?.trim();
+
+                if tables.iter().any(|x| x.1 == name) { return Err(line.error(name, "a field that isn't already in the schema")); }
+
+                tables.push((line.number(), name.to_string(), Vec::<(usize, String)>::new()));
+            }
+            else {
+                let table = tables.last_mut().ok_or_else(|| line.error(text, "a table like [byr]"))?;
+                table.2.push((line.number(), line.text().to_string()));
+            }
+        }
+
+        if tables.len() == 0 { return Err(input.end("a table like [byr]")); }
+
+        let fields = tables.iter().map(|(number, name, lines)| Self::field(*number, name, lines)).collect::<Result<_, _>>()?;
+
+        Ok(Self { fields })
+    }
+    fn field(number: usize, name: &str, lines: &[(usize, String)]) -> Result<Field, ParseError> {
+        let mut entries = Vec::<(Line, &str, Value)>::new();
+
+        for (number, text) in lines {
+            let line = Line::new(*number, text);
+            let (key, value) = line.split_once(text, "=", "a key and value like min = 1920")?;
+            let (parsed, rest) = Value::read(&line, value)?;
+
+            if rest.trim().len() > 0 { return Err(line.error(rest.trim(), "the end of the line")); }
+
+            entries.push((line, key.trim(), parsed));
+        }
+
+        let get = |key: &str| entries.iter().find(|x| x.1 == key);
+        let header = format!("[{}]", name);
+        let missing = |expected: &str| Line::new(number, &header).error(name, expected);
+        let required = match get("required") {
+            Some((_, _, Value::Boolean(x))) => *x,
+            Some((line, key, _)) => return Err(line.error(key, "required = true or false")),
+            None => true,
+        };
+
+        let rule = match get("rule") {
+            None => Rule::Any,
+            Some((line, _, value)) => match value.text() {
+                Some("range") => {
+                    let bound = |key: &str| -> Result<i64, ParseError> {
+                        match get(key) {
+                            Some((line, key, value)) => value.integer().ok_or_else(|| line.error(key, "an integer bound")),
+                            None => Err(missing(&format!("{} = an integer", key))),
+                        }
+                    };
+
+                    let (min, max) = (bound("min")?, bound("max")?);
+
+                    if let Some((line, key, _)) = get("max").filter(|_| min > max) {
+                        return Err(line.error(key, &format!("a max of at least {}", min)));
+                    }
+
+                    Rule::Range(min, max)
+                },
+                Some("units") => {
+                    let mut units = Vec::<(String, i64, i64)>::new();
+
+                    for (line, key, value) in entries.iter().filter(|x| x.1 != "rule" && x.1 != "required") {
+                        let (low, high) = value.range().filter(|x| x.0 <= x.1).ok_or_else(|| line.error(key, "a unit's bounds like [150, 193]"))?;
+                        units.push((key.to_string(), low, high));
+                    }
+
+                    if units.len() == 0 { return Err(missing("bounds for a unit like cm = [150, 193]")); }
+
+                    Rule::Units(units)
+                },
+                Some("pattern") => match get("pattern") {
+                    Some((line, key, Value::Text(text))) => Rule::Pattern(Pattern::new(text).map_err(|e| line.error(key, &format!("a valid pattern ({})", e)))?),
+                    Some((line, key, _)) => return Err(line.error(key, "a pattern string")),
+                    None => return Err(missing("pattern = a string")),
+                },
+                Some("enum") => match get("values") {
+                    Some((line, key, Value::List(values))) => {
+                        let values: Option<Vec<String>> = values.iter().map(|x| x.text().map(|x| x.to_string())).collect();
+                        Rule::OneOf(values.ok_or_else(|| line.error(key, "a list of strings"))?)
+                    },
+                    Some((line, key, _)) => return Err(line.error(key, "a list of strings")),
+                    None => return Err(missing("values = a list of strings")),
+                },
+                _ => return Err(line.error("rule", "rule = \"range\", \"units\", \"pattern\" or \"enum\"")),
+            },
+        };
+
+        // anything else is a mistake, apart from the units a unit rule is made of
+        if !matches!(rule, Rule::Units(_)) {
+            let known = ["required", "rule", "min", "max", "pattern", "values"];
+
+            if let Some((line, key, _)) = entries.iter().find(|x| !known.contains(&x.1)) {
+                return Err(line.error(key, "required, rule, min, max, pattern or values"));
+            }
+        }
+
+        Ok(Field { name: name.to_string(), required, rule })
+    }
+    /// Every field that's required and missing.
+    pub fn missing(&self, passport: &Passport) -> Vec<Violation> {
+        self.fields.iter()
+            .filter(|x| x.required && !passport.contains_key(&x.name))
+            .map(|x| Violation { field: x.name.clone(), problem: "missing".to_string() })
+            .collect()
+    }
+    /// Every field that's missing or breaks its rule, in the schema's order.
+    pub fn violations(&self, passport: &Passport) -> Vec<Violation> {
+        let mut violations = Vec::<Violation>::new();
+
+        for field in &self.fields {
+            let problem = match passport.get(&field.name) {
+                Some(value) => field.problem(value),
+                None if field.required => Some("missing".to_string()),
+                None => None,
+            };
+
+            if let Some(problem) = problem { violations.push(Violation { field: field.name.clone(), problem }); }
+        }
+
+        violations
+    }
+}
+
+/// The first character of `s`, for pointing an error at.
+fn first(s: &str) -> &str {
+    &s[..s.chars().next().map_or(0, |x| x.len_utf8())]
+}
+
+/// Lists names like `a, b or c`.
+fn list(names: &[&str]) -> String {
+    match names.split_last() {
+        Some((last, rest)) if rest.len() > 0 => format!("{} or {}", rest.join(", "), last),
+        Some((last, _)) => last.to_string(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Seat(pub usize, pub usize);
+impl Seat {
+    fn from_str(s: &str) -> Option<Self> {
+        Plane::default().decode(s)
+    }
+    fn id(&self) -> usize {
+        Plane::default().id(*self)
+    }
+}
+
+/// The most characters a boarding pass can have, so a plane has no more than about a million
+/// seats and its occupancy stays a reasonable size.
+pub const MAX_BITS: usize = 20;
+
+/// Why a plane couldn't be built or filled.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PlaneError {
+    /// Boarding passes with this many row and column characters would be longer than `MAX_BITS`.
+    TooWide(usize, usize),
+    /// A boarding pass was for a seat that isn't on the plane.
+    NotOnPlane(Seat),
+}
+impl fmt::Display for PlaneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TooWide(rows, columns) => write!(f, "{} row and {} column characters are more than {} altogether", rows, columns, MAX_BITS),
+            Self::NotOnPlane(seat) => write!(f, "row {}, column {} isn't on the plane", seat.0, seat.1),
+        }
+    }
+}
+impl std::error::Error for PlaneError {}
+
+/// How many characters of a boarding pass pick the row (`F` or `B`) and then the column (`L`
+/// or `R`), so a plane has `2^row_bits` rows of `2^column_bits` seats. The airline's planes
+/// use 7 and 3.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Plane {
+    row_bits: usize,
+    column_bits: usize,
+}
+impl Plane {
+    pub fn new(row_bits: usize, column_bits: usize) -> Result<Self, PlaneError> {
+        if row_bits.checked_add(column_bits).map_or(true, |bits| bits > MAX_BITS) { return Err(PlaneError::TooWide(row_bits, column_bits)); }
+
+        Ok(Self { row_bits, column_bits })
+    }
+    pub fn row_bits(&self) -> usize { self.row_bits }
+    pub fn column_bits(&self) -> usize { self.column_bits }
+    pub fn rows(&self) -> usize { 1 << self.row_bits }
+    pub fn columns(&self) -> usize { 1 << self.column_bits }
+    pub fn contains(&self, seat: Seat) -> bool {
+        seat.0 < self.rows() && seat.1 < self.columns()
+    }
+    pub fn decode(&self, s: &str) -> Option<Seat> {
+        let chars: Vec<char> = s.chars().collect();
+
+        if chars.len() != self.row_bits + self.column_bits { return None; }
+
+        let bits = |chars: &[char], zero: char, one: char| chars.iter().try_fold(0usize, |acc, &x| {
+            if x == zero { Some(acc << 1) }
+            else if x == one { Some(acc << 1 | 1) }
+            else { None }
+        });
+
+        Some(Seat(bits(&chars[..self.row_bits], 'F', 'B')?, bits(&chars[self.row_bits..], 'L', 'R')?))
+    }
+    pub fn encode(&self, seat: Seat) -> Option<String> {
+        if !self.contains(seat) { return None; }
+
+        let rows = (0..self.row_bits).rev().map(|i| if seat.0 >> i & 1 == 1 { 'B' } else { 'F' });
+        let columns = (0..self.column_bits).rev().map(|i| if seat.1 >> i & 1 == 1 { 'R' } else { 'L' });
+
+        Some(rows.chain(columns).collect())
+    }
+    pub fn id(&self, seat: Seat) -> usize {
+        seat.0 << self.column_bits | seat.1
+    }
+    pub fn seat(&self, id: usize) -> Seat {
+        Seat(id >> self.column_bits, id & (self.columns()-1))
+    }
+    /// How many boarding passes there are for each seat on the plane, as long as every pass is
+    /// for a seat on it.
+    pub fn occupancy(&self, seats: &[Seat]) -> Result<Occupancy, PlaneError> {
+        let mut passes = vec![0usize; self.rows() * self.columns()];
+
+        for &seat in seats {
+            if !self.contains(seat) { return Err(PlaneError::NotOnPlane(seat)); }
+
+            passes[self.id(seat)] += 1;
+        }
+
+        Ok(Occupancy { plane: *self, passes })
+    }
+}
+impl Default for Plane {
+    fn default() -> Self { Self { row_bits: 7, column_bits: 3 } }
+}
+
+/// The number of boarding passes for each seat, by seat ID.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Occupancy {
+    plane: Plane,
+    passes: Vec<usize>,
+}
+impl Occupancy {
+    /// Every seat without a boarding pass, including the ones at the front and back of the
+    /// plane that aren't there at all.
+    pub fn empty(&self) -> Vec<Seat> {
+        (0..self.passes.len()).filter(|&x| self.passes[x] == 0).map(|x| self.plane.seat(x)).collect()
+    }
+    /// Empty seats whose IDs on either side have boarding passes.
+    pub fn gaps(&self) -> Vec<Seat> {
+        (1..self.passes.len().saturating_sub(1))
+            .filter(|&x| self.passes[x] == 0 && self.passes[x-1] > 0 && self.passes[x+1] > 0)
+            .map(|x| self.plane.seat(x))
+            .collect()
+    }
+    /// Seats with more than one boarding pass, and how many.
+    pub fn duplicates(&self) -> Vec<(Seat, usize)> {
+        (0..self.passes.len()).filter(|&x| self.passes[x] > 1).map(|x| (self.plane.seat(x), self.passes[x])).collect()
+    }
+    /// A line per row from the front, with `.` for an empty seat, `#` for a seat with a boarding
+    /// pass and `X` for one with several.
+    pub fn to_string(&self) -> String {
+        let mut result = String::with_capacity((self.plane.columns()+1) * self.plane.rows());
+
+        for row in self.passes.chunks(self.plane.columns()) {
+            result.extend(row.iter().map(|&x| match x { 0 => '.', 1 => '#', _ => 'X' }));
+            result.push('\n');
+        }
+
+        result
+    }
+}
+
+fn read_seats(input: &mut Reader) -> Result<Vec<Seat>, ParseError> {
+    let mut seats = Vec::<Seat>::new();
+
+    while let Some(line) = input.next_line() {
+        seats.push(Seat::from_str(line.text()).ok_or(line.error(line.text(), "a boarding pass like FBFBBFFRLR"))?);
+    }
+
+    if seats.len() == 0 { Err(input.end("a boarding pass")) }
+    else { Ok(seats) }
+}
+
+pub struct BinaryBoarding;
+impl Solution for BinaryBoarding {
+    type Input = Vec<Seat>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_seats(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(seats: &Self::Input) -> Answer {
+        seats.iter().map(|x| x.id()).reduce(|acc, x| if x > acc { x } else { acc }).unwrap().into()
+    }
+    fn part2(seats: &Self::Input) -> Answer {
+        // every seat read is on the default plane, which is the one passes are decoded for
+        match Plane::default().occupancy(seats).map(|x| x.gaps().first().copied()) {
+            Ok(Some(seat)) => seat.id().into(),
+            _ => Answer::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seat_ids() {
+        assert_eq!(Seat::from_str("FBFBBFFRLR"), Some(Seat(44, 5)));
+        assert_eq!(Seat::from_str("FBFBBFFRLR").unwrap().id(), 357);
+        assert_eq!(Seat::from_str("BFFFBBFRRR").unwrap().id(), 567);
+        assert_eq!(Seat::from_str("FFFBBBFRRR").unwrap().id(), 119);
+        assert_eq!(Seat::from_str("BBFFBBFRLL").unwrap().id(), 820);
+        assert_eq!(Seat::from_str("FBFBBFFRL"), None);
+    }
+    #[test]
+    fn part1_example() {
+        assert_eq!(BinaryBoarding::solve("BFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n", 1), Ok(Answer::from(820)));
+    }
+    #[test]
+    fn any_geometry() {
+        for row_bits in 0..=4 {
+            for column_bits in 0..=4 {
+                let plane = Plane::new(row_bits, column_bits).unwrap();
+
+                for id in 0..plane.rows() * plane.columns() {
+                    let seat = plane.seat(id);
+                    let pass = plane.encode(seat).unwrap();
+
+                    assert_eq!((plane.id(seat), plane.decode(&pass)), (id, Some(seat)));
+                }
+
+                assert_eq!(plane.encode(Seat(plane.rows(), 0)), None);
+            }
+        }
+
+        assert_eq!(Plane::new(2, 1).unwrap().encode(Seat(2, 1)), Some("BFR".to_string()));
+        assert_eq!(Plane::new(2, 1).unwrap().decode("BFRR"), None);
+        assert_eq!(Plane::new(0, 0).unwrap().decode(""), Some(Seat(0, 0)));
+    }
+    #[test]
+    fn occupancy() {
+        // seats 2 to 7 of 8, less 4, with 5 passed out twice
+        let plane = Plane::new(2, 1).unwrap();
+        let seats: Vec<Seat> = ["FBL", "FBR", "BFR", "BFR", "BBL", "BBR"].iter().map(|x| plane.decode(x).unwrap()).collect();
+        let occupancy = plane.occupancy(&seats).unwrap();
+
+        assert_eq!(occupancy.empty(), vec![Seat(0, 0), Seat(0, 1), Seat(2, 0)]);
+        assert_eq!(occupancy.gaps(), vec![Seat(2, 0)]);
+        assert_eq!(occupancy.duplicates(), vec![(Seat(2, 1), 2)]);
+        assert_eq!(occupancy.to_string(), "..\n##\n.X\n##\n");
+    }
+    #[test]
+    fn planes_too_big_to_board() {
+        assert_eq!(Plane::new(12, 8).map(|x| x.row_bits()), Ok(12));
+        assert_eq!(Plane::new(12, 9), Err(PlaneError::TooWide(12, 9)));
+        assert_eq!(Plane::new(usize::MAX, 1), Err(PlaneError::TooWide(usize::MAX, 1)));
+        assert_eq!(Plane::new(64, 0).unwrap_err().to_string(), "64 row and 0 column characters are more than 20 altogether");
+
+        // passes for a bigger plane aren't cut down to fit a smaller one
+        let small = Plane::new(2, 1).unwrap();
+        let seat = Plane::default().decode("FBFBBFFRLR").unwrap();
+        assert_eq!(small.decode("FBFBBFFRLR"), None);
+        assert_eq!(small.occupancy(&[Seat(1, 1), seat]), Err(PlaneError::NotOnPlane(Seat(44, 5))));
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+type Survey = HashSet<char>;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SurveyGroup {
+    individuals: Vec<Survey>,
+    total: Survey,
+    unanimous: Survey,
+}
+impl SurveyGroup {
+    fn new() -> Self {
+        Self { individuals: Vec::<Survey>::new(), total: Survey::new(), unanimous: ('a' as u8..='z' as u8).map(|x| x as char).collect() }
+    }
+    fn add_survey(&mut self, survey: &Survey) {
+        self.individuals.push(survey.clone());
+        self.total = self.total.union(survey).copied().collect();
+        self.unanimous = self.unanimous.intersection(survey).copied().collect();
+    }
+}
+
+fn read_surveys(input: &mut Reader) -> Result<Vec<SurveyGroup>, ParseError> {
+    let mut surveys = Vec::<SurveyGroup>::new();
+
+    while let Some(group) = input.next_group() {
+        let mut current_survey = SurveyGroup::new();
+
+        for line in group.lines() {
+            let answers = line.map_chars("a question from a to z", |x| if x.is_ascii_lowercase() { Some(x) } else { None })?;
+
+            current_survey.add_survey(&answers.into_iter().collect());
+        }
+
+        surveys.push(current_survey);
+    }
+
+    if surveys.len() == 0 { Err(input.end("a group of answers")) }
+    else { Ok(surveys) }
+}
+
+pub struct CustomCustoms;
+impl Solution for CustomCustoms {
+    type Input = Vec<SurveyGroup>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_surveys(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(surveys: &Self::Input) -> Answer {
+        surveys.iter().map(|x| x.total.len()).sum::<usize>().into()
+    }
+    fn part2(surveys: &Self::Input) -> Answer {
+        surveys.iter().map(|x| x.unanimous.len()).sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(CustomCustoms::solve(EXAMPLE, 1), Ok(Answer::from(11)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(CustomCustoms::solve(EXAMPLE, 2), Ok(Answer::from(6)));
+    }
+}
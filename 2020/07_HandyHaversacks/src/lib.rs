@@ -1,5 +1,5 @@
-use std::io;
 use std::collections::{HashMap, HashSet};
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 type BagId = usize;
 
@@ -22,7 +22,7 @@ impl Bag {
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Bags {
+pub struct Bags {
     bags: Vec<Bag>,
 }
 impl Bags {
@@ -103,59 +103,83 @@ impl Bags {
     }
 }
 
-fn read_bags() -> Result<Bags, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_bags(input: &mut Reader) -> Result<Bags, ParseError> {
     let mut bags = Bags::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
+    while let Some(line) = input.next_line() {
+        let (label, rule) = line.split_once(line.text(), " bags contain ", "a rule like \"<color> bags contain <contents>.\"")?;
+        let label = label.to_string();
         
-        let chunks: Vec<&str> = buffer.trim().split(" bags contain ").collect();
-
-        let label = chunks[0].to_string();
-        
-        if chunks[1] == "no other bags." {
+        if rule == "no other bags." {
             bags.register_bag(&label, &Vec::<(String, usize)>::new());
-            buffer.clear();
             continue;
         }
 
-        let contents: Vec<(String, usize)> = chunks[1].replace(" bags","")
-            .replace(" bag","")
-            .replace(".","")
-            .split(", ")
-            .map(|x| {
-                if let Some((amount, bag)) = x.split_once(" ") {
-                    (bag.to_string(), amount.parse::<usize>().unwrap())
-                } else { panic!("bad chunk: {}", chunks[1]); }
-            })
-            .collect();
+        let rule = rule.strip_suffix(".").ok_or(line.error(rule, "contents ending in a period"))?;
+        let mut contents = Vec::<(String, usize)>::new();
+
+        for chunk in rule.split(", ") {
+            let (amount, bag) = line.split_once(chunk, " ", "contents like \"2 muted yellow bags\"")?;
+            let bag = bag.strip_suffix(" bags")
+                .or(bag.strip_suffix(" bag"))
+                .ok_or(line.error(bag, "a color followed by bag or bags"))?;
+
+            contents.push((bag.to_string(), line.parse::<usize>(amount, "a number of bags")?));
+        }
         
         bags.register_bag(&label, &contents);
-        buffer.clear();
     }
 
-    if bags.bags.len() == 0 { Err(()) }
+    if bags.bags.len() == 0 { Err(input.end("a bag rule")) }
     else { Ok(bags) }
 }
 
-fn part1() {
-    if let Ok(bags) = read_bags() {
-        println!("{}", bags.find_containers(&"shiny gold".to_string()).len());
-    }
-    else { panic!("couldn't read bags!"); }
-}
+pub struct HandyHaversacks;
+impl Solution for HandyHaversacks {
+    type Input = Bags;
 
-fn part2() {
-    if let Ok(bags) = read_bags() {
-        println!("{}", bags.count_bags(&"shiny gold".to_string()));
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_bags(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(bags: &Self::Input) -> Answer {
+        bags.find_containers(&"shiny gold".to_string()).len().into()
+    }
+    fn part2(bags: &Self::Input) -> Answer {
+        bags.count_bags(&"shiny gold".to_string()).into()
     }
-    else { panic!("couldn't read bags!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+    const NESTED: &str = "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(HandyHaversacks::solve(EXAMPLE, 1), Ok(Answer::from(4)));
+    }
+    #[test]
+    fn part2_examples() {
+        assert_eq!(HandyHaversacks::solve(EXAMPLE, 2), Ok(Answer::from(32)));
+        assert_eq!(HandyHaversacks::solve(NESTED, 2), Ok(Answer::from(126)));
+    }
 }
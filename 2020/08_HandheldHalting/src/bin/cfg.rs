@@ -0,0 +1,33 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use common::Solution;
+use handheldhalting::HandheldHalting;
+
+const USAGE: &str = "usage: cfg [<program>]
+    prints the program's control flow graph in Graphviz's DOT language, as in cfg | dot -Tsvg
+    <program> defaults to this day's input.txt";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.len() > 1 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let path = args.get(0).map_or(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input.txt"), PathBuf::from);
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("couldn't open {}: {}", path.display(), e);
+        process::exit(1);
+    });
+
+    let program = HandheldHalting::parse(&text).unwrap_or_else(|e| {
+        eprintln!("{}: {}", path.display(), e);
+        process::exit(1);
+    });
+
+    print!("{}", program.to_dot());
+}
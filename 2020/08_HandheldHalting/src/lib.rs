@@ -0,0 +1,102 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+use console::{ControlFlowGraph, Instruction, InstructionSet, Machine, Outcome};
+
+#[derive(Clone, Debug)]
+pub struct Program {
+    set: InstructionSet,
+    instructions: Vec<Instruction>,
+}
+impl Program {
+    /// The accumulator when execution either loops or runs off the end.
+    fn execute(&self) -> (Outcome, isize) {
+        let mut machine = Machine::new(&self.set, &self.instructions);
+        let outcome = machine.run();
+
+        (outcome, machine.registers().accumulator)
+    }
+    /// Swaps a single `nop` for a `jmp` or the other way round so the program terminates,
+    /// returning the accumulator it terminates with.
+    fn patch(&self) -> Option<isize> {
+        let graph = ControlFlowGraph::new(&self.set, &self.instructions);
+        let (address, patched) = graph.terminating_patch(&[("nop", "jmp"), ("jmp", "nop")])?;
+        let mut instructions = self.instructions.clone();
+
+        instructions[address] = patched;
+
+        let mut machine = Machine::new(&self.set, &instructions);
+
+        if machine.run() == Outcome::Terminated { Some(machine.registers().accumulator) }
+        else { None }
+    }
+    /// The program's control flow graph in Graphviz's DOT language.
+    pub fn to_dot(&self) -> String {
+        ControlFlowGraph::new(&self.set, &self.instructions).to_dot()
+    }
+}
+
+fn read_program(input: &mut Reader) -> Result<Program, ParseError> {
+    let set = InstructionSet::handheld();
+    let mut instructions = Vec::<Instruction>::new();
+
+    while let Some(line) = input.next_line() {
+        instructions.push(set.read(&line)?);
+    }
+
+    if instructions.len() == 0 { Err(input.end("an instruction")) }
+    else { Ok(Program { set, instructions }) }
+}
+
+pub struct HandheldHalting;
+impl Solution for HandheldHalting {
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_program(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(program: &Self::Input) -> Answer {
+        program.execute().1.into()
+    }
+    fn part2(program: &Self::Input) -> Answer {
+        program.patch().map_or(Answer::None, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+    #[test]
+    fn unknown_opcode() {
+        let error = HandheldHalting::parse("nop +0\nhcf +1\n").unwrap_err();
+
+        assert_eq!(error.to_string(), "line 2, column 1: expected nop, jmp or acc, found \"hcf\"");
+    }
+    #[test]
+    fn part1_example() {
+        assert_eq!(HandheldHalting::solve(EXAMPLE, 1), Ok(Answer::from(5)));
+    }
+    #[test]
+    fn loops_back_to_the_second_instruction() {
+        let program = HandheldHalting::parse(EXAMPLE).unwrap();
+
+        match program.execute() {
+            (Outcome::Loop(report), 5) => assert_eq!(report.cycle, vec![1, 2, 6, 7, 3, 4]),
+            other => panic!("expected a loop, got {:?}", other),
+        }
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(HandheldHalting::solve(EXAMPLE, 2), Ok(Answer::from(8)));
+    }
+}
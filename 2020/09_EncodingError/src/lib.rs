@@ -1,5 +1,5 @@
-use std::io;
 use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 struct Window {
@@ -12,7 +12,7 @@ impl Window {
         Self { size: size, values: Vec::<usize>::new(), sums: HashMap::<usize, usize>::new() }
     }
     fn validate(&self, value: usize) -> bool {
-        self.values.len() != self.size || self.sums.get(&value).is_some()
+        self.values.len() != self.size || self.sums.contains_key(&value)
     }
     fn push(&mut self, value: usize) {
         if self.values.len() == self.size {
@@ -43,6 +43,17 @@ impl Window {
     }
 }
 
+fn find_invalid(values: &Vec<usize>, preamble: usize) -> usize {
+    let mut window = Window::new(preamble);
+        
+    for value in values {
+        if !window.validate(*value) { return *value; }
+        window.push(*value);
+    }
+
+    panic!("couldn't find invalid value!");
+}
+
 fn find_sum(values: &Vec<usize>, target: usize) -> usize {
     let mut low_end = 0usize;
     let mut high_end = 1usize;
@@ -66,48 +77,68 @@ fn find_sum(values: &Vec<usize>, target: usize) -> usize {
     panic!("couldn't find sum!");
 }
 
-fn read_values() -> Result<Vec<usize>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_values(input: &mut Reader) -> Result<Vec<usize>, ParseError> {
     let mut values = Vec::<usize>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-
-        values.push(buffer.trim().parse().unwrap());
-        buffer.clear();
+    while let Some(line) = input.next_line() {
+        values.push(line.parse(line.text(), "a number")?);
     }
 
-    if values.len() == 0 { Err(()) }
+    if values.len() == 0 { Err(input.end("a number")) }
     else { Ok(values) }
 }
                     
-fn part1() {
-    if let Ok(values) = read_values() {
-        let mut window = Window::new(25);
-        
-        for value in values {
-            if !window.validate(value) { println!("{}", value); break; }
-            window.push(value);
-        }
+pub struct EncodingError;
+impl Solution for EncodingError {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_values(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
     }
-    else { panic!("couldn't read values!"); }
-}
-                    
-fn part2() {
-    if let Ok(values) = read_values() {
-        let mut window = Window::new(25);
-        
-        for value in &values {
-            if !window.validate(*value) { println!("{}", find_sum(&values, *value)); break; }
-            window.push(*value);
-        }
+    fn part1(values: &Self::Input) -> Answer {
+        find_invalid(values, 25).into()
+    }
+    fn part2(values: &Self::Input) -> Answer {
+        find_sum(values, find_invalid(values, 25)).into()
     }
-    else { panic!("couldn't read values!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+";
+
+    #[test]
+    fn part1_example() {
+        let values = EncodingError::parse(EXAMPLE).unwrap();
+
+        assert_eq!(find_invalid(&values, 5), 127);
+    }
+    #[test]
+    fn part2_example() {
+        let values = EncodingError::parse(EXAMPLE).unwrap();
+
+        assert_eq!(find_sum(&values, 127), 62);
+    }
 }
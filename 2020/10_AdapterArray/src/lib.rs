@@ -1,5 +1,5 @@
-use std::io;
 use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 fn jolt_differences(jolts: &Vec<usize>) -> (usize, usize) {
     let mut sorted_jolts = jolts.clone();
@@ -46,39 +46,92 @@ fn jolt_combos(jolts: &Vec<usize>) -> usize {
     jolt_counts.iter().product()
 }
 
-fn read_jolts() -> Result<Vec<usize>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_jolts(input: &mut Reader) -> Result<Vec<usize>, ParseError> {
     let mut jolts = Vec::<usize>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-        
-        jolts.push(buffer.trim().parse().unwrap());
-        buffer.clear();
+    while let Some(line) = input.next_line() {
+        jolts.push(line.parse(line.text(), "a joltage rating")?);
     }
 
-    if jolts.len() == 0 { Err(()) }
+    if jolts.len() == 0 { Err(input.end("a joltage rating")) }
     else { Ok(jolts) }
 }
         
-fn part1() {
-    if let Ok(jolts) = read_jolts() {
-        let (one, three) = jolt_differences(&jolts);
-        println!("{}", one*three);
+pub struct AdapterArray;
+impl Solution for AdapterArray {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_jolts(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
     }
-    else { panic!("couldn't read jolts!"); }
-}
+    fn part1(jolts: &Self::Input) -> Answer {
+        let (one, three) = jolt_differences(jolts);
         
-fn part2() {
-    if let Ok(jolts) = read_jolts() {
-        println!("{}", jolt_combos(&jolts));
+        (one*three).into()
+    }
+    fn part2(jolts: &Self::Input) -> Answer {
+        jolt_combos(jolts).into()
     }
-    else { panic!("couldn't read jolts!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4
+";
+
+    const LARGE: &str = "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(AdapterArray::solve(SMALL, 1), Ok(Answer::from(7*5)));
+        assert_eq!(AdapterArray::solve(LARGE, 1), Ok(Answer::from(22*10)));
+    }
+    #[test]
+    fn part2_examples() {
+        assert_eq!(AdapterArray::solve(SMALL, 2), Ok(Answer::from(8)));
+        assert_eq!(AdapterArray::solve(LARGE, 2), Ok(Answer::from(19208)));
+    }
 }
@@ -0,0 +1,179 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Grid, Neighborhood};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Tile {
+    Floor,
+    Unoccupied,
+    Occupied,
+}
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Floor),
+            'L' => Some(Self::Unoccupied),
+            '#' => Some(Self::Occupied),
+            _ => None,
+        }
+    }
+    #[allow(dead_code)]
+    fn to_char(&self) -> char {
+        match self {
+            Self::Floor => '.',
+            Self::Unoccupied => 'L',
+            Self::Occupied => '#',
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Layout {
+    tiles: Grid<Tile>,
+    seats: Vec<Coordinate>,
+}
+impl Layout {
+    fn new(tiles: Grid<Tile>) -> Self {
+        let seats = tiles.iter()
+            .filter(|(_, &tile)| tile != Tile::Floor)
+            .map(|(coord, _)| coord)
+            .collect();
+
+        Self { tiles, seats }
+    }
+    
+    fn neighbors_p1(&self, base_coord: Coordinate) -> Vec<Coordinate> {
+        self.tiles.neighbors(base_coord, Neighborhood::Eight)
+    }
+    
+    fn neighbors_p2(&self, base_coord: Coordinate) -> Vec<Coordinate> {
+        Neighborhood::Eight.offsets()
+            .iter()
+            .filter_map(|&(x,y)| self.tiles.ray(base_coord, x, y).find(|&coord| self.tiles[coord] != Tile::Floor))
+            .collect()
+    }
+        
+    fn step_p1(&self) -> (usize, Self) {
+        let mut new_layout = self.clone();
+        let mut changes = 0usize;
+
+        for &coord in &self.seats {
+            let occupied = self.neighbors_p1(coord).iter()
+                .filter(|&&x| self.tiles[x] == Tile::Occupied)
+                .count();
+            
+            match self.tiles[coord] {
+                Tile::Floor => (),
+                Tile::Unoccupied => {
+                    if occupied == 0 {
+                        new_layout.tiles.set(coord, Tile::Occupied);
+                        changes += 1;
+                    }
+                },
+                Tile::Occupied => {
+                    if occupied >= 4 {
+                        new_layout.tiles.set(coord, Tile::Unoccupied);
+                        changes += 1;
+                    }
+                },
+            }
+        }
+
+        (changes, new_layout)
+    }
+        
+    fn step_p2(&self) -> (usize, Self) {
+        let mut new_layout = self.clone();
+        let mut changes = 0usize;
+
+        for &coord in &self.seats {
+            let occupied = self.neighbors_p2(coord).iter()
+                .filter(|&&x| self.tiles[x] == Tile::Occupied)
+                .count();
+            
+            match self.tiles[coord] {
+                Tile::Floor => (),
+                Tile::Unoccupied => {
+                    if occupied == 0 {
+                        new_layout.tiles.set(coord, Tile::Occupied);
+                        changes += 1;
+                    }
+                },
+                Tile::Occupied => {
+                    if occupied >= 5 {
+                        new_layout.tiles.set(coord, Tile::Unoccupied);
+                        changes += 1;
+                    }
+                },
+            }
+        }
+
+        (changes, new_layout)
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        print!("{}", self.tiles.render(Tile::to_char));
+    }
+}
+
+fn read_layout(input: &mut Reader) -> Result<Layout, ParseError> {
+    Ok(Layout::new(Grid::read(input, "'.', 'L' or '#'", Tile::from_char)?))
+}
+
+fn count_seats(part: bool, layout: &Layout) -> usize {
+    let mut layout_state = layout.clone();
+
+    loop {
+        let (changes, new_layout);
+
+        if part { let state = layout_state.step_p2(); changes = state.0; new_layout = state.1; }
+        else { let state = layout_state.step_p1(); changes = state.0; new_layout = state.1; }
+
+        if changes == 0 { return layout_state.seats.iter()
+                          .filter(|&&x| new_layout.tiles[x] == Tile::Occupied)
+                          .count(); }
+        
+        layout_state = new_layout;
+    }
+}
+                    
+pub struct SeatingSystem;
+impl Solution for SeatingSystem {
+    type Input = Layout;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_layout(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(layout: &Self::Input) -> Answer {
+        count_seats(false, layout).into()
+    }
+    fn part2(layout: &Self::Input) -> Answer {
+        count_seats(true, layout).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(SeatingSystem::solve(EXAMPLE, 1), Ok(Answer::from(37)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(SeatingSystem::solve(EXAMPLE, 2), Ok(Answer::from(26)));
+    }
+}
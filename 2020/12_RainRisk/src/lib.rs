@@ -1,5 +1,5 @@
-use std::io;
 use std::ops::{Add, Mul};
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 struct Coordinate(isize, isize);
@@ -33,7 +33,7 @@ impl Mul for Coordinate {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-enum Heading {
+pub enum Heading {
     North,
     South,
     East,
@@ -81,34 +81,27 @@ impl Heading {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-enum Action {
+pub enum Action {
     Heading(Heading, isize),
     RotateLeft(usize),
     RotateRight(usize),
     Forward(isize),
 }
 impl Action {
-    fn from_str(s: &str) -> Self {
-        let mut chars: Vec<char> = s.chars().collect();
-        chars.reverse();
-
-        let operand = chars.pop().unwrap();
-        let mut value = 0usize;
-
-        while let Some(c) = chars.pop() {
-            value *= 10;
-            value += c as usize - '0' as usize;
-        }
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let mut chars = s.chars();
+        let operand = chars.next().ok_or(line.error(s, "an action like F10"))?;
+        let value = line.parse::<usize>(chars.as_str(), "a distance or angle")?;
 
         match operand {
-            'N' => Self::Heading(Heading::North, value as isize),
-            'S' => Self::Heading(Heading::South, value as isize),
-            'E' => Self::Heading(Heading::East, value as isize),
-            'W' => Self::Heading(Heading::West, value as isize),
-            'L' => Self::RotateLeft(value),
-            'R' => Self::RotateRight(value),
-            'F' => Self::Forward(value as isize),
-            _ => panic!("bad operand: {}", operand)
+            'N' => Ok(Self::Heading(Heading::North, value as isize)),
+            'S' => Ok(Self::Heading(Heading::South, value as isize)),
+            'E' => Ok(Self::Heading(Heading::East, value as isize)),
+            'W' => Ok(Self::Heading(Heading::West, value as isize)),
+            'L' => Ok(Self::RotateLeft(value)),
+            'R' => Ok(Self::RotateRight(value)),
+            'F' => Ok(Self::Forward(value as isize)),
+            _ => Err(line.error(&s[..operand.len_utf8()], "one of N, S, E, W, L, R or F")),
         }
     }
 }
@@ -170,44 +163,55 @@ impl Ship {
     }
 }
 
-fn read_actions() -> Result<Vec<Action>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_actions(input: &mut Reader) -> Result<Vec<Action>, ParseError> {
     let mut actions = Vec::<Action>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-
-        actions.push(Action::from_str(buffer.trim()));
-        buffer.clear();
+    while let Some(line) = input.next_line() {
+        actions.push(Action::from_str(line.text(), &line)?);
     }
 
-    if actions.len() == 0 { Err(()) }
+    if actions.len() == 0 { Err(input.end("an action")) }
     else { Ok(actions) }
 }
 
-fn part1() {
-    if let Ok(actions) = read_actions() {
-        let mut ship = Ship::new();
-        ship.perform_p1(&actions);
+pub struct RainRisk;
+impl Solution for RainRisk {
+    type Input = Vec<Action>;
 
-        println!("{}", ship.manhattan());
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_actions(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
     }
-    else { panic!("couldn't read actions!"); }
-}
+    fn part1(actions: &Self::Input) -> Answer {
+        let mut ship = Ship::new();
+        ship.perform_p1(actions);
 
-fn part2() {
-    if let Ok(actions) = read_actions() {
+        ship.manhattan().into()
+    }
+    fn part2(actions: &Self::Input) -> Answer {
         let mut ship = Ship::new();
-        ship.perform_p2(&actions);
+        ship.perform_p2(actions);
 
-        println!("{}", ship.manhattan());
+        ship.manhattan().into()
     }
-    else { panic!("couldn't read actions!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "F10
+N3
+F7
+R90
+F11
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(RainRisk::solve(EXAMPLE, 1), Ok(Answer::from(25)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(RainRisk::solve(EXAMPLE, 2), Ok(Answer::from(286)));
+    }
 }
@@ -0,0 +1,78 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn read_schedule(input: &mut Reader) -> Result<(usize, Vec<usize>), ParseError> {
+    let mut schedule = Vec::<usize>::new();
+
+    let line = input.expect_line("a timestamp")?;
+    let timestamp = line.parse(line.text(), "a timestamp")?;
+
+    let line = input.expect_line("a list of buses")?;
+
+    for bus in line.text().split(",") {
+        if bus == "x" { schedule.push(0); }
+        else { schedule.push(line.parse(bus, "a bus id or x")?); }
+    }
+
+    Ok((timestamp, schedule))
+}
+
+pub struct ShuttleSearch;
+impl Solution for ShuttleSearch {
+    type Input = (usize, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_schedule(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1((timestamp, schedule): &Self::Input) -> Answer {
+        let mut best_bus = 0usize;
+        let mut best_time = usize::MAX;
+
+        for &bus_id in schedule {
+            if bus_id == 0 { continue; }
+        
+            let bus_time = (0..timestamp+bus_id).step_by(bus_id).next_back().unwrap();
+            if bus_time < best_time { best_time = bus_time; best_bus = bus_id; }
+        }
+
+        ((best_time - timestamp) * best_bus).into()
+    }
+    fn part2((_, schedule): &Self::Input) -> Answer {
+        let mut timestamp = 0;
+        let mut period = 1;
+
+        for phase in 0..schedule.len() {
+            if schedule[phase] == 0 { continue; }
+            
+            while (timestamp+phase) % schedule[phase] != 0 {
+                timestamp += period;
+            }
+
+            period *= schedule[phase];
+        }
+
+        timestamp.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "939
+7,13,x,x,59,x,31,19
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(ShuttleSearch::solve(EXAMPLE, 1), Ok(Answer::from(295)));
+    }
+    #[test]
+    fn part2_examples() {
+        assert_eq!(ShuttleSearch::solve(EXAMPLE, 2), Ok(Answer::from(1068781)));
+        assert_eq!(ShuttleSearch::solve("939\n17,x,13,19\n", 2), Ok(Answer::from(3417)));
+        assert_eq!(ShuttleSearch::solve("939\n67,7,59,61\n", 2), Ok(Answer::from(754018)));
+        assert_eq!(ShuttleSearch::solve("939\n67,x,7,59,61\n", 2), Ok(Answer::from(779210)));
+        assert_eq!(ShuttleSearch::solve("939\n67,7,x,59,61\n", 2), Ok(Answer::from(1261476)));
+        assert_eq!(ShuttleSearch::solve("939\n1789,37,47,1889\n", 2), Ok(Answer::from(1202161486)));
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Bitmask {
+    mask: usize,
+    value: usize,
+    address: usize,
+}
+impl Bitmask {
+    fn new() -> Self {
+        Self { mask: 0, value: 0, address: 0 }
+    }
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let mut mask = 0usize;
+        let mut value = 0usize;
+        let mut address = 0usize;
+
+        for (i, c) in s.char_indices() {
+            mask <<= 1;
+            value <<= 1;
+            address <<= 1;
+            
+            match c {
+                '0' => { mask |= 1; },
+                '1' => { value |= 1; mask |= 1; },
+                'X' => { address |= 1; },
+                _ => return Err(line.error(&s[i..i+c.len_utf8()], "'0', '1' or 'X'")),
+            }
+        }
+
+        Ok(Self { mask, value, address })
+    }
+    fn mask(&self, v: usize) -> usize {
+        (v & !self.mask) | self.value
+    }
+    
+    fn address_offsets(&self) -> Vec<usize> {
+        let mut result = Vec::<usize>::new();
+        let mut address = self.address;
+        let mut offset = 0usize;
+
+        while address > 0 {
+            if address & 1 == 1 { result.push(offset); }
+            
+            address >>= 1;
+            offset += 1;
+        }
+
+        result
+    }
+    fn address_max(&self) -> usize {
+        2usize.pow(self.address_offsets().len() as u32)
+    }
+    fn address_index(&self, value: usize) -> usize {
+        let max = self.address_max();
+
+        if value >= max { panic!("value out of range: {}", value); }
+
+        let mut offsets = self.address_offsets();
+        let mut bits = value;
+        let mut result = 0usize;
+        
+        offsets.reverse();
+
+        while let Some(index) = offsets.pop() {
+            result |= (bits & 1) << index;
+            bits >>= 1;
+        }
+
+        result
+    }
+    fn write(&self, address: usize, value: usize, memory: &mut HashMap<usize, usize>) {
+        let base_address = (address | self.value) & !self.address;
+
+        for index in 0..self.address_max() {
+            memory.insert(base_address | self.address_index(index), value);
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Command {
+    Mask(Bitmask),
+    Write(usize, usize),
+}
+
+fn run_p1(program: &Vec<Command>) -> HashMap<usize, usize> {
+    let mut bitmask = Bitmask::new();
+    let mut memory = HashMap::<usize, usize>::new();
+
+    for command in program {
+        match *command {
+            Command::Mask(mask) => bitmask = mask,
+            Command::Write(address, value) => { memory.insert(address, bitmask.mask(value)); },
+        }
+    }
+
+    memory
+}
+
+fn run_p2(program: &Vec<Command>) -> HashMap<usize, usize> {
+    let mut bitmask = Bitmask::new();
+    let mut memory = HashMap::<usize, usize>::new();
+
+    for command in program {
+        match *command {
+            Command::Mask(mask) => bitmask = mask,
+            Command::Write(address, value) => bitmask.write(address, value, &mut memory),
+        }
+    }
+
+    memory
+}
+
+fn read_program(input: &mut Reader) -> Result<Vec<Command>, ParseError> {
+    let mut program = Vec::<Command>::new();
+
+    while let Some(line) = input.next_line() {
+        let (target, value) = line.split_once(line.text(), " = ", "mask = <bits> or mem[<address>] = <value>")?;
+
+        if target == "mask" { program.push(Command::Mask(Bitmask::from_str(value, &line)?)); }
+        else {
+            let address = target.strip_prefix("mem[")
+                .and_then(|x| x.strip_suffix("]"))
+                .ok_or(line.error(target, "mask or mem[<address>]"))?;
+            
+            program.push(Command::Write(line.parse(address, "an address")?, line.parse(value, "a value")?));
+        }
+            
+    }
+
+    if program.len() == 0 { Err(input.end("a mask or write")) }
+    else { Ok(program) }
+}
+
+pub struct DockingData;
+impl Solution for DockingData {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_program(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(program: &Self::Input) -> Answer {
+        run_p1(program).values().sum::<usize>().into()
+    }
+    fn part2(program: &Self::Input) -> Answer {
+        run_p2(program).values().sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_P1: &str = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0
+";
+
+    const EXAMPLE_P2: &str = "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(DockingData::solve(EXAMPLE_P1, 1), Ok(Answer::from(165)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(DockingData::solve(EXAMPLE_P2, 2), Ok(Answer::from(208)));
+    }
+}
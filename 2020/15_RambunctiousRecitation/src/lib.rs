@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn read_numbers(input: &mut Reader) -> Result<Vec<usize>, ParseError> {
+    let line = input.expect_line("the starting numbers")?;
+
+    line.text()
+        .split(",")
+        .map(|x| line.parse(x, "a comma-separated number"))
+        .collect()
+}
+
+fn play_game(start: &Vec<usize>, stop: usize) -> usize {
+    let mut turn = 1usize;
+    let mut priors = HashMap::<usize, (Option<usize>, Option<usize>)>::new();
+    let mut last = 0usize;
+
+    for init in start {
+        let entry = priors.entry(*init).or_insert((None, None));
+
+        (*entry).0 = Some(turn);
+        last = *init;
+        turn += 1;
+    }
+
+    while turn <= stop {
+        let prior = priors.entry(last).or_insert((None, None));
+        let new;
+
+        if prior.1.is_none() { new = 0usize; }
+        else { new = prior.0.clone().unwrap() - prior.1.clone().unwrap(); }
+
+        let entry = priors.entry(new).or_insert((None, None));
+
+        if entry.0.is_some() { (*entry).1 = entry.0; }
+        (*entry).0 = Some(turn);
+
+        last = new;
+        turn += 1;
+    }
+
+    last
+}
+    
+pub struct RambunctiousRecitation;
+impl Solution for RambunctiousRecitation {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_numbers(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(numbers: &Self::Input) -> Answer {
+        play_game(numbers, 2020).into()
+    }
+    fn part2(numbers: &Self::Input) -> Answer {
+        play_game(numbers, 30000000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(RambunctiousRecitation::solve("0,3,6\n", 1), Ok(Answer::from(436)));
+        assert_eq!(play_game(&vec![1,3,2], 2020), 1);
+        assert_eq!(play_game(&vec![2,1,3], 2020), 10);
+        assert_eq!(play_game(&vec![1,2,3], 2020), 27);
+        assert_eq!(play_game(&vec![2,3,1], 2020), 78);
+        assert_eq!(play_game(&vec![3,2,1], 2020), 438);
+        assert_eq!(play_game(&vec![3,1,2], 2020), 1836);
+    }
+    #[test]
+    fn early_turns() {
+        assert_eq!((4..=10).map(|x| play_game(&vec![0,3,6], x)).collect::<Vec<usize>>(), vec![0,3,3,1,0,4,0]);
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive as Range;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+fn read_range(s: &str, line: &Line) -> Result<Range<usize>, ParseError> {
+    let (start, end) = line.split_once(s, "-", "a range like 1-3")?;
+
+    Ok(Range::<usize>::new(line.parse(start, "a number")?, line.parse(end, "a number")?))
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TicketRule {
+    label: String,
+    ranges: [Range<usize>; 2]
+}
+impl TicketRule {
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let (label, ranges) = line.split_once(s, ": ", "a rule like \"class: 1-3 or 5-7\"")?;
+        let (left, right) = line.split_once(ranges, " or ", "two ranges like 1-3 or 5-7")?;
+
+        Ok(Self { label: label.to_string(), ranges: [read_range(left, line)?, read_range(right, line)?] })
+    }
+    fn contains(&self, value: usize) -> bool {
+        self.ranges[0].contains(&value) || self.ranges[1].contains(&value)
+    }
+}
+
+pub type Ticket = Vec<usize>;
+
+fn validate_ticket(rules: &Vec<TicketRule>, ticket: &Ticket) -> Option<usize> {
+    if ticket.len() != rules.len() { panic!("bad ticket: {:?}", ticket); }
+
+    for value in ticket {
+        let valid = rules.iter()
+            .map(|x| x.contains(*value))
+            .filter(|&x| x == true)
+            .count() > 0;
+        
+        if !valid { return Some(*value); }
+    }
+
+    None
+}
+
+fn identify_fields(rules: &Vec<TicketRule>, my_ticket: &Ticket, nearby_tickets: &Vec<Ticket>) -> HashMap<usize, TicketRule> {
+    let mut result = HashMap::<usize, TicketRule>::new();
+    let mut found_rules = HashSet::<usize>::new();
+
+    loop {
+        for index in 0..rules.len() {
+            if result.contains_key(&index) { continue; }
+
+            let mut reduction: HashSet<usize> = rules.iter()
+                .enumerate()
+                .filter(|(i, _)| !found_rules.contains(&i))
+                .filter(|(_, x)| x.contains(my_ticket[index]))
+                .map(|(i, _)| i)
+                .collect();
+
+            for ticket in nearby_tickets {
+                reduction = reduction.intersection(&rules.iter()
+                                                   .enumerate()
+                                                   .filter(|(i, _)| !found_rules.contains(&i))
+                                                   .filter(|(_, x)| x.contains(ticket[index]))
+                                                   .map(|(i, _)| i)
+                                                   .collect())
+                    .copied()
+                    .collect();
+            }
+
+            if reduction.len() == 1 {
+                let found_index = *reduction.iter().next().unwrap();
+                
+                result.insert(index, rules[found_index].clone());
+                found_rules.insert(found_index);
+            }
+        }
+
+        if result.len() == rules.len() { return result; }
+    }
+}
+
+fn read_ticket(line: &Line, fields: usize) -> Result<Ticket, ParseError> {
+    let mut ticket = Ticket::new();
+
+    for value in line.text().split(",") {
+        ticket.push(line.parse(value, "a comma-separated ticket value")?);
+    }
+
+    if ticket.len() != fields {
+        return Err(line.error(line.text(), &format!("a ticket with {} values", fields)));
+    }
+
+    Ok(ticket)
+}
+
+fn read_ticket_info(input: &mut Reader) -> Result<(Vec<TicketRule>, Ticket, Vec<Ticket>), ParseError> {
+    let mut rules = Vec::<TicketRule>::new();
+    let mut my_ticket = Ticket::new();
+    let mut nearby_tickets = Vec::<Ticket>::new();
+
+    let group = input.next_group().ok_or(input.end("a ticket rule"))?;
+
+    for line in group.lines() {
+        rules.push(TicketRule::from_str(line.text(), &line)?);
+    }
+
+    let group = input.next_group().ok_or(input.end("your ticket"))?;
+
+    for line in group.lines() {
+        if line.text() != "your ticket:" { my_ticket = read_ticket(&line, rules.len())?; }
+    }
+
+    if my_ticket.len() == 0 { return Err(ParseError::unexpected_end(group.last().number()+1, "your ticket")); }
+
+    if let Some(group) = input.next_group() {
+        for line in group.lines() {
+            if line.text() != "nearby tickets:" { nearby_tickets.push(read_ticket(&line, rules.len())?); }
+        }
+    }
+
+    if nearby_tickets.len() == 0 { Err(input.end("a nearby ticket")) }
+    else { Ok((rules, my_ticket, nearby_tickets)) }
+}
+    
+pub struct TicketTranslation;
+impl Solution for TicketTranslation {
+    type Input = (Vec<TicketRule>, Ticket, Vec<Ticket>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_ticket_info(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1((rules, _, nearby_tickets): &Self::Input) -> Answer {
+        nearby_tickets.iter()
+            .filter_map(|x| validate_ticket(rules, x))
+            .sum::<usize>()
+            .into()
+    }
+    fn part2((rules, my_ticket, nearby_tickets): &Self::Input) -> Answer {
+        let valid_tickets: Vec<Ticket> = nearby_tickets.iter()
+            .filter(|x| validate_ticket(rules, x).is_none())
+            .cloned()
+            .collect();
+        let fields = identify_fields(rules, my_ticket, &valid_tickets);
+
+        fields.iter()
+            .filter(|(_,x)| x.label.contains("departure"))
+            .map(|(i,_)| my_ticket[*i])
+            .product::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_P1: &str = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12
+";
+
+    const EXAMPLE_P2: &str = "class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(TicketTranslation::solve(EXAMPLE_P1, 1), Ok(Answer::from(71)));
+    }
+    #[test]
+    fn part2_example() {
+        let (rules, my_ticket, nearby_tickets) = TicketTranslation::parse(EXAMPLE_P2).unwrap();
+        let fields = identify_fields(&rules, &my_ticket, &nearby_tickets);
+        let mut labels: Vec<(usize, &str)> = fields.iter().map(|(i,x)| (my_ticket[*i], x.label.as_str())).collect();
+        labels.sort();
+
+        assert_eq!(labels, vec![(11, "row"), (12, "class"), (13, "seat")]);
+    }
+    #[test]
+    fn malformed_ticket_info() {
+        let bad_rule = EXAMPLE_P1.replace("row: 6-11", "row: 6..11");
+        let short_ticket = EXAMPLE_P1.replace("40,4,50", "40,4");
+        let truncated = EXAMPLE_P1.split("nearby").next().unwrap();
+
+        assert_eq!(TicketTranslation::parse(&bad_rule).unwrap_err().to_string(), "line 2, column 6: expected a range like 1-3, found \"6..11\"");
+        assert_eq!(TicketTranslation::parse(&short_ticket).unwrap_err().to_string(), "line 10, column 1: expected a ticket with 3 values, found \"40,4\"");
+        assert_eq!(TicketTranslation::parse(truncated).unwrap_err().to_string(), "line 8: input ended, expected a nearby ticket");
+    }
+}
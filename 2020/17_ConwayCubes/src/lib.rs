@@ -1,6 +1,6 @@
-use std::io;
 use std::collections::{HashMap, HashSet};
 use std::ops::RangeInclusive;
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 struct Coordinate {
@@ -75,13 +75,14 @@ enum Cube {
     Inactive,
 }
 impl Cube {
-    fn from_char(c: char) -> Self {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '#' => Self::Active,
-            '.' => Self::Inactive,
-            _ => panic!("bad cube: {}", c),
+            '#' => Some(Self::Active),
+            '.' => Some(Self::Inactive),
+            _ => None,
         }
     }
+    #[allow(dead_code)]
     fn to_char(&self) -> char {
         match self {
             Self::Active => '#',
@@ -91,7 +92,7 @@ impl Cube {
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct PocketDimension {
+pub struct PocketDimension {
     cubes: HashMap<Coordinate, Cube>,
     min: Coordinate,
     max: Coordinate,
@@ -113,6 +114,18 @@ impl PocketDimension {
             }
         }
     }
+    fn expand(&self, dimensions: usize) -> Self {
+        let mut expanded = Self::new(dimensions);
+
+        for (coord, cube) in &self.cubes {
+            let mut new_coord = Coordinate::new(dimensions);
+            new_coord.from_vec(&coord.coords);
+
+            expanded.set_cube(&new_coord, *cube);
+        }
+
+        expanded
+    }
     fn get_cube(&self, coord: &Coordinate) -> Cube {
         if let Some(cube) = self.cubes.get(coord) { *cube }
         else { Cube::Inactive }
@@ -148,50 +161,63 @@ impl PocketDimension {
     }
 }
 
-fn read_cubes(dimensions: usize) -> Result<PocketDimension, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_cubes(input: &mut Reader, dimensions: usize) -> Result<PocketDimension, ParseError> {
     let mut dimension = PocketDimension::new(dimensions);
     let mut y = 0isize;
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-        
-        for (i,x) in buffer.trim().chars().enumerate() {
+    while let Some(line) = input.next_line() {
+
+        for (i,x) in line.map_chars("'#' or '.'", Cube::from_char)?.into_iter().enumerate() {
             let mut new_coord = Coordinate::new(dimensions);
             new_coord.from_vec(&[i as isize, y].to_vec());
 
-            dimension.set_cube(&new_coord, Cube::from_char(x));
+            dimension.set_cube(&new_coord, x);
         }
 
         y += 1;
-        buffer.clear();
     }
 
-    if dimension.cubes.len() == 0 { Err(()) }
+    if dimension.cubes.len() == 0 { Err(input.end("a slice of cubes")) }
     else { Ok(dimension) }
 }
 
-fn part1() {
-    if let Ok(mut dimension) = read_cubes(3) {
-        (0..6).for_each(|_| dimension = dimension.step());
-        
-        println!("{}", dimension.cubes.values().filter(|&x| *x == Cube::Active).count());
-    }
-    else { panic!("couldn't read cubes!"); }
+fn boot(dimension: &PocketDimension, dimensions: usize) -> usize {
+    let mut dimension = dimension.expand(dimensions);
+    (0..6).for_each(|_| dimension = dimension.step());
+
+    dimension.cubes.values().filter(|&x| *x == Cube::Active).count()
 }
 
-fn part2() {
-    if let Ok(mut dimension) = read_cubes(4) {
-        (0..6).for_each(|_| dimension = dimension.step());
-        
-        println!("{}", dimension.cubes.values().filter(|&x| *x == Cube::Active).count());
+pub struct ConwayCubes;
+impl Solution for ConwayCubes {
+    type Input = PocketDimension;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_cubes(&mut Reader::new(input.as_bytes()), 2).map_err(Error::Parse)
+    }
+    fn part1(dimension: &Self::Input) -> Answer {
+        boot(dimension, 3).into()
+    }
+    fn part2(dimension: &Self::Input) -> Answer {
+        boot(dimension, 4).into()
     }
-    else { panic!("couldn't read cubes!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ".#.
+..#
+###
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(ConwayCubes::solve(EXAMPLE, 1), Ok(Answer::from(112)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(ConwayCubes::solve(EXAMPLE, 2), Ok(Answer::from(848)));
+    }
 }
@@ -1,4 +1,4 @@
-use std::io;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 enum Operator {
@@ -27,7 +27,7 @@ impl Operand {
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Expression {
+pub struct Expression {
     operands: Vec<Operand>,
     operators: Vec<Operator>,
 }
@@ -35,14 +35,17 @@ impl Expression {
     fn new() -> Self {
         Self { operands: Vec::<Operand>::new(), operators: Vec::<Operator>::new(), }
     }
-    fn from_str(s: &str) -> Self {
-        let mut tokens: Vec<char> = s.chars().rev().collect();
+    fn is_complete(&self) -> bool {
+        self.operands.len() == self.operators.len()+1
+    }
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
         let mut expression_stack = Vec::<Expression>::new();
 
         expression_stack.push(Expression::new());
 
-        while let Some(token) = tokens.pop() {
+        for (i, token) in s.char_indices() {
             let mut expression_id = expression_stack.len()-1;
+            let token_str = &s[i..i+token.len_utf8()];
                      
             if token == ' ' {
                 continue;
@@ -57,7 +60,12 @@ impl Expression {
                 expression_stack.push(Expression::new());
             }
             else if token == ')' {
+                if expression_stack.len() == 1 { return Err(line.error(token_str, "a '(' before each ')'")); }
+                
                 let subexpression = expression_stack.pop().unwrap();
+
+                if !subexpression.is_complete() { return Err(line.error(token_str, "an operand after each operator")); }
+                
                 expression_id = expression_stack.len()-1;
                 
                 let expression = &mut expression_stack[expression_id];
@@ -70,10 +78,18 @@ impl Expression {
 
                 expression.operands.push(operand);
             }
-            else { panic!("bad token: {}", token); }
+            else { return Err(line.error(token_str, "a digit, operator or parenthesis")); }
         }
 
-        expression_stack.pop().unwrap()
+        let end = &s[s.len()..];
+
+        if expression_stack.len() > 1 { return Err(line.error(end, "a ')' for each '('")); }
+
+        let expression = expression_stack.pop().unwrap();
+
+        if !expression.is_complete() { return Err(line.error(end, "an operand after each operator")); }
+
+        Ok(expression)
     }
     fn eval_p1(&self) -> usize {
         let mut result = 0usize;
@@ -114,38 +130,66 @@ impl Expression {
     }
 }
 
-fn read_expressions() -> Result<Vec<Expression>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_expressions(input: &mut Reader) -> Result<Vec<Expression>, ParseError> {
     let mut expressions = Vec::<Expression>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-
-        expressions.push(Expression::from_str(buffer.trim()));
-        buffer.clear();
+    while let Some(line) = input.next_line() {
+        expressions.push(Expression::from_str(line.text(), &line)?);
     }
 
-    if expressions.len() == 0 { Err(()) }
+    if expressions.len() == 0 { Err(input.end("an expression")) }
     else { Ok(expressions) }
 }
         
-fn part1() {
-    if let Ok(expressions) = read_expressions() {
-        println!("{}", expressions.iter().map(|x| x.eval_p1()).sum::<usize>());
+pub struct OperationOrder;
+impl Solution for OperationOrder {
+    type Input = Vec<Expression>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_expressions(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(expressions: &Self::Input) -> Answer {
+        expressions.iter().map(|x| x.eval_p1()).sum::<usize>().into()
+    }
+    fn part2(expressions: &Self::Input) -> Answer {
+        expressions.iter().map(|x| x.eval_p2()).sum::<usize>().into()
     }
-    else { panic!("couldn't read expressions!"); }
 }
 
-fn part2() {
-    if let Ok(expressions) = read_expressions() {
-        println!("{}", expressions.iter().map(|x| x.eval_p2()).sum::<usize>());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize, usize); 6] = [
+        ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+        ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+        ("2 * 3 + (4 * 5)", 26, 46),
+        ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+        ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+        ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 13632, 23340),
+    ];
+
+    fn read(s: &str) -> Result<Expression, ParseError> {
+        Expression::from_str(s, &Line::new(1, s))
     }
-    else { panic!("couldn't read expressions!"); }
-}
 
-fn main() {
-    // part1();
-    part2();
+    #[test]
+    fn part1_examples() {
+        for (expression, p1, _) in &EXAMPLES {
+            assert_eq!(read(expression).unwrap().eval_p1(), *p1, "{}", expression);
+        }
+    }
+    #[test]
+    fn part2_examples() {
+        for (expression, _, p2) in &EXAMPLES {
+            assert_eq!(read(expression).unwrap().eval_p2(), *p2, "{}", expression);
+        }
+    }
+    #[test]
+    fn malformed_expressions() {
+        assert_eq!(read("1 + (2 * 3").unwrap_err().to_string(), "line 1, column 11: expected a ')' for each '(', found \"\"");
+        assert_eq!(read("1 + 2) * 3").unwrap_err().to_string(), "line 1, column 6: expected a '(' before each ')', found \")\"");
+        assert_eq!(read("1 + (2 *) * 3").unwrap_err().to_string(), "line 1, column 9: expected an operand after each operator, found \")\"");
+        assert_eq!(read("1 - 2").unwrap_err().to_string(), "line 1, column 3: expected a digit, operator or parenthesis, found \"-\"");
+    }
 }
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+type RuleID = usize;
+
+fn read_rule_ids(s: &str, line: &Line) -> Result<Vec<RuleID>, ParseError> {
+    s.split(" ").map(|x| line.parse(x, "a rule number")).collect()
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum Rule {
+    Constant(String),
+    Ruleset(Vec<RuleID>),
+    Branch(Vec<RuleID>,Vec<RuleID>),
+}
+impl Rule {
+    fn from_str(s: &str, line: &Line) -> Result<(RuleID, Self), ParseError> {
+        let (rule_id, body) = line.split_once(s, ": ", "a rule like 0: 4 1 5")?;
+        let rule_id = line.parse::<RuleID>(rule_id, "a rule number")?;
+
+        if body.contains("\"") {
+            let constant = body.strip_prefix("\"")
+                .and_then(|x| x.strip_suffix("\""))
+                .ok_or(line.error(body, "a quoted string like \"a\""))?;
+            
+            return Ok((rule_id, Self::Constant(constant.to_string())));
+        }
+        else if body.contains("|") {
+            let (left, right) = line.split_once(body, " | ", "two alternatives like 1 2 | 3")?;
+
+            return Ok((rule_id, Self::Branch(read_rule_ids(left, line)?, read_rule_ids(right, line)?)));
+        }
+
+        Ok((rule_id, Self::Ruleset(read_rule_ids(body, line)?)))
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Engine {
+    rules: HashMap<RuleID, Rule>,
+}
+impl Engine {
+    fn new() -> Self {
+        Self { rules: HashMap::<RuleID, Rule>::new() }
+    }
+    fn add_rule(&mut self, id: RuleID, rule: &Rule) {
+        self.rules.insert(id, rule.clone());
+    }
+    fn get_rule(&self, id: RuleID) -> &Rule {
+        self.rules.get(&id).unwrap()
+    }
+    fn matches(&self, string: &String) -> bool {
+        let mut stack = Vec::<(Vec<Vec<RuleID>>, usize)>::new();
+
+        stack.push((vec![vec![0 as RuleID]], 0));
+
+        while let Some((mut rule_stack, mut consumed)) = stack.pop() {
+            if consumed >= string.len() && rule_stack.len() > 0 { continue; }
+            else if rule_stack.len() == 0 {
+                if consumed == string.len() { return true; }
+                else { continue; }
+            }
+            
+            let last_rules = rule_stack.len()-1;
+            let rules = &mut rule_stack[last_rules];
+            let rule_id = rules[0];
+            rules.remove(0);
+
+            if rules.len() == 0 { rule_stack.pop(); }
+
+            match self.get_rule(rule_id) {
+                Rule::Constant(s) => {
+                    if *s != string[consumed..consumed+s.len()] { continue; }
+
+                    consumed += s.len();
+                    stack.push((rule_stack, consumed));
+                },
+                Rule::Ruleset(r) => {
+                    rule_stack.push(r.clone());
+                    stack.push((rule_stack, consumed));
+                },
+                Rule::Branch(left,right) => {
+                    let mut left_stack = rule_stack.clone();
+                    let mut right_stack = rule_stack.clone();
+
+                    left_stack.push(left.clone());
+                    right_stack.push(right.clone());
+
+                    stack.push((right_stack, consumed));
+                    stack.push((left_stack, consumed));
+                },
+            }
+        }
+
+        false
+    }
+}
+
+fn read_rules(input: &mut Reader) -> Result<(Engine, Vec<String>), ParseError> {
+    let mut engine = Engine::new();
+    let mut strings = Vec::<String>::new();
+
+    let rules = input.next_group().ok_or(input.end("a rule"))?;
+
+    for line in rules.lines() {
+        let (rule_id, rule) = Rule::from_str(line.text(), &line)?;
+        engine.add_rule(rule_id, &rule);
+    }
+
+    while let Some(line) = input.next_line() {
+        strings.push(line.text().to_string());
+    }
+
+    if strings.len() == 0 { Err(input.end("a message")) }
+    else { Ok((engine, strings)) }
+}
+
+pub struct MonsterMessages;
+impl Solution for MonsterMessages {
+    type Input = (Engine, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_rules(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1((engine, strings): &Self::Input) -> Answer {
+        strings.iter().map(|x| engine.matches(x)).filter(|x| *x == true).count().into()
+    }
+    fn part2((engine, strings): &Self::Input) -> Answer {
+        let mut engine = engine.clone();
+        engine.add_rule(8, &Rule::Branch(vec![42], vec![42, 8]));
+        engine.add_rule(11, &Rule::Branch(vec![42, 31], vec![42, 11, 31]));
+        
+        strings.iter().map(|x| engine.matches(x)).filter(|x| *x == true).count().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb
+";
+
+    const LOOPING: &str = "42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(MonsterMessages::solve(EXAMPLE, 1), Ok(Answer::from(2)));
+        assert_eq!(MonsterMessages::solve(LOOPING, 1), Ok(Answer::from(3)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(MonsterMessages::solve(LOOPING, 2), Ok(Answer::from(12)));
+    }
+    #[test]
+    fn malformed_rules() {
+        let bad_branch = EXAMPLE.replace("2 3 | 3 2", "2 3 | 3 b");
+        let bad_constant = EXAMPLE.replace("\"a\"", "\"a");
+
+        assert_eq!(MonsterMessages::parse(&bad_branch).unwrap_err().to_string(), "line 2, column 12: expected a rule number, found \"b\"");
+        assert_eq!(MonsterMessages::parse(&bad_constant).unwrap_err().to_string(), "line 5, column 4: expected a quoted string like \"a\", found \"\\\"a\"");
+    }
+}
@@ -1,5 +1,5 @@
-use std::io;
 use std::collections::{HashMap, HashSet};
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 struct Image {
@@ -128,6 +128,7 @@ impl Image {
         max_monsters
     }
         
+    #[allow(dead_code)]
     fn print(&self) {
         for row in &self.pixels {
             println!("{}", row.iter().collect::<String>());
@@ -184,7 +185,7 @@ impl Tile {
         self.neighbors.insert(border, id);
     }
     fn has_neighbor(&self, border: Border) -> bool {
-        self.neighbors.get(&border).is_some()
+        self.neighbors.contains_key(&border)
     }
     fn get_neighbor(&self, border: Border) -> Option<&TileID> {
         self.neighbors.get(&border)
@@ -295,13 +296,14 @@ impl Tile {
 
         result
     }
+    #[allow(dead_code)]
     fn print(&self) {
         self.image.print();
     }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Puzzle {
+pub struct Puzzle {
     tiles: HashMap<TileID, Tile>
 }
 impl Puzzle {
@@ -527,55 +529,57 @@ impl Puzzle {
     }
 }
 
-fn read_tiles() -> Result<Puzzle, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_tiles(input: &mut Reader) -> Result<Puzzle, ParseError> {
     let mut result = Puzzle::new();
-    let mut current_id = 0usize;
-    let mut current_tile = Tile::new();
-
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 || size == 1 {
-            if current_tile.size().0 > 0 && current_tile.size().1 > 0 {
-                result.add_tile(current_id, &current_tile);
-                current_tile = Tile::new();
-            }
-            
-            buffer.clear();
 
-            if size == 0 { break; }
-            else { continue; }
-        }
+    while let Some(group) = input.next_group() {
+        let header = group.first();
+        let id = header.text().strip_prefix("Tile ")
+            .and_then(|x| x.strip_suffix(":"))
+            .ok_or(header.error(header.text(), "a header like \"Tile 2311:\""))?;
+        let id = header.parse(id, "a tile number")?;
+        let mut tile = Tile::new();
 
-        if buffer.contains("Tile") {
-            current_id = buffer.trim().replace("Tile ","").replace(":","").parse().unwrap();
-        }
-        else {
-            current_tile.add_row(&buffer.trim().chars().collect());
+        if group.len() == 1 { return Err(ParseError::unexpected_end(header.number()+1, "a row of the tile")); }
+
+        for line in group.lines().skip(1) {
+            let row = line.map_chars("'#' or '.'", |x| if x == '#' || x == '.' { Some(x) } else { None })?;
+            let width = tile.size().0;
+
+            if width > 0 && row.len() != width {
+                return Err(line.error(line.text(), &format!("a row {} wide", width)));
+            }
+            
+            tile.add_row(&row);
         }
 
-        buffer.clear();
+        result.add_tile(id, &tile);
     }
 
-    if result.tiles.len() == 0 { Err(()) }
+    if result.tiles.len() == 0 { Err(input.end("a tile")) }
     else { Ok(result) }
 }
 
-fn part1() {
-    if let Ok(mut puzzle) = read_tiles() {
+pub struct JurassicJigsaw;
+impl Solution for JurassicJigsaw {
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_tiles(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(puzzle: &Self::Input) -> Answer {
+        let mut puzzle = puzzle.clone();
         puzzle.assemble();
         
-        println!("{}", puzzle.tiles
-                 .iter()
-                 .filter(|(_,tile)| tile.neighbors.len() == 2)
-                 .map(|(id,_)| *id)
-                 .product::<usize>());
-    }
-    else { panic!("couldn't read tiles!"); }
-}
-
-fn part2() {
-    if let Ok(mut puzzle) = read_tiles() {
+        puzzle.tiles
+            .iter()
+            .filter(|(_,tile)| tile.neighbors.len() == 2)
+            .map(|(id,_)| *id)
+            .product::<usize>()
+            .into()
+    }
+    fn part2(puzzle: &Self::Input) -> Answer {
+        let mut puzzle = puzzle.clone();
         puzzle.assemble();
         puzzle.solve();
         
@@ -583,12 +587,129 @@ fn part2() {
         let monsters = image.find_sea_monsters();
         let roughness = image.pixels.iter().map(|x| x.iter().filter(|&y| *y=='#').count()).sum::<usize>() - (monsters*15);
 
-        println!("{}", roughness);
+        roughness.into()
     }
-    else { panic!("couldn't read tiles!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###...
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(JurassicJigsaw::solve(EXAMPLE, 1), Ok(Answer::from(20899048083289usize)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(JurassicJigsaw::solve(EXAMPLE, 2), Ok(Answer::from(273)));
+    }
 }
@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn read_allergens(input: &mut Reader) -> Result<(Vec<String>, HashMap<String, HashSet<String>>), ParseError> {
+    let mut ingredients = Vec::<String>::new();
+    let mut allergens = HashMap::<String, HashSet<String>>::new();
+
+    while let Some(line) = input.next_line() {
+        let (food, contains) = line.split_once(line.text(), " (contains ", "a food like \"mxmxvkd kfcds (contains dairy, fish)\"")?;
+        let contains = contains.strip_suffix(")").ok_or(line.error(contains, "an allergen list ending in ')'"))?;
+        let ingredient_set: HashSet<String> = food.split(" ").map(|x| x.to_string()).collect();
+        let allergen_vec: Vec<String> = contains.split(", ").map(|x| x.to_string()).collect();
+
+        for allergen in allergen_vec {
+            let entry = allergens.entry(allergen).or_insert(ingredient_set.clone());
+            *entry = entry.intersection(&ingredient_set).cloned().collect::<HashSet<String>>();
+        }
+
+        ingredients.append(&mut ingredient_set.iter().cloned().collect::<Vec<String>>());
+
+    }
+
+    if allergens.len() == 0 { Err(input.end("a food")) }
+    else { Ok((ingredients, allergens)) }
+}
+
+pub struct AllergenAssessment;
+impl Solution for AllergenAssessment {
+    type Input = (Vec<String>, HashMap<String, HashSet<String>>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_allergens(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1((ingredients, allergens): &Self::Input) -> Answer {
+        let allergen_set = allergens.values().cloned().reduce(|acc, x| acc.union(&x).cloned().collect::<HashSet<String>>()).unwrap();
+        let full_ingredient_set: HashSet<String> = ingredients.iter().cloned().collect();
+        let safe_set: HashSet<String> = full_ingredient_set.difference(&allergen_set).cloned().collect();
+
+        safe_set.iter().map(|x| ingredients.iter().filter(|&y| y == x).count()).sum::<usize>().into()
+    }
+    fn part2((_, allergens): &Self::Input) -> Answer {
+        let allergen_set = allergens.values().cloned().reduce(|acc, x| acc.union(&x).cloned().collect::<HashSet<String>>()).unwrap();
+        let mut known_allergens = HashMap::<String, String>::new();
+
+        loop {
+            let solved_ingredients: HashSet<String> = known_allergens.values().cloned().collect();
+            if solved_ingredients == allergen_set { break; }
+
+            for (allergen, ingredient_set) in allergens {
+                if known_allergens.contains_key(allergen) { continue; }
+
+                let difference: HashSet<String> = ingredient_set.difference(&solved_ingredients).cloned().collect();
+
+                if difference.len() == 1 {
+                    known_allergens.insert(allergen.clone(), difference.iter().next().unwrap().clone());
+                    break;
+                }
+            }
+        }
+
+        let mut sorted_allergens: Vec<(String, String)> = known_allergens.iter().map(|(k,v)| (k.clone(),v.clone())).collect();
+        sorted_allergens.sort();
+
+        let allergen_string: Vec<String> = sorted_allergens.iter().map(|(_,v)| v.clone()).collect();
+        allergen_string.join(",").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(AllergenAssessment::solve(EXAMPLE, 1), Ok(Answer::from(5)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(AllergenAssessment::solve(EXAMPLE, 2), Ok(Answer::from("mxmxvkd,sqjhc,fvjkl")));
+    }
+}
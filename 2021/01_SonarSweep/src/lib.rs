@@ -0,0 +1,65 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn read_depths(input: &mut Reader) -> Result<Vec<u32>, ParseError> {
+    let mut depths = Vec::<u32>::new();
+
+    while let Some(line) = input.next_line() {
+        depths.push(line.parse(line.text(), "a depth")?);
+    }
+
+    if depths.len() == 0 { Err(input.end("a depth")) }
+    else { Ok(depths) }
+}
+
+fn count_increases(measurements: &Vec<u32>) -> u32 {
+    let mut increase = 0u32;
+
+    for i in 1..measurements.len() {
+        if measurements[i] > measurements[i-1] { increase += 1; }
+    }
+
+    increase
+}
+
+pub struct SonarSweep;
+impl Solution for SonarSweep {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_depths(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(depths: &Self::Input) -> Answer {
+        count_increases(depths).into()
+    }
+    fn part2(depths: &Self::Input) -> Answer {
+        let windows: Vec<u32> = depths.windows(3).map(|x| x.iter().sum()).collect();
+
+        count_increases(&windows).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(SonarSweep::solve(EXAMPLE, 1), Ok(Answer::from(7)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(SonarSweep::solve(EXAMPLE, 2), Ok(Answer::from(5)));
+    }
+}
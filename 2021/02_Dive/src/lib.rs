@@ -0,0 +1,96 @@
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+pub struct Command {
+    command: String,
+    distance: u32,
+}
+impl Command {   
+    pub fn read(line: &Line) -> Result<Self, ParseError> {
+        let (command, distance) = line.split_once(line.text(), " ", "a command like forward 5")?;
+
+        if command != "forward" && command != "down" && command != "up" {
+            return Err(line.error(command, "forward, down or up"));
+        }
+
+        Ok(Self {
+            command: command.to_string(),
+            distance: line.parse(distance, "a distance")?
+        })
+    }
+}
+
+fn read_commands(input: &mut Reader) -> Result<Vec<Command>, ParseError> {
+    let mut commands = Vec::<Command>::new();
+
+    while let Some(line) = input.next_line() {
+        commands.push(Command::read(&line)?);
+    }
+
+    if commands.len() == 0 { Err(input.end("a command")) }
+    else { Ok(commands) }
+}
+
+pub struct Dive;
+impl Solution for Dive {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_commands(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(commands: &Self::Input) -> Answer {
+        let mut position = 0u32;
+        let mut depth = 0i32;
+    
+        for command in commands {
+            match command.command.as_str() {
+                "forward" => position += command.distance,
+                "down" => depth += command.distance as i32,
+                "up" => depth -= command.distance as i32,
+                _ => (),
+            }
+        }
+
+        ((position as i32) * depth).into()
+    }
+    fn part2(commands: &Self::Input) -> Answer {
+        let mut position = 0u32;
+        let mut depth = 0i32;
+        let mut aim = 0i32;
+    
+        for command in commands {
+            match command.command.as_str() {
+                "down" => aim += command.distance as i32,
+                "up" => aim -= command.distance as i32,
+                "forward" => {
+                    position += command.distance;
+                    depth += aim * (command.distance as i32);
+                },
+                _ => (),
+            }
+        }
+
+        ((position as i32) * depth).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Dive::solve(EXAMPLE, 1), Ok(Answer::from(150)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(Dive::solve(EXAMPLE, 2), Ok(Answer::from(900)));
+    }
+}
@@ -0,0 +1,140 @@
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct Frequency(u32, u32);
+impl Frequency {
+    pub fn new() -> Self { Self(0,0) }
+    pub fn gamma(&self) -> u8 { if self.0 > self.1 { 0 } else { 1 } }
+    pub fn epsilon(&self) -> u8 { if self.0 > self.1 { 1 } else { 0 } }
+}
+
+fn read_bitvec(line: &Line) -> Result<Vec<u8>, ParseError> {
+    // convert the string into an array of bit values
+    line.map_chars("'0' or '1'", |x| match x {
+        '0' => Some(0u8),
+        '1' => Some(1u8),
+        _ => None,
+    })
+}
+
+fn bitvec_frequency(freq: &mut Vec::<Frequency>, bitvec: &Vec<u8>) {
+    if freq.len() == 0 {
+        for _ in 0..bitvec.len() { freq.push(Frequency::new()) }
+    }
+
+    for i in 0..bitvec.len() {
+        let value = bitvec[i];
+
+        match value {
+            0 => freq[i].0 += 1,
+            1 => freq[i].1 += 1,
+            _ => (),
+        }
+    }
+}
+
+fn bits_to_u32(v: &Vec<u8>) -> u32 {
+    v.iter().fold(0, |acc, x| (acc << 1) | (*x as u32))
+}
+
+fn life_support_rating(bitvecs: &Vec<Vec<u8>>, freq: &Vec<Frequency>, rating: bool) -> Result<u32, ()> {
+    let mut selections = bitvecs.clone();
+    let mut reduced_freq = freq.clone();
+    let mut index = 0usize;
+
+    loop {
+        let check;
+        
+        if rating { check = reduced_freq[index].gamma(); }
+        else { check = reduced_freq[index].epsilon(); }
+
+        selections = selections.iter()
+            .filter(|x| x[index] == check)
+            .cloned()
+            .collect();
+
+        if selections.len() == 1 { return Ok(bits_to_u32(&selections[0])); }
+        else {
+            index += 1;
+            if index == freq.len() { return Err(()); }
+        }
+
+        reduced_freq = Vec::<Frequency>::new();
+
+        for i in 0..selections.len() {
+            bitvec_frequency(&mut reduced_freq, &selections[i]);
+        }
+    }
+}
+
+fn read_report(input: &mut Reader) -> Result<Vec<Vec<u8>>, ParseError> {
+    let mut bitvecs = Vec::<Vec<u8>>::new();
+
+    while let Some(line) = input.next_line() {
+        let bitvec = read_bitvec(&line)?;
+
+        if bitvecs.len() > 0 && bitvec.len() != bitvecs[0].len() {
+            return Err(line.error(line.text(), &format!("a number {} bits wide", bitvecs[0].len())));
+        }
+
+        bitvecs.push(bitvec);
+    }
+
+    if bitvecs.len() == 0 { Err(input.end("a binary number")) }
+    else { Ok(bitvecs) }
+}
+
+pub struct BinaryDiagnostic;
+impl Solution for BinaryDiagnostic {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_report(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(bitvecs: &Self::Input) -> Answer {
+        let mut freq = Vec::<Frequency>::new();
+        bitvecs.iter().for_each(|x| bitvec_frequency(&mut freq, x));
+
+        let gamma_value = bits_to_u32(&freq.iter().map(|x| x.gamma()).collect());
+        let epsilon_value = bits_to_u32(&freq.iter().map(|x| x.epsilon()).collect());
+
+        (gamma_value * epsilon_value).into()
+    }
+    fn part2(bitvecs: &Self::Input) -> Answer {
+        let mut freq = Vec::<Frequency>::new();
+        bitvecs.iter().for_each(|x| bitvec_frequency(&mut freq, x));
+
+        let gamma_value = life_support_rating(bitvecs, &freq, true).unwrap();
+        let epsilon_value = life_support_rating(bitvecs, &freq, false).unwrap();
+
+        (gamma_value * epsilon_value).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(BinaryDiagnostic::solve(EXAMPLE, 1), Ok(Answer::from(198)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(BinaryDiagnostic::solve(EXAMPLE, 2), Ok(Answer::from(230)));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+use common::{Answer, Error, Group, ParseError, Reader, Solution};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+struct Coordinate(usize, usize);
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Board {
+    board: [[u8; 5]; 5],
+    coords: HashMap<u8, Coordinate>,
+    winners: HashSet<Coordinate>,
+}
+impl Board {
+    pub fn read_board(rows: &Group) -> Result<Self, ParseError> {
+        let mut board = [[0u8; 5]; 5];
+        let mut coords = HashMap::<u8, Coordinate>::new();
+        let winners = HashSet::<Coordinate>::new();
+
+        if rows.len() < 5 { return Err(ParseError::unexpected_end(rows.last().number()+1, "a row of the board")); }
+
+        for (y, line) in rows.lines().enumerate() {
+            if y == 5 { return Err(line.error(line.text(), "a blank line after 5 rows of the board")); }
+
+            let mut row = Vec::<u8>::new();
+
+            for number in line.text().split_whitespace() {
+                row.push(line.parse(number, "a number from 0 to 255")?);
+            }
+
+            if row.len() != 5 { return Err(line.error(line.text(), "a row of 5 numbers")); }
+
+            for x in 0..row.len() {
+                board[y][x] = row[x];
+                coords.insert(row[x], Coordinate(x, y));
+            }
+        }
+
+        Ok(Self { board, coords, winners })
+    }
+
+    pub fn call(&mut self, ball: u8) -> Option<u32> {
+        if let Some(found) = self.coords.get(&ball) {
+            self.winners.insert(*found);
+
+            let x = found.0;
+            let y = found.1;
+            let mut winner = true;
+
+            for sx in 0..5 {
+                if x == sx { continue; }
+                if !self.winners.contains(&Coordinate(sx, y)) { winner = false; break; }
+            }
+
+            if winner { return Some(self.sum_losers() * (ball as u32)); }
+
+            winner = true;
+
+            for sy in 0..5 {
+                if y == sy { continue; }
+                if !self.winners.contains(&Coordinate(x, sy)) { winner = false; break; }
+            }
+
+            if winner { return Some(self.sum_losers() * (ball as u32)); }
+        }
+
+        None
+    }
+
+    fn sum_losers(&self) -> u32 {
+        let mut sum = 0u32;
+        
+        for y in 0..5 {
+            for x in 0..5 {
+                let c = Coordinate(x, y);
+
+                if self.winners.contains(&c) { continue; }
+
+                sum += self.board[y][x] as u32;
+            }
+        }
+
+        sum
+    }
+}
+
+fn read_bingo(input: &mut Reader) -> Result<(Vec<u8>, Vec<Board>), ParseError> {
+    let mut boards = Vec::<Board>::new();
+    let mut calls = Vec::<u8>::new();
+
+    let line = input.expect_line("the called numbers")?;
+
+    for call in line.text().split(",") {
+        calls.push(line.parse(call, "a number from 0 to 255")?);
+    }
+
+    // each board follows a blank line
+    while let Some(rows) = input.next_group() {
+        boards.push(Board::read_board(&rows)?);
+    }
+
+    Ok((calls, boards))
+}
+    
+pub struct GiantSquid;
+impl Solution for GiantSquid {
+    type Input = (Vec<u8>, Vec<Board>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_bingo(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1((calls, boards): &Self::Input) -> Answer {
+        let mut boards = boards.clone();
+        
+        for &call in calls {
+            for board in &mut boards {
+                if let Some(bingo) = board.call(call) {
+                    return bingo.into();
+                }
+            }
+        }
+
+        panic!("no board won!");
+    }
+    fn part2((calls, boards): &Self::Input) -> Answer {
+        let mut boards = boards.clone();
+        let mut winner = 0u32;
+        let mut winners = HashSet::<usize>::new();
+    
+        for &call in calls {
+            for i in 0..boards.len() {
+                if winners.contains(&i) { continue; }
+                
+                let board = &mut boards[i];
+                
+                if let Some(bingo) = board.call(call) {
+                    winner = bingo;
+                    winners.insert(i);
+                }
+            }
+        }
+
+        winner.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(GiantSquid::solve(EXAMPLE, 1), Ok(Answer::from(4512)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(GiantSquid::solve(EXAMPLE, 2), Ok(Answer::from(1924)));
+    }
+}
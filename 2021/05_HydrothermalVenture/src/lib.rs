@@ -1,20 +1,18 @@
-use std::io;
 use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-struct Coordinate(u32, u32);
+pub struct Coordinate(u32, u32);
 impl Coordinate {
-    fn from_str(s: &str) -> Self {
-        let coords: Vec<u32> = s.split(",")
-            .map(|x| x.parse().unwrap())
-            .collect();
+    fn from_str(s: &str, line: &common::Line) -> Result<Self, ParseError> {
+        let (x, y) = line.split_once(s, ",", "a coordinate like 0,9")?;
 
-        Self(coords[0], coords[1])
+        Ok(Self(line.parse(x, "a number")?, line.parse(y, "a number")?))
     }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-struct Line(Coordinate, Coordinate);
+pub struct Line(Coordinate, Coordinate);
 impl Line {
     pub fn slope(&self) -> Option<f32> {
         let mx = (self.1.0 as f32) - (self.0.0 as f32);
@@ -53,27 +51,16 @@ impl Line {
     }
 }
 
-fn read_lines() -> Result<Vec<Line>, ()> {
-    let mut buffer = String::new();
+fn read_lines(input: &mut Reader) -> Result<Vec<Line>, ParseError> {
     let mut lines = Vec::<Line>::new();
-    let stdin = io::stdin();
     
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; } // newline
-        
-        let coords: Vec<Coordinate> = buffer
-            .trim()
-            .split(" -> ")
-            .map(|x| Coordinate::from_str(x))
-            .collect();
-
-        lines.push(Line(coords[0], coords[1]));
+    while let Some(text) = input.next_line() {
+        let (start, end) = text.split_once(text.text(), " -> ", "a line like 0,9 -> 5,9")?;
 
-        buffer.clear();
+        lines.push(Line(Coordinate::from_str(start, &text)?, Coordinate::from_str(end, &text)?));
     }
 
-    if lines.len() == 0 { return Err(()); }
+    if lines.len() == 0 { return Err(input.end("a line of vents")); }
 
     Ok(lines)
 }
@@ -97,26 +84,48 @@ fn traverse_lines(lines: &Vec<Line>) -> u32 {
     overlaps
 }
 
-fn part1() {
-    if let Ok(mut lines) = read_lines() {
-        lines = lines.iter()
+pub struct HydrothermalVenture;
+impl Solution for HydrothermalVenture {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_lines(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(lines: &Self::Input) -> Answer {
+        let lines: Vec<Line> = lines.iter()
             .filter(|x| x.slope() == None || x.slope() == Some(0.0))
             .cloned()
             .collect();
 
-        println!("{:?}", traverse_lines(&lines));
+        traverse_lines(&lines).into()
     }
-    else { panic!("couldn't read line data"); }
-}
-
-fn part2() {
-    if let Ok(mut lines) = read_lines() {
-        println!("{:?}", traverse_lines(&lines));
+    fn part2(lines: &Self::Input) -> Answer {
+        traverse_lines(lines).into()
     }
-    else { panic!("couldn't read line data"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(HydrothermalVenture::solve(EXAMPLE, 1), Ok(Answer::from(5)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(HydrothermalVenture::solve(EXAMPLE, 2), Ok(Answer::from(12)));
+    }
 }
@@ -0,0 +1,74 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn simulate(fish: &Vec<u8>, steps: u16) -> u64 {
+    let mut breedable = [0u64; 7];
+    let mut available = [0u64; 7];
+
+    for f in fish {
+        breedable[*f as usize] += 1;
+        available[*f as usize] += 1;
+    }
+    for s in 0..steps {
+        let modulo = (s % 7) as usize;
+        let delay = ((s + 2) % 7) as usize;
+
+        available[delay] = available[delay] + breedable[modulo];
+        breedable[modulo] = available[modulo];
+    }
+
+    available.iter().sum()
+}
+
+fn read_fish(input: &mut Reader) -> Result<Vec<u8>, ParseError> {
+    if let Some(line) = input.next_line() {
+        let mut fish = Vec::<u8>::new();
+
+        for timer in line.text().split(",") {
+            match line.parse::<u8>(timer, "a timer from 0 to 8")? {
+                value if value <= 8 => fish.push(value),
+                _ => return Err(line.error(timer, "a timer from 0 to 8")),
+            }
+        }
+
+        Ok(fish)
+    }
+    else { Err(input.end("a list of fish")) }
+}
+
+pub struct Lanternfish;
+impl Solution for Lanternfish {
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_fish(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(fish: &Self::Input) -> Answer {
+        simulate(fish, 80).into()
+    }
+    fn part2(fish: &Self::Input) -> Answer {
+        simulate(fish, 256).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3,4,3,1,2
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Lanternfish::solve(EXAMPLE, 1), Ok(Answer::from(5934)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(Lanternfish::solve(EXAMPLE, 2), Ok(Answer::from(26984457539u64)));
+    }
+    #[test]
+    fn eighteen_days() {
+        let fish = Lanternfish::parse(EXAMPLE).unwrap();
+
+        assert_eq!(simulate(&fish, 18), 26);
+    }
+}
@@ -0,0 +1,68 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn read_crabs(input: &mut Reader) -> Result<Vec<i32>, ParseError> {
+    if let Some(line) = input.next_line() {
+        line.text()
+            .split(",")
+            .map(|x| line.parse(x, "a crab position"))
+            .collect()
+    }
+    else { Err(input.end("a list of crabs")) }
+}
+
+fn fuel_economy_median(crabs: &Vec<i32>) -> u32 {
+    let mut sorted = crabs.clone();
+    sorted.sort();
+    
+    let median = sorted[sorted.len()/2] as i32;
+
+    sorted.iter()
+        .map(|&x| i32::abs(median-x))
+        .sum::<i32>() as u32
+}
+
+fn fuel_economy_average(crabs: &Vec<i32>) -> u32 {
+    let average: f64 = (crabs.iter().sum::<i32>() as f64)/(crabs.len() as f64);
+
+    u32::min(crabs.iter()
+             .map(|&x| i32::abs((average.floor() as i32)-x))
+             .map(|x| (1..=x).sum::<i32>())
+             .sum::<i32>() as u32,
+             
+             crabs.iter()
+             .map(|&x| i32::abs((average.ceil() as i32)-x))
+             .map(|x| (1..=x).sum::<i32>())
+             .sum::<i32>() as u32)
+}
+
+pub struct TheTreacheryOfWhales;
+impl Solution for TheTreacheryOfWhales {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_crabs(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(crabs: &Self::Input) -> Answer {
+        fuel_economy_median(crabs).into()
+    }
+    fn part2(crabs: &Self::Input) -> Answer {
+        fuel_economy_average(crabs).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(TheTreacheryOfWhales::solve(EXAMPLE, 1), Ok(Answer::from(37)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(TheTreacheryOfWhales::solve(EXAMPLE, 2), Ok(Answer::from(168)));
+    }
+}
@@ -1,16 +1,20 @@
-use std::io;
 use std::collections::{HashMap, HashSet};
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
-struct Pattern {
+pub struct Pattern {
     segments: String
 }
 impl Pattern {
-    pub fn from_str(s: &str) -> Self {
-        let mut segments: Vec<u8> = s.as_bytes().iter().cloned().collect();
+    pub fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        if s.len() == 0 || !s.chars().all(|x| x >= 'a' && x <= 'g') {
+            return Err(line.error(s, "a pattern of segments a to g"));
+        }
+        
+        let mut segments: Vec<char> = s.chars().collect();
         segments.sort();
         
-        Self { segments: std::str::from_utf8(&segments).unwrap().to_string() } 
+        Ok(Self { segments: segments.into_iter().collect() })
     }
     pub fn as_set(&self) -> HashSet<u8> {
         self.segments.as_str().as_bytes().iter().cloned().collect()
@@ -18,7 +22,7 @@ impl Pattern {
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Signal {
+pub struct Signal {
     patterns: Vec<Pattern>,
     output: Vec<Pattern>,
 }
@@ -95,55 +99,75 @@ impl Signal {
     }
 }
 
-fn read_signals() -> Result<Vec<Signal>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_signals(input: &mut Reader) -> Result<Vec<Signal>, ParseError> {
     let mut signals = Vec::<Signal>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-        
-        let signal_data: Vec<&str> = buffer.trim().split(" | ").collect();
-        let pattern_segments: Vec<Pattern> = signal_data[0].split(" ")
-            .map(|x| Pattern::from_str(x))
-            .collect();
-        let output_segments: Vec<Pattern> = signal_data[1].split(" ")
-            .map(|x| Pattern::from_str(x))
-            .collect();
+    while let Some(line) = input.next_line() {
+        let (patterns, output) = line.split_once(line.text(), " | ", "ten patterns and four outputs separated by |")?;
+        let pattern_segments = patterns.split(" ")
+            .map(|x| Pattern::from_str(x, &line))
+            .collect::<Result<Vec<Pattern>, ParseError>>()?;
+        let output_segments = output.split(" ")
+            .map(|x| Pattern::from_str(x, &line))
+            .collect::<Result<Vec<Pattern>, ParseError>>()?;
+
+        if pattern_segments.len() != 10 { return Err(line.error(patterns, "ten patterns")); }
+        if output_segments.len() != 4 { return Err(line.error(output, "four outputs")); }
 
         signals.push(Signal { patterns: pattern_segments, output: output_segments });
 
-        buffer.clear();
     }
 
-    if signals.len() == 0 { return Err(()) }
+    if signals.len() == 0 { return Err(input.end("a signal")) }
     else { Ok(signals) }
 }
 
-fn part1() {
-    if let Ok(signals) = read_signals() {
+pub struct SevenSegmentSearch;
+impl Solution for SevenSegmentSearch {
+    type Input = Vec<Signal>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_signals(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(signals: &Self::Input) -> Answer {
         let lengths: HashSet<usize> = [2usize, 3, 4, 7].iter().cloned().collect();
         let mut count = 0u32;
 
-        for signal in &signals {
+        for signal in signals {
             for pattern in &signal.output {
                 if lengths.contains(&pattern.segments.len()) { count += 1; }
             }
         }
 
-        println!("{}", count);
+        count.into()
     }
-}
-    
-fn part2() {
-    if let Ok(signals) = read_signals() {
-        println!("{}", signals.iter().map(|x| x.readout()).sum::<u32>());
+    fn part2(signals: &Self::Input) -> Answer {
+        signals.iter().map(|x| x.readout()).sum::<u32>().into()
     }
-    else { panic!("couldn't read signals!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(SevenSegmentSearch::solve(EXAMPLE, 1), Ok(Answer::from(26)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(SevenSegmentSearch::solve(EXAMPLE, 2), Ok(Answer::from(61229)));
+    }
 }
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Grid, Neighborhood};
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct HeightMap {
+    data: Grid<u32>,
+}
+impl HeightMap {
+    pub fn read(input: &mut Reader) -> Result<Self, ParseError> {
+        Ok(Self { data: Grid::read(input, "a height from 0 to 9", |x| x.to_digit(10))? })
+    }
+
+    pub fn get_point(&self, coord: Coordinate) -> u32 {
+        self.data[coord]
+    }
+
+    pub fn get_deltas(&self, coord: Coordinate) -> Vec<i32> {
+        let height_value = self.get_point(coord);
+
+        self.data.neighbors(coord, Neighborhood::Four)
+            .into_iter()
+            .map(|x| delta(height_value, self.get_point(x)))
+            .collect()
+    }
+
+    pub fn low_points(&self) -> Vec<Coordinate> {
+        self.data.coordinates()
+            .filter(|&coord| self.get_deltas(coord).iter().all(|&x| x < 0))
+            .collect()
+    }
+
+    pub fn basins(&self) -> Vec<HashSet<Coordinate>> {
+        let mut basins = Vec::<HashSet::<Coordinate>>::new();
+        let low_points = self.low_points();
+
+        for low_point in low_points {
+            let mut basin = HashSet::<Coordinate>::new();
+
+            self.find_basin(low_point, &mut basin);
+
+            basins.push(basin);
+        }
+
+        basins
+    }
+
+    fn find_basin(&self, coord: Coordinate, basin: &mut HashSet::<Coordinate>) {
+        if basin.contains(&coord) { return; }
+        
+        let height_value = self.get_point(coord);
+
+        if height_value == 9 { return; }
+
+        basin.insert(coord);
+
+        for neighbor in self.data.neighbors(coord, Neighborhood::Four) {
+            self.find_basin(neighbor, basin);
+        }
+    }
+}
+
+fn delta(a: u32, b: u32) -> i32 {
+    a as i32 - b as i32
+}
+
+pub struct SmokeBasin;
+impl Solution for SmokeBasin {
+    type Input = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        HeightMap::read(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(height_map: &Self::Input) -> Answer {
+        height_map.low_points()
+            .iter()
+            .map(|&x| height_map.get_point(x)+1)
+            .sum::<u32>()
+            .into()
+    }
+    fn part2(height_map: &Self::Input) -> Answer {
+        let mut basin_sizes: Vec<usize> = height_map.basins()
+            .iter()
+            .map(|x| x.len())
+            .collect();
+
+        basin_sizes.sort();
+        basin_sizes.reverse();
+        
+        (basin_sizes[0] * basin_sizes[1] * basin_sizes[2]).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(SmokeBasin::solve(EXAMPLE, 1), Ok(Answer::from(15)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(SmokeBasin::solve(EXAMPLE, 2), Ok(Answer::from(1134)));
+    }
+}
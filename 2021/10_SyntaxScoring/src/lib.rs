@@ -1,20 +1,16 @@
-use std::io;
 use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
 
-fn read_nav() -> Result<Vec<String>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_nav(input: &mut Reader) -> Result<Vec<String>, ParseError> {
     let mut lines = Vec::<String>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-        
-        lines.push(buffer.trim().to_string());
-        buffer.clear();
+    while let Some(line) = input.next_line() {
+        let chunks = line.map_chars("one of ()[]{}<>", |x| if "()[]{}<>".contains(x) { Some(x) } else { None })?;
+
+        lines.push(chunks.into_iter().collect());
     }
 
-    if lines.len() == 0 { Err(()) }
+    if lines.len() == 0 { Err(input.end("a line of chunks")) }
     else { Ok(lines) }
 }
 
@@ -42,7 +38,6 @@ fn check_syntax(lines: &Vec<String>) -> (u64, u64) {
     ].iter().cloned().collect();
     let mut corruption_score = 0u64;
     let mut incomplete_scores = Vec::<u64>::new();
-    let mut index = 0usize;
 
     for line in lines {
         let mut state = Vec::<char>::new();
@@ -83,25 +78,43 @@ fn check_syntax(lines: &Vec<String>) -> (u64, u64) {
     (corruption_score, incomplete_scores[incomplete_scores.len()/2])
 }
 
-fn part1() {
-    if let Ok(lines) = read_nav() {
-        println!("{}", check_syntax(&lines).0);
+pub struct SyntaxScoring;
+impl Solution for SyntaxScoring {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_nav(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(lines: &Self::Input) -> Answer {
+        check_syntax(lines).0.into()
     }
-    else {
-        panic!("couldn't read nav!");
+    fn part2(lines: &Self::Input) -> Answer {
+        check_syntax(lines).1.into()
     }
 }
 
-fn part2() {
-    if let Ok(lines) = read_nav() {
-        println!("{}", check_syntax(&lines).1);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(SyntaxScoring::solve(EXAMPLE, 1), Ok(Answer::from(26397)));
     }
-    else {
-        panic!("couldn't read nav!");
+    #[test]
+    fn part2_example() {
+        assert_eq!(SyntaxScoring::solve(EXAMPLE, 2), Ok(Answer::from(288957)));
     }
 }
-
-fn main() {
-    // part1();
-    part2();
-}
@@ -0,0 +1,142 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Grid, Neighborhood};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum FlashState {
+    Charging,
+    Flashing,
+    Flashed,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Octopus {
+    energy: u32,
+    state: FlashState,
+}
+impl Octopus {
+    fn new(energy: u32) -> Self {
+        Self { energy: energy, state: FlashState::Charging }
+    }
+    
+    fn charge(&mut self) -> bool {
+        if self.state != FlashState::Charging { return false; }
+        
+        self.energy += 1;
+
+        if self.energy > 9 {
+            self.state = FlashState::Flashing;
+            true
+        }
+        else { false }
+    }
+    fn flash(&mut self) {
+        self.state = FlashState::Flashed;
+    }
+    fn reset(&mut self) {
+        self.energy = 0;
+        self.state = FlashState::Charging;
+    }
+}
+
+/// Runs one step, returning how many octopuses flashed.
+fn step(pod: &mut Grid<Octopus>) -> usize {
+    let mut flashing = Vec::<Coordinate>::new();
+    let mut flashed = Vec::<Coordinate>::new();
+    
+    for (coord, octopus) in pod.iter_mut() {
+        if octopus.charge() {
+            flashing.push(coord);
+        }
+    }
+
+    while flashing.len() > 0 {
+        let mut new_flashing = Vec::<Coordinate>::new();
+
+        for coord in flashing {
+            pod[coord].flash();
+            flashed.push(coord);
+
+            for neighbor in pod.neighbors(coord, Neighborhood::Eight) {
+                if pod[neighbor].charge() { new_flashing.push(neighbor); }
+            }
+        }
+
+        flashing = new_flashing;
+    }
+
+    for &coord in &flashed {
+        pod[coord].reset();
+    }
+
+    flashed.len()
+}
+
+fn simulate(pod: &mut Grid<Octopus>, steps: usize) -> usize {
+    (0..steps).map(|_| step(pod)).sum()
+}
+
+fn read_pod(input: &mut Reader) -> Result<Grid<Octopus>, ParseError> {
+    Grid::read(input, "an energy level from 0 to 9", |x| x.to_digit(10).map(Octopus::new))
+}
+
+pub struct DumboOctopus;
+impl Solution for DumboOctopus {
+    type Input = Grid<Octopus>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_pod(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(pod: &Self::Input) -> Answer {
+        let mut pod = pod.clone();
+        
+        simulate(&mut pod, 100).into()
+    }
+    fn part2(pod: &Self::Input) -> Answer {
+        let mut pod = pod.clone();
+        let mut steps = 1usize;
+        
+        while step(&mut pod) < pod.len() {
+            steps += 1;
+        }
+
+        steps.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(DumboOctopus::solve(EXAMPLE, 1), Ok(Answer::from(1656)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(DumboOctopus::solve(EXAMPLE, 2), Ok(Answer::from(195)));
+    }
+    #[test]
+    fn ten_steps() {
+        let mut pod = DumboOctopus::parse(EXAMPLE).unwrap();
+
+        assert_eq!(simulate(&mut pod, 10), 204);
+    }
+    #[test]
+    fn flashing_together_early() {
+        assert_eq!(DumboOctopus::solve("9", 1), Ok(Answer::from(10)));
+        assert_eq!(DumboOctopus::solve("9", 2), Ok(Answer::from(1)));
+        assert_eq!(DumboOctopus::solve("99\n99", 1), Ok(Answer::from(40)));
+    }
+}
@@ -1,5 +1,5 @@
-use std::io;
 use std::collections::{HashSet, HashMap};
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 enum Cave {
@@ -84,16 +84,19 @@ impl Frame {
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct CaveSystem {
+pub struct CaveSystem {
     nodes: HashMap<Cave, HashSet<Cave>>,
 }
 impl CaveSystem {
     fn new() -> Self { Self { nodes: HashMap::<Cave, HashSet<Cave>>::new() } }
-    fn import_edge(&mut self, s: &str) {
-        let caves: Vec<Cave> = s.split("-").map(|x| Cave::from_str(x)).collect();
+    fn import_edge(&mut self, line: &Line) -> Result<(), ParseError> {
+        let (from, to) = line.split_once(line.text(), "-", "a passage like start-A")?;
+        let (from, to) = (Cave::from_str(from), Cave::from_str(to));
 
-        self.add_edge(&caves[0], &caves[1]);
-        self.add_edge(&caves[1], &caves[0]);
+        self.add_edge(&from, &to);
+        self.add_edge(&to, &from);
+
+        Ok(())
     }
     fn add_edge(&mut self, from: &Cave, to: &Cave) {
         if let Some(edges) = self.nodes.get_mut(&from) {
@@ -137,38 +140,92 @@ impl CaveSystem {
     }
 }
 
-fn read_system() -> Result<CaveSystem, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_system(input: &mut Reader) -> Result<CaveSystem, ParseError> {
     let mut system = CaveSystem::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-        
-        system.import_edge(&buffer.trim());
-        buffer.clear();
+    while let Some(line) = input.next_line() {
+        system.import_edge(&line)?;
     }
 
-    if system.nodes.len() == 0 { Err(()) }
+    if !system.nodes.contains_key(&Cave::Start) { Err(input.end("a passage from start")) }
     else { Ok(system) }
 }
 
-fn part1() {
-    if let Ok(system) = read_system() {
-        println!("{}", system.traverse(1));
-    }
-    else { panic!("couldn't read system!"); }
-}
+pub struct PassagePathing;
+impl Solution for PassagePathing {
+    type Input = CaveSystem;
 
-fn part2() {
-    if let Ok(system) = read_system() {
-        println!("{}", system.traverse(2));
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_system(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(system: &Self::Input) -> Answer {
+        system.traverse(1).into()
+    }
+    fn part2(system: &Self::Input) -> Answer {
+        system.traverse(2).into()
     }
-    else { panic!("couldn't read system!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    const MEDIUM: &str = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sj
+kj-HN
+kj-dc
+";
+
+    const LARGE: &str = "fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(PassagePathing::solve(SMALL, 1), Ok(Answer::from(10)));
+        assert_eq!(PassagePathing::solve(MEDIUM, 1), Ok(Answer::from(19)));
+        assert_eq!(PassagePathing::solve(LARGE, 1), Ok(Answer::from(226)));
+    }
+    #[test]
+    fn part2_examples() {
+        assert_eq!(PassagePathing::solve(SMALL, 2), Ok(Answer::from(36)));
+        assert_eq!(PassagePathing::solve(MEDIUM, 2), Ok(Answer::from(103)));
+        assert_eq!(PassagePathing::solve(LARGE, 2), Ok(Answer::from(3509)));
+    }
+    #[test]
+    fn missing_start() {
+        assert_eq!(PassagePathing::parse("").unwrap_err().to_string(), "line 1: input ended, expected a passage from start");
+        assert_eq!(PassagePathing::parse("A-b\nb-end\n").unwrap_err().to_string(), "line 3: input ended, expected a passage from start");
+    }
 }
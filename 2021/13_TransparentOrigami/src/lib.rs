@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Coordinate(usize, usize);
+impl Coordinate {
+    fn fold_x(&self, bifurcation: usize) -> Self {
+        Self(self.0-((self.0 - bifurcation) * 2),self.1)
+    }
+    fn fold_y(&self, bifurcation: usize) -> Self {
+        Self(self.0,self.1-((self.1 - bifurcation) * 2))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Axis {
+    X,
+    Y,
+}
+impl Axis {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "x" => Some(Self::X),
+            "y" => Some(Self::Y),
+            _ => None,
+        }
+    }
+}
+
+fn read_origami(input: &mut Reader) -> Result<(HashSet<Coordinate>, Vec<(Axis,usize)>), ParseError> {
+    let mut coords = HashSet::<Coordinate>::new();
+    let mut instructions = Vec::<(Axis,usize)>::new();
+    let dots = input.next_group().ok_or(input.end("a dot"))?;
+
+    for line in dots.lines() {
+        let (x, y) = line.split_once(line.text(), ",", "a dot like 6,10")?;
+        coords.insert(Coordinate(line.parse(x, "a number")?, line.parse(y, "a number")?));
+    }
+
+    while let Some(line) = input.next_line() {
+        let instruction = line.text().strip_prefix("fold along ").ok_or(line.error(line.text(), "an instruction like fold along y=7"))?;
+        let (axis, position) = line.split_once(instruction, "=", "a fold like y=7")?;
+        let axis = Axis::from_str(axis).ok_or(line.error(axis, "x or y"))?;
+
+        instructions.push((axis, line.parse(position, "a number")?));
+    }
+
+    if instructions.len() == 0 { Err(input.end("a fold instruction")) }
+    else { Ok((coords, instructions)) }
+}
+
+fn fold(coords: &HashSet<Coordinate>, axis: Axis, bifurcation: usize) -> HashSet<Coordinate> {
+    let affected_coords: HashSet<Coordinate> = match axis {
+        Axis::X => {
+            coords.iter().filter(|x| x.0 > bifurcation).cloned().collect()
+        },
+        Axis::Y => {
+            coords.iter().filter(|x| x.1 > bifurcation).cloned().collect()
+        },
+    };
+
+    let unaffected_coords: HashSet<Coordinate> = coords.difference(&affected_coords).cloned().collect();
+
+    let folded_coords: HashSet<Coordinate> = match axis {
+        Axis::X => {
+            affected_coords.iter().map(|x| x.fold_x(bifurcation)).collect()
+        },
+        Axis::Y => {
+            affected_coords.iter().map(|x| x.fold_y(bifurcation)).collect()
+        }
+    };
+
+    folded_coords.union(&unaffected_coords).cloned().collect()
+}
+
+fn render_dots(coords: &HashSet<Coordinate>) -> String {
+    let (mut mx, mut my) = (0usize, 0usize);
+
+    for coord in coords {
+        let (nx,ny) = (coord.0,coord.1);
+
+        if nx+1>mx { mx=nx+1; }
+        if ny+1>my { my=ny+1; }
+    }
+
+    let mut buffer = vec![vec![' '; mx]; my];
+
+    for coord in coords {
+        let (x,y) = (coord.0,coord.1);
+
+        buffer[y][x] = '#';
+    }
+
+    let lines: Vec<String> = buffer.iter().map(|x| x.into_iter().collect()).collect();
+
+    lines.join("\n")
+}
+
+pub struct TransparentOrigami;
+impl Solution for TransparentOrigami {
+    type Input = (HashSet<Coordinate>, Vec<(Axis,usize)>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_origami(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1((coords, instructions): &Self::Input) -> Answer {
+        let (axis, bifurcation) = instructions[0];
+                
+        fold(coords, axis, bifurcation).len().into()
+    }
+    fn part2((coords, instructions): &Self::Input) -> Answer {
+        let mut coords = coords.clone();
+        
+        for &(axis, bifurcation) in instructions {
+            coords = fold(&coords, axis, bifurcation);
+        }
+
+        render_dots(&coords).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(TransparentOrigami::solve(EXAMPLE, 1), Ok(Answer::from(17)));
+    }
+    #[test]
+    fn part2_example() {
+        let square = ["#####", "#   #", "#   #", "#   #", "#####"].join("\n");
+
+        assert_eq!(TransparentOrigami::solve(EXAMPLE, 2), Ok(Answer::from(square)));
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PolymerPair(char,char);
+impl PolymerPair {
+    fn from_str(s: &str) -> Option<Self> {
+        let cv: Vec<char> = s.chars().collect();
+
+        if cv.len() != 2 { None }
+        else { Some(Self(cv[0],cv[1])) }
+    }
+    fn insert(&self, c: char) -> (PolymerPair, PolymerPair) {
+        (Self(self.0, c), Self(c, self.1))
+    }
+}
+
+fn read_polymers(input: &mut Reader) -> Result<(char, HashMap<PolymerPair, usize>, HashMap<PolymerPair, char>), ParseError> {
+    let mut polymer_pairs = Vec::<PolymerPair>::new();
+    let mut polymer_count = HashMap::<PolymerPair, usize>::new();
+    let mut first = ' ';
+    let template = input.next_group().ok_or(input.end("a polymer template"))?;
+
+    for line in template.lines() {
+        let char_vec: Vec<char> = line.text().chars().collect();
+        first = char_vec[0];
+
+        for i in 0..char_vec.len()-1 {
+            polymer_pairs.push(PolymerPair(char_vec[i],char_vec[i+1]));
+        }
+    }
+
+    if polymer_pairs.len() == 0 { return Err(template.last().error(template.last().text(), "a template at least two elements long")); }
+
+    // the pairs the polymer can ever hold, in the order they first turn up
+    let mut reachable = polymer_pairs.clone();
+
+    for polymer in polymer_pairs {
+        let value = polymer_count.get(&polymer);
+
+        if value.is_none() { polymer_count.insert(polymer, 1); }
+        else { polymer_count.insert(polymer, value.unwrap()+1); }
+    }
+
+    let mut polymer_rules = HashMap::<PolymerPair, char>::new();
+
+    while let Some(line) = input.next_line() {
+        let (pair, element) = line.split_once(line.text(), " -> ", "a rule like CH -> B")?;
+        let polymer = PolymerPair::from_str(pair).ok_or(line.error(pair, "a pair of elements"))?;
+        let mut rule = element.chars();
+
+        match (rule.next(), rule.next()) {
+            (Some(c), None) => { polymer_rules.insert(polymer, c); },
+            _ => return Err(line.error(element, "a single element")),
+        }
+
+    }
+
+    // every pair has to have a rule, or the polymer can't grow past it
+    let mut i = 0;
+
+    while i < reachable.len() {
+        let polymer = reachable[i];
+        let c = polymer_rules.get(&polymer).ok_or_else(|| input.end(&format!("a rule for {}{}", polymer.0, polymer.1)))?;
+        let (left, right) = polymer.insert(*c);
+
+        for pair in [left, right] {
+            if !reachable.contains(&pair) { reachable.push(pair); }
+        }
+
+        i += 1;
+    }
+
+    Ok((first, polymer_count, polymer_rules))
+}
+
+fn evolve(rules: &HashMap<PolymerPair, char>, count: &HashMap<PolymerPair, usize>) -> HashMap<PolymerPair, usize> {
+    let mut new_count = HashMap::<PolymerPair, usize>::new();
+        
+    for (polymer, occurences) in count {
+        let (left, right) = polymer.insert(*rules.get(&polymer).unwrap());
+
+        let left_value = new_count.get(&left);
+
+        if left_value.is_none() { new_count.insert(left, *occurences); }
+        else { new_count.insert(left, left_value.unwrap()+*occurences); }
+
+        let right_value = new_count.get(&right);
+        
+        if right_value.is_none() { new_count.insert(right, *occurences); }
+        else { new_count.insert(right, right_value.unwrap()+*occurences); }
+    }
+
+    new_count
+}
+
+fn iterate(rules: &HashMap<PolymerPair, char>, count: &HashMap<PolymerPair, usize>, first: char, steps: usize) -> usize {
+    let mut result = count.clone();
+
+    for _ in 0..steps {
+        result = evolve(rules, &result);
+    }
+
+    // every letter is the right half of exactly one pair, except the first one
+    let mut letter_count = HashMap::<char, usize>::new();
+    letter_count.insert(first, 1);
+
+    for (pair, count) in result {
+        let value = letter_count.get(&pair.1);
+
+        if value.is_none() { letter_count.insert(pair.1, count); }
+        else { letter_count.insert(pair.1, value.unwrap()+count); }
+    }
+
+    let mut min = usize::MAX;
+    let mut max = 0;
+
+    for (_, count) in letter_count {
+        if count <= min { min = count; }
+        if count >= max { max = count; }
+    }
+
+    max - min
+}
+
+pub struct ExtendedPolymerization;
+impl Solution for ExtendedPolymerization {
+    type Input = (char, HashMap<PolymerPair, usize>, HashMap<PolymerPair, char>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_polymers(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1((first, count, rules): &Self::Input) -> Answer {
+        iterate(rules, count, *first, 10).into()
+    }
+    fn part2((first, count, rules): &Self::Input) -> Answer {
+        iterate(rules, count, *first, 40).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(ExtendedPolymerization::solve(EXAMPLE, 1), Ok(Answer::from(1588)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(ExtendedPolymerization::solve(EXAMPLE, 2), Ok(Answer::from(2188189693529u64)));
+    }
+    #[test]
+    fn missing_rules() {
+        let error = |text: &str| ExtendedPolymerization::parse(text).unwrap_err().to_string();
+
+        assert_eq!(error("NNCB\n\nCH -> B"), "line 4: input ended, expected a rule for NN");
+        // NC makes NB, which has no rule of its own
+        assert_eq!(error("NC\n\nNC -> B\nBC -> C\n"), "line 5: input ended, expected a rule for NB");
+    }
+}
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, BinaryHeap};
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Grid, Neighborhood};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct Node(usize);
+
+#[derive(Clone, Debug)]
+pub struct Graph {
+    nodes: Grid<Node>,
+}
+impl Graph {
+    fn read(input: &mut Reader) -> Result<Self, ParseError> {
+        let nodes = Grid::read(input, "a risk level from 0 to 9", |x| x.to_digit(10).map(|x| Node(x as usize)))?;
+
+        Ok(Self { nodes })
+    }
+    fn traverse(&self) -> usize {
+        // binary heap relies on the Ord trait, so we have to implement a bunch of boilerplate...
+        #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+        struct Visit {
+            label: Coordinate,
+            risk: usize,
+        }
+        impl Visit {
+            fn new(label: Coordinate, risk: usize) -> Self { Self { label, risk } }
+        }
+        impl Ord for Visit {
+            fn cmp(&self, other: &Self) -> Ordering {
+                other.risk.cmp(&self.risk)
+                    .then_with(|| self.label.0.cmp(&other.label.0))
+                    .then_with(|| self.label.1.cmp(&other.label.1))
+            }
+        }
+        impl PartialOrd for Visit {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        let start = Coordinate(0,0);
+        let end = Coordinate(self.nodes.width()-1,self.nodes.height()-1);
+
+        let mut risks = HashMap::<Coordinate, usize>::new();
+        let mut heap = BinaryHeap::<Visit>::new();
+
+        risks.insert(start, 0);
+        heap.push(Visit::new(start, 0));
+
+        while let Some(Visit { label, risk }) = heap.pop() {
+            if label == end { return risk; }
+            
+            for neighbor in self.nodes.neighbors(label, Neighborhood::Four) {
+                let new_risk = risk + self.nodes[neighbor].0;
+                let shorter = risks.get(&neighbor).map_or(true, |&current| new_risk < current);
+
+                if !shorter { continue; }
+
+                risks.insert(neighbor, new_risk);
+                heap.push(Visit::new(neighbor, new_risk));
+            }
+        }
+
+        *risks.get(&end).unwrap()
+    }
+    fn inflate(&self) -> Graph {
+        let (width, height) = (self.nodes.width(), self.nodes.height());
+
+        let nodes = Grid::from_fn(width*5, height*5, |coord| {
+            let (nx, ny) = (coord.0 / width, coord.1 / height);
+            let original_risk = self.nodes[Coordinate(coord.0 % width, coord.1 % height)].0;
+            let new_risk = original_risk + nx + ny;
+            let mut wrapped_risk = new_risk % 10;
+
+            if new_risk >= 10 { wrapped_risk += new_risk / 10; }
+
+            Node(wrapped_risk)
+        });
+
+        Graph { nodes }
+    }
+}
+        
+pub struct Chiton;
+impl Solution for Chiton {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Graph::read(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(graph: &Self::Input) -> Answer {
+        graph.traverse().into()
+    }
+    fn part2(graph: &Self::Input) -> Answer {
+        graph.inflate().traverse().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Chiton::solve(EXAMPLE, 1), Ok(Answer::from(40)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(Chiton::solve(EXAMPLE, 2), Ok(Answer::from(315)));
+    }
+}
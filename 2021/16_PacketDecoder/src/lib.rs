@@ -0,0 +1,886 @@
+use std::fmt;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+mod number;
+pub use number::BigNumber;
+
+/// Reads a transmission a field at a time without copying it, keeping track of how many bits
+/// have been read so packets can tell where their subpackets end.
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct Bitstream<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+impl<'a> Bitstream<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+    /// The number of bits read so far.
+    fn offset(&self) -> usize { self.offset }
+    fn remaining(&self) -> usize { self.bytes.len()*8 - self.offset }
+    /// Reads the next `size` bits, up to 64 of them, most significant first.
+    fn read(&mut self, size: usize) -> Result<u64, ()> {
+        assert!(size <= 64, "can't read more than 64 bits at once");
+
+        if size > self.remaining() { return Err(()); }
+
+        let mut result = 0u64;
+        let mut total = size;
+
+        while total > 0 {
+            let used = self.offset % 8;
+            let take = total.min(8 - used);
+            let bits = (self.bytes[self.offset / 8] as u64 >> (8 - used - take)) & ((1 << take) - 1);
+
+            result = (result << take) | bits;
+            self.offset += take;
+            total -= take;
+        }
+
+        Ok(result)
+    }
+    /// Skips the padding after a packet, up to the start of the next byte.
+    fn eop(&mut self) {
+        self.offset = (self.offset+7) / 8 * 8;
+    }
+    fn read_packets(&mut self) -> Result<Vec<Packet>, ()> {
+        let mut packets = Vec::<Packet>::new();
+
+        while self.remaining() > 0 {
+            packets.push(Packet::parse(self)?);
+            self.eop();
+        }
+
+        Ok(packets)
+    }
+}
+
+/// Builds a transmission a field at a time, the inverse of reading a `Bitstream`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct BitstreamWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+impl BitstreamWriter {
+    fn new() -> Self {
+        Self { bytes: Vec::<u8>::new(), len: 0 }
+    }
+    /// Writes the low `size` bits of `value`, up to 64 of them, most significant first.
+    fn write(&mut self, value: u64, size: usize) {
+        assert!(size <= 64, "can't write more than 64 bits at once");
+
+        let mut total = size;
+
+        while total > 0 {
+            let used = self.len % 8;
+
+            if used == 0 { self.bytes.push(0); }
+
+            let take = total.min(8 - used);
+            let bits = (value >> (total - take)) & ((1 << take) - 1);
+
+            *self.bytes.last_mut().unwrap() |= (bits << (8 - used - take)) as u8;
+            self.len += take;
+            total -= take;
+        }
+    }
+    /// The bits written so far as hex, padded with zeroes to a whole number of bytes.
+    fn to_hex(&self) -> String {
+        self.bytes.iter().map(|x| format!("{:02X}", x)).collect()
+    }
+}
+
+/// A packet and everything inside it. Packets can nest as deep as a transmission is long, so
+/// everything that walks them keeps its own stack rather than recursing, apart from the
+/// derived traits.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Packet {
+    version: u8,
+    type_id: u8,
+    packet_type: PacketType,
+}
+impl Packet {
+    /// A literal packet holding `value` in as few groups of four bits as it takes.
+    pub fn literal(version: u8, value: usize) -> Self {
+        Self::literal_nibbles(version, BigNumber::from_u64(value as u64).to_nibbles())
+    }
+    fn literal_nibbles(version: u8, nibbles: Vec<u8>) -> Self {
+        assert!(version < 8, "versions are three bits");
+
+        let blocks = nibbles.iter().enumerate()
+            .map(|(i, &value)| LiteralBlock { more: i+1 < nibbles.len(), value })
+            .collect();
+
+        Self { version, type_id: 4, packet_type: PacketType::LiteralPacket(LiteralPacket { blocks }) }
+    }
+    /// An operator packet, counting its subpackets in the header unless there are too many to
+    /// fit in 11 bits, in which case it gives their length instead if that fits in 15. With too
+    /// many subpackets for either, the packet can be built but `encode` fails.
+    pub fn operator(version: u8, type_id: u8, subpackets: Vec<Packet>) -> Self {
+        assert!(version < 8, "versions are three bits");
+        assert!(type_id < 8 && type_id != 4, "operators are type 0 to 3 or 5 to 7");
+
+        let bits = || subpackets.iter().map(|x| x.bit_length()).sum::<usize>();
+        let length_type = if subpackets.len() < (1 << 11) || bits() >= (1 << 15) { LengthType::Packets } else { LengthType::Bits };
+
+        Self { version, type_id, packet_type: PacketType::OperatorPacket(OperatorPacket { length_type, subpackets }) }
+    }
+    /// The packet as a transmission in hex, padded to a whole number of bytes, unless one of
+    /// its operators has more subpackets than its header can describe.
+    pub fn encode(&self) -> Result<String, EncodingError> {
+        let mut writer = BitstreamWriter::new();
+
+        self.write(&mut writer)?;
+        Ok(writer.to_hex())
+    }
+    fn write(&self, writer: &mut BitstreamWriter) -> Result<(), EncodingError> {
+        // the transmission lays packets out in the same order as `layout` lists them
+        for (packet, length) in self.layout() {
+            writer.write(packet.version as u64, 3);
+            writer.write(packet.type_id as u64, 3);
+
+            match &packet.packet_type {
+                PacketType::LiteralPacket(literal) => literal.write(writer),
+                PacketType::OperatorPacket(operator) => operator.write_header(writer, length - packet.header_length())?,
+            }
+        }
+
+        Ok(())
+    }
+    /// How many bits the packet takes up in a transmission, not counting padding.
+    fn bit_length(&self) -> usize {
+        self.layout()[0].1
+    }
+    /// How many bits the packet takes up before its subpackets, or altogether for a literal.
+    fn header_length(&self) -> usize {
+        match &self.packet_type {
+            PacketType::LiteralPacket(literal) => 6 + literal.blocks.len()*5,
+            PacketType::OperatorPacket(operator) => match operator.length_type { LengthType::Bits => 22, LengthType::Packets => 18 },
+        }
+    }
+    /// Every packet from this one down, parents before their subpackets, each with its length in bits.
+    fn layout(&self) -> Vec<(&Packet, usize)> {
+        let mut packets = Vec::<(&Packet, usize)>::new();
+        let mut parents = Vec::<usize>::new();
+        let mut stack = vec![(self, 0usize)];
+
+        while let Some((packet, parent)) = stack.pop() {
+            packets.push((packet, packet.header_length()));
+            parents.push(parent);
+            stack.extend(packet.subpackets().iter().rev().map(|x| (x, packets.len()-1)));
+        }
+
+        // every packet comes after its parent, so each is complete by the time it's added to it
+        for i in (1..packets.len()).rev() {
+            packets[parents[i]].1 += packets[i].1;
+        }
+
+        packets
+    }
+    fn subpackets(&self) -> &[Packet] {
+        match &self.packet_type {
+            PacketType::LiteralPacket(_) => &[],
+            PacketType::OperatorPacket(operator) => &operator.subpackets,
+        }
+    }
+    /// Reads a packet and everything inside it. Packets can nest as deep as the transmission is
+    /// long, so the operators still waiting on subpackets are kept on a stack of their own.
+    fn parse(stream: &mut Bitstream) -> Result<Packet, ()> {
+        let mut open = Vec::<OpenOperator>::new();
+
+        loop {
+            let version = stream.read(3)? as u8;
+            let type_id = stream.read(3)? as u8;
+
+            let mut packet = if type_id == 4 {
+                Packet { version, type_id, packet_type: PacketType::LiteralPacket(LiteralPacket::parse(stream)?) }
+            }
+            else {
+                let operator = OpenOperator::parse(stream, version, type_id)?;
+
+                if !operator.is_complete(stream)? {
+                    open.push(operator);
+                    continue;
+                }
+
+                operator.close()
+            };
+
+            // hand the packet to its parent, along with any parents that are now complete too
+            loop {
+                match open.last_mut() {
+                    None => return Ok(packet),
+                    Some(parent) => {
+                        parent.subpackets.push(packet);
+
+                        if !parent.is_complete(stream)? { break; }
+
+                        packet = open.pop().unwrap().close();
+                    },
+                }
+            }
+        }
+    }
+    fn version_sum(&self) -> usize {
+        let mut result = 0usize;
+        let mut stack = vec![self];
+
+        while let Some(packet) = stack.pop() {
+            result += packet.version as usize;
+            stack.extend(packet.subpackets());
+        }
+
+        result
+    }
+    /// The packet's value, worked out in `V`: `u64` fails on overflow rather than wrapping,
+    /// and `BigNumber` evaluates any transmission exactly.
+    pub fn evaluate<V: Value>(&self) -> Result<V, EvaluationError> {
+        // each operator with how many of its subpackets have been evaluated, whose values are
+        // the last ones on `values`
+        let mut stack = vec![(self, 0usize)];
+        let mut values = Vec::<V>::new();
+
+        while let Some((packet, evaluated)) = stack.pop() {
+            let subpackets = packet.subpackets();
+
+            match &packet.packet_type {
+                PacketType::LiteralPacket(literal) => values.push(V::from_nibbles(literal.nibbles()).ok_or(EvaluationError::Overflow)?),
+                PacketType::OperatorPacket(_) if evaluated < subpackets.len() => {
+                    stack.push((packet, evaluated+1));
+                    stack.push((&subpackets[evaluated], 0));
+                },
+                PacketType::OperatorPacket(_) => {
+                    let operands = values.split_off(values.len() - subpackets.len());
+                    values.push(apply(packet.type_id, operands)?);
+                },
+            }
+        }
+
+        Ok(values.pop().unwrap())
+    }
+    /// The packet as an S-expression like `(sum v1@0 (lit v6@18 3) (gt v0@29 ...))`, where
+    /// each packet's version and the bit it starts at in the transmission follow its name.
+    /// Operators whose header gives a length in bits rather than a count are marked `bits`.
+    pub fn to_expression(&self) -> String {
+        let mut expression = String::new();
+        let mut offset = 0usize;
+        // `None` closes the innermost packet still open
+        let mut stack = vec![Some(self)];
+
+        while let Some(step) = stack.pop() {
+            let packet = match step {
+                Some(packet) => packet,
+                None => { expression.push(')'); continue; },
+            };
+
+            if !expression.is_empty() { expression.push(' '); }
+
+            expression.push_str(&format!("({} v{}@{}", EXPRESSION_NAMES[packet.type_id as usize], packet.version, offset));
+
+            match &packet.packet_type {
+                PacketType::LiteralPacket(literal) => {
+                    expression.push_str(&format!(" {})", BigNumber::from_nibbles(literal.nibbles()).to_string()));
+                },
+                PacketType::OperatorPacket(operator) => {
+                    if operator.length_type == LengthType::Bits { expression.push_str(" bits"); }
+
+                    stack.push(None);
+                    stack.extend(operator.subpackets.iter().rev().map(Some));
+                },
+            }
+
+            // packets are laid out in the order they're visited, so each starts where the
+            // header before it ended
+            offset += packet.header_length();
+        }
+
+        expression
+    }
+    /// Builds a packet from the form `to_expression` writes. Versions default to 0 and offsets
+    /// are optional and not checked, since encoding lays the packets out again anyway, so
+    /// `(sum (lit 3) (gt (lit 5) (lit 2)))` is enough to write a transmission by hand.
+    pub fn from_expression(line: &Line) -> Result<Self, ParseError> {
+        let mut tokens = ExpressionTokens::new(line.text());
+        // the packets whose ) hasn't been reached yet, innermost last
+        let mut open = vec![OpenExpression::read(line, &mut tokens)?];
+
+        let packet = loop {
+            let current = open.last_mut().unwrap();
+
+            match tokens.peek() {
+                None => return Err(line.end(")")),
+                Some(token) if token == "(" => {
+                    if current.type_id == 4 { return Err(line.error(token, "a literal value")); }
+
+                    open.push(OpenExpression::read(line, &mut tokens)?);
+                },
+                Some(token) if token == ")" => {
+                    tokens.next();
+
+                    let packet = open.pop().unwrap().close(line)?;
+
+                    match open.last_mut() {
+                        Some(parent) => parent.subpackets.push(packet),
+                        None => break packet,
+                    }
+                },
+                Some(token) => {
+                    tokens.next();
+                    current.annotate(line, token)?;
+                },
+            }
+        };
+
+        match tokens.next() {
+            Some(token) => Err(line.error(token, "the end of the expression")),
+            None => Ok(packet),
+        }
+    }
+}
+impl Drop for Packet {
+    fn drop(&mut self) {
+        // dropping the subpackets as they are would recurse once for every level they nest
+        let mut packets = self.take_subpackets();
+
+        while let Some(mut packet) = packets.pop() {
+            packets.append(&mut packet.take_subpackets());
+        }
+    }
+}
+impl Packet {
+    fn take_subpackets(&mut self) -> Vec<Packet> {
+        match &mut self.packet_type {
+            PacketType::LiteralPacket(_) => Vec::<Packet>::new(),
+            PacketType::OperatorPacket(operator) => std::mem::take(&mut operator.subpackets),
+        }
+    }
+}
+
+/// An operator read from a transmission whose subpackets are still being read.
+struct OpenOperator {
+    version: u8,
+    type_id: u8,
+    length_type: LengthType,
+    /// How many subpackets there are, or the bit the last of them ends at.
+    end: usize,
+    subpackets: Vec<Packet>,
+}
+impl OpenOperator {
+    /// Reads the rest of an operator's header, after its version and type id.
+    fn parse(stream: &mut Bitstream, version: u8, type_id: u8) -> Result<Self, ()> {
+        let (length_type, end) = if stream.read(1)? == 1 {
+            (LengthType::Packets, stream.read(11)? as usize)
+        }
+        else {
+            let length = stream.read(15)? as usize;
+            (LengthType::Bits, stream.offset() + length)
+        };
+
+        Ok(Self { version, type_id, length_type, end, subpackets: Vec::<Packet>::new() })
+    }
+    fn is_complete(&self, stream: &Bitstream) -> Result<bool, ()> {
+        match self.length_type {
+            LengthType::Packets => Ok(self.subpackets.len() == self.end),
+            // the last subpacket ran past the length the header gave
+            LengthType::Bits if stream.offset() > self.end => Err(()),
+            LengthType::Bits => Ok(stream.offset() == self.end),
+        }
+    }
+    fn close(self) -> Packet {
+        let operator = OperatorPacket { length_type: self.length_type, subpackets: self.subpackets };
+
+        Packet { version: self.version, type_id: self.type_id, packet_type: PacketType::OperatorPacket(operator) }
+    }
+}
+
+/// A packet in an expression whose ) hasn't been reached yet.
+struct OpenExpression {
+    version: u8,
+    type_id: u8,
+    length_type: LengthType,
+    subpackets: Vec<Packet>,
+    value: Option<BigNumber>,
+}
+impl OpenExpression {
+    /// Reads the ( and name a packet starts with.
+    fn read(line: &Line, tokens: &mut ExpressionTokens) -> Result<Self, ParseError> {
+        let open = tokens.next().ok_or(line.end("an expression like (lit 3)"))?;
+
+        if open != "(" { return Err(line.error(open, "(")); }
+
+        let name = tokens.next().ok_or(line.end("a packet name"))?;
+        let type_id = EXPRESSION_NAMES.iter().position(|x| *x == name)
+            .ok_or(line.error(name, "sum, product, min, max, lit, gt, lt or eq"))? as u8;
+
+        Ok(Self { version: 0, type_id, length_type: LengthType::Packets, subpackets: Vec::<Packet>::new(), value: None })
+    }
+    /// Takes in a token between the name and the subpackets: a version, `bits` or a literal's value.
+    fn annotate(&mut self, line: &Line, token: &str) -> Result<(), ParseError> {
+        if let Some(annotation) = token.strip_prefix('v') {
+            let (number, offset) = annotation.split_once('@').unwrap_or((annotation, "0"));
+
+            self.version = line.parse(number, "a version from 0 to 7")?;
+            line.parse::<usize>(offset, "a bit offset")?;
+
+            if self.version > 7 { return Err(line.error(number, "a version from 0 to 7")); }
+        }
+        else if token == "bits" && self.type_id != 4 { self.length_type = LengthType::Bits; }
+        else if self.type_id == 4 && self.value.is_none() { self.value = Some(BigNumber::from_decimal(token).ok_or(line.error(token, "a literal value"))?); }
+        else { return Err(line.error(token, "a version like v1@0, a subpacket or )")); }
+
+        Ok(())
+    }
+    fn close(self, line: &Line) -> Result<Packet, ParseError> {
+        if self.type_id == 4 {
+            return Ok(Packet::literal_nibbles(self.version, self.value.ok_or(line.end("a literal value"))?.to_nibbles()));
+        }
+
+        let mut packet = Packet::operator(self.version, self.type_id, self.subpackets);
+
+        if let PacketType::OperatorPacket(operator) = &mut packet.packet_type {
+            if self.length_type == LengthType::Bits { operator.length_type = self.length_type; }
+        }
+
+        Ok(packet)
+    }
+}
+
+/// The name of each type id in an expression.
+const EXPRESSION_NAMES: [&str; 8] = ["sum", "product", "min", "max", "lit", "gt", "lt", "eq"];
+
+/// Splits an expression into parentheses and words, each sliced out of the original text so
+/// errors point at the right column.
+struct ExpressionTokens<'a> {
+    rest: &'a str,
+}
+impl<'a> ExpressionTokens<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { rest: text }
+    }
+    fn peek(&self) -> Option<&'a str> {
+        let rest = self.rest.trim_start();
+
+        if rest.starts_with('(') || rest.starts_with(')') { return Some(&rest[..1]); }
+
+        let end = rest.find(|c: char| c.is_whitespace() || c == '(' || c == ')').unwrap_or(rest.len());
+
+        if end == 0 { None } else { Some(&rest[..end]) }
+    }
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.peek()?;
+        let start = token.as_ptr() as usize - self.rest.as_ptr() as usize;
+
+        self.rest = &self.rest[start+token.len()..];
+        Some(token)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum PacketType {
+    LiteralPacket(LiteralPacket),
+    OperatorPacket(OperatorPacket),
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct LiteralBlock {
+    more: bool,
+    /// The four bits of the value this block holds.
+    value: u8,
+}
+impl LiteralBlock {
+    fn parse(stream: &mut Bitstream) -> Result<Self, ()> {
+        let block = stream.read(5)?;
+
+        Ok(Self { more: block >> 4 == 1, value: (block & 0xF) as u8 })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct LiteralPacket {
+    blocks: Vec<LiteralBlock>,
+}
+impl LiteralPacket {
+    fn parse(stream: &mut Bitstream) -> Result<Self, ()> {
+        let mut blocks = Vec::<LiteralBlock>::new();
+
+        loop {
+            let block = LiteralBlock::parse(stream)?;
+            let has_more = block.more;
+
+            blocks.push(block);
+
+            if !has_more { break; }
+        }
+
+        Ok(Self { blocks })
+    }
+    fn nibbles(&self) -> impl Iterator<Item=u8> + '_ {
+        self.blocks.iter().map(|x| x.value)
+    }
+    fn write(&self, writer: &mut BitstreamWriter) {
+        for block in &self.blocks {
+            writer.write(((block.more as u64) << 4) | block.value as u64, 5);
+        }
+    }
+}
+
+/// How an operator's header says where its subpackets end.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum LengthType {
+    /// Length type 0: the subpackets' total length in bits, in 15 bits.
+    Bits,
+    /// Length type 1: the number of subpackets, in 11 bits.
+    Packets,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct OperatorPacket {
+    length_type: LengthType,
+    subpackets: Vec<Packet>,
+}
+impl OperatorPacket {
+    /// Writes the length type and length, given the subpackets' total length in bits.
+    fn write_header(&self, writer: &mut BitstreamWriter, bits: usize) -> Result<(), EncodingError> {
+        match self.length_type {
+            LengthType::Bits => {
+                if bits >= (1 << 15) { return Err(EncodingError::TooLong(bits)); }
+
+                writer.write(0, 1);
+                writer.write(bits as u64, 15);
+            },
+            LengthType::Packets => {
+                if self.subpackets.len() >= (1 << 11) { return Err(EncodingError::TooManySubpackets(self.subpackets.len())); }
+
+                writer.write(1, 1);
+                writer.write(self.subpackets.len() as u64, 11);
+            },
+        }
+
+        Ok(())
+    }
+}
+
+/// An operator's value given the values of its subpackets.
+fn apply<V: Value>(type_id: u8, values: Vec<V>) -> Result<V, EvaluationError> {
+    if values.len() == 0 && type_id <= 3 { return Err(EvaluationError::EmptyOperator(type_id)); }
+    if values.len() != 2 && type_id >= 5 { return Err(EvaluationError::Arity(type_id, values.len())); }
+
+    let mut values = values.into_iter();
+
+    match type_id {
+        0 => values.try_fold(V::from_bool(false), |sum, x| sum.checked_add(&x).ok_or(EvaluationError::Overflow)),
+        1 => values.try_fold(V::from_bool(true), |product, x| product.checked_mul(&x).ok_or(EvaluationError::Overflow)),
+        2 => Ok(values.min().unwrap()),
+        3 => Ok(values.max().unwrap()),
+        _ => {
+            let (left, right) = (values.next().unwrap(), values.next().unwrap());
+
+            match type_id {
+                5 => Ok(V::from_bool(left > right)),
+                6 => Ok(V::from_bool(left < right)),
+                _ => Ok(V::from_bool(left == right)),
+            }
+        },
+    }
+}
+
+/// Why a packet couldn't be encoded.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EncodingError {
+    /// An operator counting its subpackets had this many, more than 11 bits can count.
+    TooManySubpackets(usize),
+    /// An operator giving its subpackets' length had this many bits of them, more than 15 bits can give.
+    TooLong(usize),
+}
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TooManySubpackets(count) => write!(f, "{} subpackets are too many for a length type 1 header", count),
+            Self::TooLong(bits) => write!(f, "{} bits of subpackets are too long for a length type 0 header", bits),
+        }
+    }
+}
+impl std::error::Error for EncodingError {}
+
+/// Why a packet couldn't be evaluated.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EvaluationError {
+    /// A literal, sum or product didn't fit in the type being evaluated with.
+    Overflow,
+    /// A sum, product, minimum or maximum, given by its type id, had no subpackets.
+    EmptyOperator(u8),
+    /// A comparison, given by its type id, didn't have exactly two subpackets, but this many.
+    Arity(u8, usize),
+}
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "the value overflowed"),
+            Self::EmptyOperator(type_id) => write!(f, "{} of no subpackets", EXPRESSION_NAMES[*type_id as usize]),
+            Self::Arity(type_id, count) => write!(f, "{} of {} subpackets rather than 2", EXPRESSION_NAMES[*type_id as usize], count),
+        }
+    }
+}
+impl std::error::Error for EvaluationError {}
+
+/// A type packets can be evaluated in.
+pub trait Value: Ord + Sized {
+    /// The number with these hexadecimal digits, most significant first, if it fits.
+    fn from_nibbles<I: Iterator<Item=u8>>(nibbles: I) -> Option<Self>;
+    /// 1 for true and 0 for false, which is also how sums and products start.
+    fn from_bool(value: bool) -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+impl Value for u64 {
+    fn from_nibbles<I: Iterator<Item=u8>>(nibbles: I) -> Option<Self> {
+        let mut result = 0u64;
+
+        for nibble in nibbles {
+            if result >> 60 != 0 { return None; }
+
+            result = (result << 4) | nibble as u64;
+        }
+
+        Some(result)
+    }
+    fn from_bool(value: bool) -> Self { value as u64 }
+    fn checked_add(&self, other: &Self) -> Option<Self> { u64::checked_add(*self, *other) }
+    fn checked_mul(&self, other: &Self) -> Option<Self> { u64::checked_mul(*self, *other) }
+}
+impl Value for BigNumber {
+    fn from_nibbles<I: Iterator<Item=u8>>(nibbles: I) -> Option<Self> { Some(BigNumber::from_nibbles(nibbles)) }
+    fn from_bool(value: bool) -> Self { BigNumber::from_u64(value as u64) }
+    fn checked_add(&self, other: &Self) -> Option<Self> { Some(self.add(other)) }
+    fn checked_mul(&self, other: &Self) -> Option<Self> { Some(self.multiply(other)) }
+}
+
+fn read_pcap(input: &mut Reader) -> Result<Vec<u8>, ParseError> {
+    let line = input.expect_line("a hexadecimal transmission")?;
+    let digits = line.map_chars("a hexadecimal digit", |x| x.to_digit(16).map(|x| x as u8))?;
+
+    if digits.len() % 2 != 0 { return Err(line.end("a whole number of bytes")); }
+
+    Ok(digits.chunks(2).map(|x| (x[0] << 4) | x[1]).collect())
+}
+
+pub struct PacketDecoder;
+impl Solution for PacketDecoder {
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let bytes = read_pcap(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)?;
+        let mut stream = Bitstream::new(&bytes);
+
+        // the bitstream can only fail by running out of bits partway through a packet, either
+        // at the end of the transmission or at the end of the length its parent gave
+        stream.read_packets().map_err(|_| Error::Parse(ParseError::unexpected_end(1, "the rest of the packet")))
+    }
+    fn part1(packets: &Self::Input) -> Answer {
+        packets.iter().map(|x| x.version_sum()).sum::<usize>().into()
+    }
+    fn part2(packets: &Self::Input) -> Answer {
+        // a transmission is a single outermost packet, anything after it is padding, and it's
+        // only evaluated exactly if it doesn't fit in 64 bits. A packet that can't be evaluated
+        // at all has no answer, never one that could be recorded as right; `Packet::evaluate`
+        // says why
+        match packets[0].evaluate::<u64>() {
+            Ok(value) => value.into(),
+            Err(EvaluationError::Overflow) => packets[0].evaluate::<BigNumber>().map_or(Answer::None, |x| x.to_string().into()),
+            Err(_) => Answer::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_packet() {
+        let packets = PacketDecoder::parse("D2FE28\n").unwrap();
+
+        assert_eq!(packets[0].version, 6);
+        assert_eq!(packets[0].evaluate(), Ok(2021u64));
+    }
+    #[test]
+    fn malformed_transmissions() {
+        assert_eq!(PacketDecoder::parse("D2FG28\n").unwrap_err().to_string(), "line 1, column 4: expected a hexadecimal digit, found \"G\"");
+        assert_eq!(PacketDecoder::parse("D2FE2\n").unwrap_err().to_string(), "line 1: input ended, expected a whole number of bytes");
+        assert_eq!(PacketDecoder::parse("D2FE\n").unwrap_err().to_string(), "line 1: input ended, expected the rest of the packet");
+    }
+    #[test]
+    fn bitstream_fields() {
+        let bytes = [0xD2, 0xFE, 0x28, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+        let mut stream = Bitstream::new(&bytes);
+
+        assert_eq!(stream.read(3), Ok(6));
+        assert_eq!(stream.read(3), Ok(4));
+        assert_eq!(stream.read(15), Ok(0b101111111000101));
+        assert_eq!(stream.offset(), 21);
+        stream.eop();
+        assert_eq!(stream.read(64), Ok(0x0123456789ABCDEF));
+        assert_eq!(stream.read(1), Err(()));
+
+        let mut writer = BitstreamWriter::new();
+        writer.write(6, 3);
+        writer.write(4, 3);
+        writer.write(0b101111111000101, 15);
+        writer.write(0x0123456789ABCDEF, 64);
+        assert_eq!(writer.len, 85);
+        assert_eq!(writer.to_hex(), "D2FE28091A2B3C4D5E6F78");
+    }
+    #[test]
+    fn subpackets_overrunning_their_length() {
+        // an operator claiming 10 bits of subpackets, holding an 11 bit literal
+        assert_eq!(PacketDecoder::parse("3800294080").unwrap_err().to_string(), "line 1: input ended, expected the rest of the packet");
+    }
+    #[test]
+    fn multi_megabyte_transmission() {
+        let packets: Vec<Packet> = (0..500_000).map(|x| Packet::literal((x % 8) as u8, x)).collect();
+        let transmission: String = packets.iter().map(|x| x.encode().unwrap()).collect();
+
+        assert!(transmission.len() > 3_000_000);
+        assert_eq!(PacketDecoder::parse(&transmission).unwrap(), packets);
+        assert_eq!(PacketDecoder::solve(&transmission, 1), Ok(Answer::from(500_000 / 8 * 28)));
+    }
+    #[test]
+    fn deeply_nested_packets() {
+        // a sum of a sum of ... of a literal 1, 135 KB of hex
+        let depth = 30_000;
+        let mut writer = BitstreamWriter::new();
+
+        for _ in 0..depth {
+            writer.write(0b000_000_1, 7);
+            writer.write(1, 11);
+        }
+
+        writer.write(0b000_100_00001, 11);
+
+        let transmission = writer.to_hex();
+        let packets = PacketDecoder::parse(&transmission).unwrap();
+
+        assert_eq!(PacketDecoder::solve(&transmission, 1), Ok(Answer::from(0)));
+        assert_eq!(PacketDecoder::solve(&transmission, 2), Ok(Answer::from(1)));
+        assert_eq!(packets[0].encode().unwrap(), transmission);
+
+        let expression = packets[0].to_expression();
+        assert!(expression.ends_with(&format!("(sum v0@{} (lit v0@{} 1){}", (depth-1)*18, depth*18, ")".repeat(depth))));
+        assert_eq!(Packet::from_expression(&Line::new(1, &expression)).unwrap().encode().unwrap(), transmission);
+    }
+    fn all_packets(packet: &Packet) -> Vec<&Packet> {
+        let mut packets = vec![packet];
+
+        if let PacketType::OperatorPacket(operator) = &packet.packet_type {
+            packets.extend(operator.subpackets.iter().flat_map(all_packets));
+        }
+
+        packets
+    }
+
+    #[test]
+    fn encodes_back_to_hex() {
+        assert_eq!(PacketDecoder::parse("D2FE28\n").unwrap()[0].encode().unwrap(), "D2FE28");
+        assert_eq!(PacketDecoder::parse("38006F45291200\n").unwrap()[0].encode().unwrap(), "38006F45291200");
+        assert_eq!(PacketDecoder::parse("EE00D40C823060\n").unwrap()[0].encode().unwrap(), "EE00D40C823060");
+    }
+    #[test]
+    fn synthetic_packets() {
+        let packet = Packet::operator(1, 0, vec![Packet::literal(2, 0), Packet::literal(3, 2021), Packet::literal(0, usize::MAX)]);
+        let encoded = packet.encode().unwrap();
+
+        assert_eq!(encoded.len() % 2, 0);
+        assert_eq!(PacketDecoder::parse(&encoded).unwrap(), vec![packet.clone()]);
+        assert_eq!(PacketDecoder::solve(&encoded, 1), Ok(Answer::from(6)));
+        assert_eq!(Packet::literal(6, 2021), PacketDecoder::parse("D2FE28").unwrap()[0]);
+
+        let wide = Packet::operator(0, 0, vec![Packet::literal(0, 1); 2100]);
+        assert_eq!(PacketDecoder::parse(&wide.encode().unwrap()).unwrap(), vec![wide.clone()]);
+        assert_eq!(PacketDecoder::solve(&wide.encode().unwrap(), 2), Ok(Answer::from(2100)));
+    }
+    #[test]
+    fn headers_that_fit() {
+        let wide = Packet::operator(0, 0, vec![Packet::literal(0, 1); 3000]);
+        assert_eq!(wide.encode(), Err(EncodingError::TooManySubpackets(3000)));
+        assert_eq!(wide.encode().unwrap_err().to_string(), "3000 subpackets are too many for a length type 1 header");
+
+        let long = Packet::operator(0, 0, vec![Packet::literal(0, 1); 2100]);
+        assert!(long.to_expression().starts_with("(sum v0@0 bits (lit v0@22 1)"));
+
+        let forced = Packet::from_expression(&Line::new(1, &format!("(sum bits{})", " (lit 1)".repeat(3000)))).unwrap();
+        assert_eq!(forced.encode(), Err(EncodingError::TooLong(33000)));
+    }
+    #[test]
+    fn expressions() {
+        let packet = &PacketDecoder::parse("9C0141080250320F1802104A08").unwrap()[0];
+        let expression = "(eq v4@0 bits (sum v2@22 (lit v2@40 1) (lit v4@51 3)) (product v6@62 (lit v0@80 2) (lit v2@91 2)))";
+
+        assert_eq!(packet.to_expression(), expression);
+        assert_eq!(&Packet::from_expression(&Line::new(1, expression)).unwrap(), packet);
+
+        let packet = &PacketDecoder::parse("38006F45291200").unwrap()[0];
+        assert_eq!(packet.to_expression(), "(lt v1@0 bits (lit v6@22 10) (lit v2@33 20))");
+
+        let by_hand = Packet::from_expression(&Line::new(1, "(sum (lit 3) (gt (lit 5) (lit 2)))")).unwrap();
+        assert_eq!(PacketDecoder::solve(&by_hand.encode().unwrap(), 2), Ok(Answer::from(4)));
+        assert_eq!(by_hand.to_expression(), "(sum v0@0 (lit v0@18 3) (gt v0@29 (lit v0@47 5) (lit v0@58 2)))");
+    }
+    #[test]
+    fn malformed_expressions() {
+        let error = |text: &str| Packet::from_expression(&Line::new(1, text)).unwrap_err().to_string();
+
+        assert_eq!(error("(sum (lit 3)"), "line 1: input ended, expected )");
+        assert_eq!(error("(add (lit 3))"), "line 1, column 2: expected sum, product, min, max, lit, gt, lt or eq, found \"add\"");
+        assert_eq!(error("(lit v9 3)"), "line 1, column 7: expected a version from 0 to 7, found \"9\"");
+        assert_eq!(error("(lit 3 4)"), "line 1, column 8: expected a version like v1@0, a subpacket or ), found \"4\"");
+        assert_eq!(error("(lit 3) (lit 4)"), "line 1, column 9: expected the end of the expression, found \"(\"");
+    }
+    #[test]
+    fn checked_evaluation() {
+        let packet = |text: &str| Packet::from_expression(&Line::new(1, text)).unwrap();
+
+        assert_eq!(packet("(sum)").evaluate::<u64>(), Err(EvaluationError::EmptyOperator(0)));
+        assert_eq!(packet("(max (lit 1) (min))").evaluate::<u64>(), Err(EvaluationError::EmptyOperator(2)));
+        assert_eq!(packet("(gt (lit 1))").evaluate::<u64>(), Err(EvaluationError::Arity(5, 1)));
+        assert_eq!(packet("(eq (lit 1) (lit 1) (lit 1))").evaluate::<BigNumber>(), Err(EvaluationError::Arity(7, 3)));
+        assert_eq!(EvaluationError::Arity(6, 0).to_string(), "lt of 0 subpackets rather than 2");
+
+        let product = packet("(product (lit 18446744073709551615) (lit 2))");
+        assert_eq!(product.evaluate::<u64>(), Err(EvaluationError::Overflow));
+        assert_eq!(product.evaluate::<BigNumber>().unwrap().to_string(), "36893488147419103230");
+        assert_eq!(PacketDecoder::solve(&product.encode().unwrap(), 2), Ok(Answer::from("36893488147419103230")));
+
+        let literal = packet("(lit 340282366920938463463374607431768211456)");
+        assert_eq!(literal.evaluate::<u64>(), Err(EvaluationError::Overflow));
+        assert_eq!(literal.to_expression(), "(lit v0@0 340282366920938463463374607431768211456)");
+        assert_eq!(PacketDecoder::solve(&packet("(lt (lit 2) (sum))").encode().unwrap(), 2), Ok(Answer::None));
+        assert_eq!(PacketDecoder::solve(&packet("(gt (lit 18446744073709551616) (lit 1) (lit 2))").encode().unwrap(), 2), Ok(Answer::None));
+        assert_eq!(packet("(gt (lit 18446744073709551616) (lit 1) (lit 2))").evaluate::<BigNumber>(), Err(EvaluationError::Arity(5, 3)));
+    }
+    #[test]
+    fn every_input_packet_round_trips() {
+        let packets = PacketDecoder::parse(include_str!("../input.txt")).unwrap();
+
+        for packet in packets.iter().flat_map(all_packets) {
+            assert_eq!(PacketDecoder::parse(&packet.encode().unwrap()).unwrap(), vec![packet.clone()]);
+            assert_eq!(&Packet::from_expression(&Line::new(1, &packet.to_expression())).unwrap(), packet);
+        }
+    }
+    #[test]
+    fn part1_examples() {
+        assert_eq!(PacketDecoder::solve("8A004A801A8002F478\n", 1), Ok(Answer::from(16)));
+        assert_eq!(PacketDecoder::solve("620080001611562C8802118E34\n", 1), Ok(Answer::from(12)));
+        assert_eq!(PacketDecoder::solve("A0016C880162017C3686B18A3D4780\n", 1), Ok(Answer::from(31)));
+    }
+    #[test]
+    fn part2_examples() {
+        assert_eq!(PacketDecoder::solve("C200B40A82\n", 2), Ok(Answer::from(3)));
+        assert_eq!(PacketDecoder::solve("04005AC33890\n", 2), Ok(Answer::from(54)));
+        assert_eq!(PacketDecoder::solve("880086C3E88112\n", 2), Ok(Answer::from(7)));
+        assert_eq!(PacketDecoder::solve("CE00C43D881120\n", 2), Ok(Answer::from(9)));
+        assert_eq!(PacketDecoder::solve("D8005AC2A8F0\n", 2), Ok(Answer::from(1)));
+        assert_eq!(PacketDecoder::solve("F600BC2D8F\n", 2), Ok(Answer::from(0)));
+        assert_eq!(PacketDecoder::solve("9C005AC2F8F0\n", 2), Ok(Answer::from(0)));
+        assert_eq!(PacketDecoder::solve("9C0141080250320F1802104A08\n", 2), Ok(Answer::from(1)));
+    }
+}
@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+
+/// An unsigned integer of any size, for evaluating transmissions whose values don't fit in 64
+/// bits. Only what evaluation needs is here: building one from nibbles or decimal, adding,
+/// multiplying, comparing and printing.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BigNumber {
+    /// Base 2^32 digits, least significant first, with no zeroes at the end.
+    digits: Vec<u32>,
+}
+impl BigNumber {
+    pub fn zero() -> Self {
+        Self { digits: Vec::<u32>::new() }
+    }
+    pub fn from_u64(value: u64) -> Self {
+        let mut number = Self { digits: vec![value as u32, (value >> 32) as u32] };
+
+        number.trim();
+        number
+    }
+    /// The number whose hexadecimal digits are `nibbles`, most significant first.
+    pub fn from_nibbles<I: IntoIterator<Item=u8>>(nibbles: I) -> Self {
+        let mut number = Self::zero();
+
+        for nibble in nibbles {
+            number.multiply_add(16, nibble as u32);
+        }
+
+        number
+    }
+    /// Parses a string of decimal digits, returning `None` if it's empty or holds anything else.
+    pub fn from_decimal(text: &str) -> Option<Self> {
+        if text.len() == 0 { return None; }
+
+        let mut number = Self::zero();
+
+        for c in text.chars() {
+            number.multiply_add(10, c.to_digit(10)?);
+        }
+
+        Some(number)
+    }
+    /// The number's hexadecimal digits, most significant first, with at least one digit.
+    pub fn to_nibbles(&self) -> Vec<u8> {
+        let mut nibbles: Vec<u8> = self.digits.iter()
+            .flat_map(|x| (0..8).map(move |i| ((x >> (i*4)) & 0xF) as u8))
+            .collect();
+
+        while nibbles.len() > 1 && nibbles[nibbles.len()-1] == 0 { nibbles.pop(); }
+        if nibbles.len() == 0 { nibbles.push(0); }
+
+        nibbles.reverse();
+        nibbles
+    }
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.digits.len() {
+            0 => Some(0),
+            1 => Some(self.digits[0] as u64),
+            2 => Some(self.digits[0] as u64 | (self.digits[1] as u64) << 32),
+            _ => None,
+        }
+    }
+    pub fn add(&self, other: &Self) -> Self {
+        let mut digits = Vec::<u32>::new();
+        let mut carry = 0u64;
+
+        for i in 0..self.digits.len().max(other.digits.len()) {
+            let sum = carry + *self.digits.get(i).unwrap_or(&0) as u64 + *other.digits.get(i).unwrap_or(&0) as u64;
+
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+
+        digits.push(carry as u32);
+
+        let mut number = Self { digits };
+
+        number.trim();
+        number
+    }
+    pub fn multiply(&self, other: &Self) -> Self {
+        let mut digits = vec![0u32; self.digits.len() + other.digits.len()];
+
+        for (i, &a) in self.digits.iter().enumerate() {
+            let mut carry = 0u64;
+
+            for (j, &b) in other.digits.iter().enumerate() {
+                let product = digits[i+j] as u64 + a as u64 * b as u64 + carry;
+
+                digits[i+j] = product as u32;
+                carry = product >> 32;
+            }
+
+            digits[i+other.digits.len()] = carry as u32;
+        }
+
+        let mut number = Self { digits };
+
+        number.trim();
+        number
+    }
+    /// The number in decimal.
+    pub fn to_string(&self) -> String {
+        if self.digits.len() == 0 { return "0".to_string(); }
+
+        // peel off nine decimal digits at a time, least significant first
+        let mut digits = self.digits.clone();
+        let mut chunks = Vec::<u32>::new();
+
+        while digits.len() > 0 {
+            let mut remainder = 0u64;
+
+            for digit in digits.iter_mut().rev() {
+                let value = (remainder << 32) | *digit as u64;
+
+                *digit = (value / 1_000_000_000) as u32;
+                remainder = value % 1_000_000_000;
+            }
+
+            chunks.push(remainder as u32);
+
+            while digits.last() == Some(&0) { digits.pop(); }
+        }
+
+        let mut result = chunks.pop().unwrap().to_string();
+
+        for chunk in chunks.iter().rev() {
+            result.push_str(&format!("{:09}", chunk));
+        }
+
+        result
+    }
+    fn multiply_add(&mut self, factor: u32, addend: u32) {
+        let mut carry = addend as u64;
+
+        for digit in self.digits.iter_mut() {
+            let value = *digit as u64 * factor as u64 + carry;
+
+            *digit = value as u32;
+            carry = value >> 32;
+        }
+
+        if carry > 0 { self.digits.push(carry as u32); }
+    }
+    fn trim(&mut self) {
+        while self.digits.last() == Some(&0) { self.digits.pop(); }
+    }
+}
+impl Ord for BigNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.digits.len().cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+impl PartialOrd for BigNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let big = BigNumber::from_decimal("340282366920938463463374607431768211456").unwrap();
+
+        assert_eq!(BigNumber::from_u64(u64::MAX).multiply(&BigNumber::from_u64(u64::MAX)).add(&BigNumber::from_u64(u64::MAX)).add(&BigNumber::from_u64(u64::MAX)).add(&BigNumber::from_u64(1)), big);
+        assert_eq!(big.to_string(), "340282366920938463463374607431768211456");
+        assert_eq!(big.to_nibbles(), [vec![1], vec![0; 32]].concat());
+        assert_eq!(BigNumber::from_nibbles(big.to_nibbles()), big);
+        assert_eq!(BigNumber::from_nibbles(vec![0, 0, 7, 0xE, 5]).to_u64(), Some(2021));
+        assert_eq!(BigNumber::zero().to_nibbles(), vec![0]);
+        assert_eq!(BigNumber::zero().to_string(), "0");
+        assert_eq!(big.to_u64(), None);
+        assert!(BigNumber::from_u64(u64::MAX) < big);
+        assert!(BigNumber::from_u64(3) > BigNumber::from_u64(2));
+        assert_eq!(BigNumber::from_decimal("12a"), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::io;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 struct Velocity(isize, isize);
@@ -15,7 +15,7 @@ impl Velocity {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-struct Coordinate(isize, isize);
+pub struct Coordinate(isize, isize);
 impl Coordinate {
     fn add_velocity(&self, v: Velocity) -> Coordinate {
         Coordinate(self.0+v.0, self.1+v.1)
@@ -23,7 +23,7 @@ impl Coordinate {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-struct Rectangle(Coordinate, Coordinate);
+pub struct Rectangle(Coordinate, Coordinate);
 impl Rectangle {
     fn contains_x(&self, c: Coordinate) -> bool {
         let x1 = if self.0.0 < self.1.0 { self.0.0 } else { self.1.0 };
@@ -51,32 +51,28 @@ impl Rectangle {
     }
 }
 
-fn read_area() -> Result<Rectangle, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_range(s: &str, axis: &str, line: &Line) -> Result<(isize, isize), ParseError> {
+    let range = s.strip_prefix(axis).ok_or(line.error(s, &format!("a range starting with {}", axis)))?;
+    let (start, end) = line.split_once(range, "..", "a range like 20..30")?;
 
-    if let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { return Err(()); }
-        
-        buffer = buffer.trim().replace("target area: ", "");
-        let chunks: Vec<&str> = buffer.split(", ").collect();
+    Ok((line.parse(start, "a number")?, line.parse(end, "a number")?))
+}
 
-        let x_coords: Vec<&str> = chunks[0].split("=").collect();
-        let x_chunks: Vec<&str> = x_coords[1].split("..").collect();
-        let x1 = x_chunks[0].parse::<isize>().unwrap();
-        let x2 = x_chunks[1].parse::<isize>().unwrap();
+fn read_area(input: &mut Reader) -> Result<Rectangle, ParseError> {
+    if let Some(line) = input.next_line() {
+        let area = line.text().strip_prefix("target area: ")
+            .ok_or(line.error(line.text(), "a line like \"target area: x=20..30, y=-10..-5\""))?;
+        let (x_range, y_range) = line.split_once(area, ", ", "two ranges like x=20..30, y=-10..-5")?;
 
-        let y_coords: Vec<&str> = chunks[1].split("=").collect();
-        let y_chunks: Vec<&str> = y_coords[1].split("..").collect();
-        let y1 = y_chunks[0].parse::<isize>().unwrap();
-        let y2 = y_chunks[1].parse::<isize>().unwrap();
+        let (x1, x2) = read_range(x_range, "x=", &line)?;
+        let (y1, y2) = read_range(y_range, "y=", &line)?;
 
         let c1 = Coordinate(x1,y1);
         let c2 = Coordinate(x2,y2);
 
         Ok(Rectangle(c1, c2))
     }
-    else { Err(()) }
+    else { Err(input.end("a target area")) }
 }
 
 fn calculate_high_point(area: Rectangle) -> isize {
@@ -151,21 +147,33 @@ fn calculate_all_velocities(area: Rectangle) -> usize {
     velocities
 }
 
-fn part1() {
-    if let Ok(area) = read_area() {
-        println!("{}", calculate_high_point(area));
-    }
-    else { panic!("couldn't read area!"); }
-}
+pub struct TrickShot;
+impl Solution for TrickShot {
+    type Input = Rectangle;
 
-fn part2() {
-    if let Ok(area) = read_area() {
-        println!("{}", calculate_all_velocities(area));
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_area(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(area: &Self::Input) -> Answer {
+        calculate_high_point(*area).into()
+    }
+    fn part2(area: &Self::Input) -> Answer {
+        calculate_all_velocities(*area).into()
     }
-    else { panic!("couldn't read area!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "target area: x=20..30, y=-10..-5\n";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(TrickShot::solve(EXAMPLE, 1), Ok(Answer::from(45)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(TrickShot::solve(EXAMPLE, 2), Ok(Answer::from(112)));
+    }
 }
@@ -1,34 +1,45 @@
-use std::io;
 use std::collections::HashSet;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-enum SnailfishNumber {
+pub enum SnailfishNumber {
     Number(usize),
     Pair(Box<SnailfishNumber>,Box<SnailfishNumber>),
 }
 impl SnailfishNumber {
-    fn parse(s: &str) -> Self {
+    fn parse(s: &str, line: &Line) -> Result<Self, ParseError> {
         let mut pending_pairs = Vec::<Vec<SnailfishNumber>>::new();
         
-        for c in s.chars() {
+        for (i, c) in s.char_indices() {
+            let token = &s[i..i+c.len_utf8()];
+            
             if c == '[' { pending_pairs.push(Vec::<SnailfishNumber>::new()); }
             else if c == ']' {
-                let pair = pending_pairs.pop().unwrap();
+                let pair = pending_pairs.pop().ok_or(line.error(token, "a '[' before each ']'"))?;
 
-                if pending_pairs.len() == 0 { return Self::new_pair(&pair[0],&pair[1]); }
+                if pair.len() != 2 { return Err(line.error(token, "a pair of exactly two elements")); }
+
+                if pending_pairs.len() == 0 {
+                    let rest = &s[i+1..];
+                    
+                    if rest.len() > 0 { return Err(line.error(rest, "nothing after the outermost pair")); }
+                    
+                    return Ok(Self::new_pair(&pair[0],&pair[1]));
+                }
                 else {
                     let index = pending_pairs.len()-1;
                     pending_pairs[index].push(Self::new_pair(&pair[0],&pair[1]));
                 }
             }
             else if c == ',' { continue; }
-            else if c.is_digit(10) {
+            else if c.is_digit(10) && pending_pairs.len() > 0 {
                 let index = pending_pairs.len()-1;
                 pending_pairs[index].push(Self::new_number(c as usize - '0' as usize));
             }
+            else { return Err(line.error(token, "a digit inside a pair, '[', ']' or ','")); }
         }
 
-        panic!("no snail number parsed!");
+        Err(line.error(&s[s.len()..], "a ']' for each '['"))
     }
     fn new_pair(p1: &Self, p2: &Self) -> Self {
         Self::Pair(Box::new(p1.clone()),Box::new(p2.clone()))
@@ -45,24 +56,27 @@ impl SnailfishNumber {
     fn unwrap(&self) -> usize {
         if let Self::Number(n) = self { *n } else { panic!("can't unwrap a pair!"); }
     }
-    fn get_rightmost_node(&self) -> &Self {
+    fn get_rightmost_node(&mut self) -> &mut Self {
         let mut start = self;
 
-        while let Self::Pair(_,ref right) = start {
-            start = right;
+        loop {
+            match start {
+                Self::Pair(_, right) => start = right,
+                number => return number,
+            }
         }
-
-        start
     }
-    fn get_leftmost_node(&self) -> &Self {
+    fn get_leftmost_node(&mut self) -> &mut Self {
         let mut start = self;
 
-        while let Self::Pair(ref left, _) = start {
-            start = left;
+        loop {
+            match start {
+                Self::Pair(left, _) => start = left,
+                number => return number,
+            }
         }
-
-        start
     }
+    #[allow(dead_code)]
     fn to_string(&self) -> String {
         if let Self::Pair(p1,p2) = self {
             format!("[{},{}]", p1.to_string(), p2.to_string())
@@ -167,7 +181,7 @@ impl SnailfishNumber {
                 if let Self::Pair(ex_left,ex_right) = &*exploder {
                     if let Some(left_node) = left_target {
                         if (*left_node).is_pair() {
-                            let rightmost = (*left_node).get_rightmost_node() as *const SnailfishNumber as *mut SnailfishNumber;
+                            let rightmost = (*left_node).get_rightmost_node() as *mut SnailfishNumber;
                             *rightmost = Self::new_number((*rightmost).unwrap()+ex_left.unwrap());
                         }
                         else {
@@ -177,7 +191,7 @@ impl SnailfishNumber {
 
                     if let Some(right_node) = right_target {
                         if (*right_node).is_pair() {
-                            let leftmost = (*right_node).get_leftmost_node() as *const SnailfishNumber as *mut SnailfishNumber;
+                            let leftmost = (*right_node).get_leftmost_node() as *mut SnailfishNumber;
                             *leftmost = Self::new_number((*leftmost).unwrap()+ex_right.unwrap());
                         }
                         else {
@@ -249,25 +263,26 @@ impl SnailfishNumber {
     }
 }
 
-fn read_numbers() -> Result<Vec<SnailfishNumber>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_numbers(input: &mut Reader) -> Result<Vec<SnailfishNumber>, ParseError> {
     let mut numbers = Vec::<SnailfishNumber>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-
-        numbers.push(SnailfishNumber::parse(buffer.trim()));
-        buffer.clear();
+    while let Some(line) = input.next_line() {
+        numbers.push(SnailfishNumber::parse(line.text(), &line)?);
     }
 
-    if numbers.len() == 0 { Err(()) }
+    if numbers.len() == 0 { Err(input.end("a snailfish number")) }
     else { Ok(numbers) }
 }
 
-fn part1() {
-    if let Ok(mut numbers) = read_numbers() {
+pub struct Snailfish;
+impl Solution for Snailfish {
+    type Input = Vec<SnailfishNumber>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_numbers(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(numbers: &Self::Input) -> Answer {
+        let mut numbers = numbers.clone();
         numbers.reverse();
         
         let mut result = numbers.pop().unwrap();
@@ -280,13 +295,9 @@ fn part1() {
             result = result.add(&next);
         }
 
-        println!("{}", result.magnitude());
+        result.magnitude().into()
     }
-    else { panic!("couldn't read numbers!"); }
-}
-
-fn part2() {
-    if let Ok(numbers) = read_numbers() {
+    fn part2(numbers: &Self::Input) -> Answer {
         let mut visited = HashSet::<(usize, usize)>::new();
         let mut max_magnitude = 0usize;
         
@@ -318,12 +329,55 @@ fn part2() {
             }
         }
 
-        println!("{}", max_magnitude);
+        max_magnitude.into()
     }
-    else { panic!("couldn't read numbers!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+";
+
+    fn read(s: &str) -> Result<SnailfishNumber, ParseError> {
+        SnailfishNumber::parse(s, &Line::new(1, s))
+    }
+
+    #[test]
+    fn addition() {
+        let left = read("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap();
+        let right = read("[1,1]").unwrap();
+
+        assert_eq!(left.add(&right).to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+    }
+    #[test]
+    fn magnitudes() {
+        assert_eq!(read("[[1,2],[[3,4],5]]").unwrap().magnitude(), 143);
+        assert_eq!(read("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap().magnitude(), 1384);
+        assert_eq!(read("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").unwrap().magnitude(), 3488);
+    }
+    #[test]
+    fn malformed_numbers() {
+        assert_eq!(read("[[1,2],3").unwrap_err().to_string(), "line 1, column 9: expected a ']' for each '[', found \"\"");
+        assert_eq!(read("[1,2,3]").unwrap_err().to_string(), "line 1, column 7: expected a pair of exactly two elements, found \"]\"");
+        assert_eq!(read("[1,x]").unwrap_err().to_string(), "line 1, column 4: expected a digit inside a pair, '[', ']' or ',', found \"x\"");
+    }
+    #[test]
+    fn part1_example() {
+        assert_eq!(Snailfish::solve(EXAMPLE, 1), Ok(Answer::from(4140)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(Snailfish::solve(EXAMPLE, 2), Ok(Answer::from(3993)));
+    }
 }
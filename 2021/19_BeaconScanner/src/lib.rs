@@ -1,5 +1,5 @@
-use std::io;
 use std::collections::{HashSet};
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 struct Coordinate(isize,isize,isize);
@@ -53,7 +53,7 @@ impl Coordinate {
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Scanner {
+pub struct Scanner {
     beacons: Vec<Coordinate>,
     location: Option<Coordinate>
 }
@@ -87,9 +87,7 @@ impl Scanner {
                     let overlaps: HashSet<Coordinate> = beacon_deltas.intersection(&known).copied().collect();
 
                     if overlaps.len() >= 12 {
-                        println!("overlaps: {:?}", overlaps);
                         self.location = Some(known_delta.invert());
-                        println!("location: {:?}", self.location);
                         return Some(beacon_deltas.difference(&known).copied().collect());
                     }
                 }
@@ -118,50 +116,53 @@ fn process_beacons(scanners: &mut Vec<Scanner>) -> HashSet<Coordinate> {
             new_beacons = new_beacons.union(&result.unwrap()).copied().collect();
             located.insert(i);
         }
-
-        println!("found {} new beacons", new_beacons.len());
-        println!("located: {} / {:?}", located.len(), located);
         known_beacons = known_beacons.union(&new_beacons).copied().collect();
     }
 
     known_beacons
 }
 
-fn read_beacons() -> Result<Vec<Scanner>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_beacons(input: &mut Reader) -> Result<Vec<Scanner>, ParseError> {
     let mut scanners = Vec::<Scanner>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
+    while let Some(line) = input.next_line() {
 
-        if buffer.contains("scanner") {
+        if line.text().contains("scanner") {
             scanners.push(Scanner::new());
-            buffer.clear();
             continue;
         }
 
-        let chunks: Vec<isize> = buffer.trim().split(",").map(|x| x.parse::<isize>().unwrap()).collect();
+        if scanners.len() == 0 { return Err(line.error(line.text(), "a header like \"--- scanner 0 ---\"")); }
+
+        let chunks = line.text()
+            .split(",")
+            .map(|x| line.parse::<isize>(x, "a number"))
+            .collect::<Result<Vec<isize>, ParseError>>()?;
+
+        if chunks.len() != 3 { return Err(line.error(line.text(), "a beacon like 404,-588,-901")); }
         
         let index = scanners.len()-1;
         scanners[index].push(Coordinate(chunks[0],chunks[1],chunks[2]));
-        buffer.clear();
     }
 
-    if scanners.len() == 0 { Err(()) }
+    if scanners.len() == 0 { Err(input.end("a scanner")) }
     else { Ok(scanners) }
 }
 
-fn part1() {
-    if let Ok(mut beacons) = read_beacons() {
-        println!("{}", process_beacons(&mut beacons).len());
-    }
-    else { panic!("couldn't read beacons!"); }
-}
+pub struct BeaconScanner;
+impl Solution for BeaconScanner {
+    type Input = Vec<Scanner>;
 
-fn part2() {
-    if let Ok(mut beacons) = read_beacons() {
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_beacons(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(beacons: &Self::Input) -> Answer {
+        let mut beacons = beacons.clone();
+        
+        process_beacons(&mut beacons).len().into()
+    }
+    fn part2(beacons: &Self::Input) -> Answer {
+        let mut beacons = beacons.clone();
         process_beacons(&mut beacons);
 
         let mut max_distance = 0isize;
@@ -187,11 +188,158 @@ fn part2() {
             }
         }
 
-        println!("{}", max_distance);
+        max_distance.into()
     }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(BeaconScanner::solve(EXAMPLE, 1), Ok(Answer::from(79)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(BeaconScanner::solve(EXAMPLE, 2), Ok(Answer::from(3621)));
+    }
 }
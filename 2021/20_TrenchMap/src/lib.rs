@@ -0,0 +1,149 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::Grid;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Pixel {
+    Light,
+    Dark,
+}
+impl Pixel {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Self::Light),
+            '.' => Some(Self::Dark),
+            _ => None,
+        }
+    }
+    fn to_int(&self) -> usize {
+        match self {
+            Self::Light => 1,
+            Self::Dark => 0,
+        }
+    }
+    #[allow(dead_code)]
+    fn to_char(&self) -> char {
+        match self {
+            Self::Light => '#',
+            Self::Dark => '.',
+        }
+    }
+    fn enhance(&self) -> usize {
+        match self {
+            Self::Light => 511,
+            Self::Dark => 0,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Image {
+    pixels: Grid<Pixel>,
+    /// The color of every pixel beyond the edges of `pixels`.
+    fill: Pixel,
+}
+impl Image {
+    fn get(&self, x: isize, y: isize) -> Pixel {
+        self.pixels.resolve(x,y).map_or(self.fill, |c| self.pixels[c])
+    }
+    fn enhance(&self, x: isize, y: isize, algorithm: &Vec<Pixel>) -> Pixel {
+        let mut bits = Vec::<usize>::new();
+
+        for ny in (y-1)..=(y+1) {
+            for nx in (x-1)..=(x+1) {
+                bits.push(self.get(nx,ny).to_int());
+            }
+        }
+
+        let mut index = 0usize;
+
+        for bit in &bits {
+            index <<= 1;
+            index |= bit;
+        }
+
+        algorithm[index]
+    }
+    fn light_pixels(&self) -> usize {
+        self.pixels.iter().filter(|(_, &x)| x == Pixel::Light).count()
+    }
+    #[allow(dead_code)]
+    fn print(&self) {
+        print!("{}", self.pixels.render(Pixel::to_char));
+    }
+}
+
+fn enhance_image(image: &Image, algorithm: &Vec<Pixel>, steps: usize) -> Image {
+    let mut result = image.clone();
+    
+    for _ in 0..steps {
+        // the image grows by a pixel on every side each step, everything further out is fill
+        let pixels = Grid::from_fn(result.pixels.width()+2, result.pixels.height()+2, |c| {
+            result.enhance(c.0 as isize - 1, c.1 as isize - 1, algorithm)
+        });
+        let fill = algorithm[result.fill.enhance()];
+
+        result = Image { pixels, fill };
+    }
+
+    result
+}
+
+fn read_image(input: &mut Reader) -> Result<(Vec<Pixel>, Image), ParseError> {
+    let line = input.next_raw_line().filter(|x| !x.text().is_empty()).ok_or(ParseError::unexpected_end(1, "an enhancement algorithm"))?;
+    let algorithm = line.map_chars("'#' or '.'", Pixel::from_char)?;
+
+    if algorithm.len() != 512 { return Err(line.error(line.text(), "an algorithm 512 pixels long")); }
+
+    if let Some(line) = input.next_raw_line() {
+        if !line.text().is_empty() { return Err(line.error(line.text(), "a blank line after the algorithm")); }
+    }
+    
+    let pixels = Grid::read(input, "'#' or '.'", Pixel::from_char)?;
+
+    Ok((algorithm, Image { pixels, fill: Pixel::Dark }))
+}
+
+pub struct TrenchMap;
+impl Solution for TrenchMap {
+    type Input = (Vec<Pixel>, Image);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_image(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1((algorithm, image): &Self::Input) -> Answer {
+        enhance_image(image, algorithm, 2).light_pixels().into()
+    }
+    fn part2((algorithm, image): &Self::Input) -> Answer {
+        enhance_image(image, algorithm, 50).light_pixels().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..##",
+        "#..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###",
+        ".######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#.",
+        ".#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#.....",
+        ".#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#..",
+        "...####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.....",
+        "..##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#\n",
+        "\n",
+        "#..#.\n",
+        "#....\n",
+        "##..#\n",
+        "..#..\n",
+        "..###\n",
+    );
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(TrenchMap::solve(EXAMPLE, 1), Ok(Answer::from(35)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(TrenchMap::solve(EXAMPLE, 2), Ok(Answer::from(3351)));
+    }
+}
@@ -1,5 +1,5 @@
-use std::io;
 use std::collections::VecDeque;
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 struct DeterministicDice {
@@ -138,49 +138,58 @@ fn play_dirac(p1: usize, p2: usize) -> usize {
     else { p2_wins }
 }
 
-fn read_positions() -> Result<(usize, usize), ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
-    let mut p1_position = 0usize;
-    let mut p2_position = 0usize;
+fn read_position(input: &mut Reader, player: usize) -> Result<usize, ParseError> {
+    let expected = format!("Player {} starting position: <1 to 10>", player);
+    let line = input.expect_line(&expected)?;
+    let position = line.text()
+        .strip_prefix(&format!("Player {} starting position: ", player))
+        .ok_or(line.error(line.text(), &expected))?;
 
-    if let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { return Err(()); }
-        p1_position = buffer.trim().replace("Player 1 starting position: ", "").parse().unwrap();
-        buffer.clear();
-    }
-
-    if p1_position <= 0usize || p1_position > 10 { return Err(()); }
-
-    if let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { return Err(()); }
-        p2_position = buffer.trim().replace("Player 2 starting position: ", "").parse().unwrap();
-        buffer.clear();
+    match line.parse::<usize>(position, "a position from 1 to 10")? {
+        value if value >= 1 && value <= 10 => Ok(value),
+        _ => Err(line.error(position, "a position from 1 to 10")),
     }
+}
 
-    if p2_position <= 0usize || p2_position > 10 { return Err(()); }
+fn read_positions(input: &mut Reader) -> Result<(usize, usize), ParseError> {
+    let p1_position = read_position(input, 1)?;
+    let p2_position = read_position(input, 2)?;
 
     Ok((p1_position, p2_position))
 }
 
-fn part1() {
-    if let Ok((p1,p2)) = read_positions() {
+pub struct DiracDice;
+impl Solution for DiracDice {
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_positions(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(&(p1, p2): &Self::Input) -> Answer {
         let mut dice = DeterministicDice::new();
         let score = play_deterministic(p1,p2,&mut dice);
         
-        println!("{}", score*dice.value);
+        (score*dice.value).into()
     }
-    else { panic!("couldn't read positions!"); }
-}
-
-fn part2() {
-    if let Ok((p1,p2)) = read_positions() {
-        println!("{}", play_dirac(p1,p2));
+    fn part2(&(p1, p2): &Self::Input) -> Answer {
+        play_dirac(p1,p2).into()
     }
-    else { panic!("couldn't read positions!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Player 1 starting position: 4
+Player 2 starting position: 8
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(DiracDice::solve(EXAMPLE, 1), Ok(Answer::from(739785)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(DiracDice::solve(EXAMPLE, 2), Ok(Answer::from(444356092776315u64)));
+    }
 }
@@ -1,5 +1,4 @@
-use std::io;
-use std::collections::BTreeSet;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 enum State {
@@ -7,11 +6,11 @@ enum State {
     On,
 }
 impl State {
-    fn from_str(s: &str) -> Self {
+    fn from_str(s: &str) -> Option<Self> {
         match s {
-            "off" => Self::Off,
-            "on" => Self::On,
-            _ => panic!("invalid switch value"),
+            "off" => Some(Self::Off),
+            "on" => Some(Self::On),
+            _ => None,
         }
     }
     fn flip(&self) -> Self {
@@ -25,9 +24,9 @@ impl State {
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 struct Range(isize,isize);
 impl Range {
-    fn from_str(s: &str) -> Self {
-        let chunks = s.split("..").collect::<Vec<&str>>();
-        Self(chunks[0].parse::<isize>().unwrap(),chunks[1].parse::<isize>().unwrap())
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let (start, end) = line.split_once(s, "..", "a range like -20..26")?;
+        Ok(Self(line.parse::<isize>(start, "a number")?,line.parse::<isize>(end, "a number")?))
     }
     fn contains(&self, r: Self) -> bool {
         r.0 >= self.0 && r.1 <= self.1
@@ -50,16 +49,18 @@ impl Range {
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 struct Cuboid(Range,Range,Range);
 impl Cuboid {
-    fn from_str(s: &str) -> Self {
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
         let chunks = s.split(",").collect::<Vec<&str>>();
         let mut ranges = Vec::<Range>::new();
 
-        for chunk in chunks {
-            let range_data = chunk.split("=").collect::<Vec<&str>>();
-            ranges.push(Range::from_str(range_data[1]));
+        if chunks.len() != 3 { return Err(line.error(s, "a cuboid like x=-20..26,y=-36..17,z=-47..7")); }
+
+        for (chunk, axis) in chunks.iter().zip(["x=", "y=", "z="].iter()) {
+            let range_data = chunk.strip_prefix(axis).ok_or(line.error(chunk, &format!("a range starting with {}", axis)))?;
+            ranges.push(Range::from_str(range_data, line)?);
         }
 
-        Self(ranges[0],ranges[1],ranges[2])
+        Ok(Self(ranges[0],ranges[1],ranges[2]))
     }
     fn contains(&self, c: Self) -> bool {
         self.0.contains(c.0) && self.1.contains(c.1) && self.2.contains(c.2)
@@ -82,32 +83,27 @@ impl Cuboid {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-struct Reboot {
+pub struct Reboot {
     state: State,
     cuboid: Cuboid,
 }
 impl Reboot {
-    fn from_str(s: &str) -> Self {
-        let chunks = s.split(" ").collect::<Vec<&str>>();
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let (state, cuboid) = line.split_once(s, " ", "a step like on x=10..12,y=10..12,z=10..12")?;
+        let state = State::from_str(state).ok_or(line.error(state, "on or off"))?;
 
-        Self { state: State::from_str(chunks[0]), cuboid: Cuboid::from_str(chunks[1]) }
+        Ok(Self { state, cuboid: Cuboid::from_str(cuboid, line)? })
     }
 }
 
-fn read_cuboids() -> Result<Vec<Reboot>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_cuboids(input: &mut Reader) -> Result<Vec<Reboot>, ParseError> {
     let mut result = Vec::<Reboot>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-
-        result.push(Reboot::from_str(buffer.trim()));
-        buffer.clear();
+    while let Some(line) = input.next_line() {
+        result.push(Reboot::from_str(line.text(), &line)?);
     }
 
-    if result.len() == 0 { Err(()) }
+    if result.len() == 0 { Err(input.end("a reboot step")) }
     else { Ok(result) }
 }
 
@@ -144,23 +140,40 @@ fn reboot(sequence: &Vec<Reboot>) -> isize {
     on
 }
 
-fn part1() {
-    if let Ok(sequence) = read_cuboids() {
+pub struct ReactorReboot;
+impl Solution for ReactorReboot {
+    type Input = Vec<Reboot>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_cuboids(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(sequence: &Self::Input) -> Answer {
         let range = Cuboid(Range(-50,50),Range(-50,50),Range(-50,50));
         let filtered = sequence.iter().filter(|x| range.contains(x.cuboid)).copied().collect::<Vec<Reboot>>();
-        println!("{}", reboot(&filtered));
-    }
-    else { panic!("couldn't read cuboids!"); }
-}
 
-fn part2() {
-    if let Ok(sequence) = read_cuboids() {
-        println!("{}", reboot(&sequence));
+        reboot(&filtered).into()
+    }
+    fn part2(sequence: &Self::Input) -> Answer {
+        reboot(sequence).into()
     }
-    else { panic!("couldn't read cuboids!"); }
 }
 
-fn main() {
-    // part1();
-    part2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(ReactorReboot::solve(EXAMPLE, 1), Ok(Answer::from(39)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(ReactorReboot::solve(EXAMPLE, 2), Ok(Answer::from(39)));
+    }
 }
@@ -1,6 +1,6 @@
-use std::io;
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet, BinaryHeap};
+use std::collections::{HashSet, BinaryHeap};
+use common::{Answer, Error, ParseError, Reader, Solution};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 enum Amphipod {
@@ -10,15 +10,16 @@ enum Amphipod {
     Desert,
 }
 impl Amphipod {
-    fn from_str(s: &str) -> Self {
+    fn from_str(s: &str) -> Option<Self> {
         match s {
-            "A" => Self::Amber,
-            "B" => Self::Bronze,
-            "C" => Self::Copper,
-            "D" => Self::Desert,
-            _ => panic!("bad amphipod string"),
+            "A" => Some(Self::Amber),
+            "B" => Some(Self::Bronze),
+            "C" => Some(Self::Copper),
+            "D" => Some(Self::Desert),
+            _ => None,
         }
     }
+    #[allow(dead_code)]
     fn to_string(&self) -> String {
         match self {
             Self::Amber => "A".to_string(),
@@ -54,7 +55,9 @@ impl Room {
     fn has_room(&self, room: RoomId) -> bool { self.rooms.contains(&room) }
     fn join(&mut self, room: RoomId) { if !self.has_room(room) { self.rooms.push(room); } }
     fn is_occupied(&self) -> bool { self.occupant.is_some() }
+    #[allow(dead_code)]
     fn set_occupant(&mut self, o: Option<Amphipod>) { self.occupant = o; }
+    #[allow(dead_code)]
     fn to_string(&self) -> String {
         if self.occupant.is_none() { ".".to_string() }
         else { self.occupant.clone().unwrap().to_string() }
@@ -240,6 +243,7 @@ impl Burrow {
         moves
     }
             
+    #[allow(dead_code)]
     fn print(&self) {
         let hallway_len = self.hallway.len();
 
@@ -334,19 +338,14 @@ fn solve(burrow: &Burrow) -> BurrowState {
     panic!("couldn't solve burrow!");
 }
 
-fn read_burrow() -> Result<Burrow, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_burrow(input: &mut Reader) -> Result<Burrow, ParseError> {
     let mut burrow = Burrow::new();
     
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-
-        if buffer.trim() == std::iter::repeat("#").take(buffer.len()).collect::<String>() { continue; }
+    while let Some(line) = input.next_line() {
+        if line.text().trim() == "#".repeat(line.text().len()) { continue; }
         
         if burrow.hallway.len() == 0 {
-            let length = buffer.trim().replace("#","").chars().count();
+            let length = line.text().trim().replace("#","").chars().count();
 
             for _ in 0..length {
                 let room_id = burrow.add_room(None);
@@ -354,36 +353,72 @@ fn read_burrow() -> Result<Burrow, ()> {
             }
         }
         else {
-            for substr in buffer.trim().replace(" ","").split("#") {
+            for substr in line.text().split(|x| x == '#' || x == ' ') {
                 if substr.len() == 0 { continue; }
                     
-                let room_id = burrow.add_room(Some(Amphipod::from_str(substr)));
+                let amphipod = Amphipod::from_str(substr).ok_or(line.error(substr, "A, B, C or D"))?;
+                let room_id = burrow.add_room(Some(amphipod));
                 burrow.add_sideroom(room_id);
             }
         }
-        
-        buffer.clear();
     }
 
-    if burrow.rooms.len() == 0 || burrow.siderooms.len() == 0 { Err(()) }
+    if burrow.rooms.len() == 0 || burrow.siderooms.len() == 0 { Err(input.end("a row of side rooms")) }
     else { Ok(burrow) }
 }
 
-fn main() {
-    if let Ok(burrow) = read_burrow() {
-        let solved = solve(&burrow);
-        
-        for i in 0..solved.history.len() {
-            println!("state {}:", i);
-            solved.history[i].print();
-            println!("");
-        }
+fn unfold_burrow(diagram: &str) -> String {
+    let mut lines: Vec<&str> = diagram.lines().collect();
 
-        println!("solution:");
-        solved.burrow.print();
-        println!("");
-            
-        println!("cost: {}", solved.cost);
+    if lines.len() >= 3 {
+        lines.insert(3, "  #D#C#B#A#");
+        lines.insert(4, "  #D#B#A#C#");
+    }
+
+    lines.join("\n")
+}
+
+pub struct AmphipodBurrow;
+impl Solution for AmphipodBurrow {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_burrow(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)?;
+
+        // part 2 unfolds the diagram before reading it, so keep the text around
+        Ok(input.to_string())
+    }
+    fn part1(diagram: &Self::Input) -> Answer {
+        let burrow = read_burrow(&mut Reader::new(diagram.as_bytes())).unwrap();
+
+        solve(&burrow).cost.into()
+    }
+    fn part2(diagram: &Self::Input) -> Answer {
+        let unfolded = unfold_burrow(diagram);
+        let burrow = read_burrow(&mut Reader::new(unfolded.as_bytes())).unwrap();
+
+        solve(&burrow).cost.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(AmphipodBurrow::solve(EXAMPLE, 1), Ok(Answer::from(12521)));
+    }
+    #[test]
+    #[ignore = "takes minutes, run with --ignored"]
+    fn part2_example() {
+        assert_eq!(AmphipodBurrow::solve(EXAMPLE, 2), Ok(Answer::from(44169)));
     }
-    else { panic!("couldn't read burrow!"); }
 }
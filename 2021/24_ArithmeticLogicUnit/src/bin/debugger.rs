@@ -0,0 +1,42 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+
+use arithmeticlogicunit::{parse_tape, ArithmeticLogicUnit, Debugger};
+use common::Solution;
+
+const USAGE: &str = "usage: debugger <tape> [<program>]
+    <tape> is a model number like 13579246899999, or comma-separated inputs like 5,-3
+    <program> defaults to this day's input.txt";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.len() == 0 || args.len() > 2 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let tape = parse_tape(&args[0]).unwrap_or_else(|| {
+        eprintln!("bad tape: {}", args[0]);
+        process::exit(2);
+    });
+
+    let path = args.get(1).map_or(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input.txt"), PathBuf::from);
+    let program = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("couldn't open {}: {}", path.display(), e);
+        process::exit(1);
+    });
+
+    let blocks = ArithmeticLogicUnit::parse(&program).unwrap_or_else(|e| {
+        eprintln!("{}: {}", path.display(), e);
+        process::exit(1);
+    });
+
+    if let Err(e) = Debugger::new(&blocks, &tape).run(io::stdin().lock(), &mut io::stdout()) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
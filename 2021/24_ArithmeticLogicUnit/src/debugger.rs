@@ -0,0 +1,310 @@
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+
+use super::{Block, Instruction, Opcode, Operand, Register, State};
+
+const HELP: &str = "commands:
+    step [<n>]             run the next n instructions, 1 by default
+    continue               run until a breakpoint, a fault or the end of the program
+    break <index>|inp      stop before the instruction at index, or before every inp
+    delete <index>|inp     remove a breakpoint
+    watch <expression>     show an expression like z, z % 26 or x == w at every stop
+    unwatch <n>            remove the nth watch expression
+    trace on|off           print every instruction as it runs, on by default
+    list                   show the instructions around the next one
+    state                  show the registers and the remaining tape
+    tape                   show the remaining tape
+    help                   show this
+    quit
+an empty line repeats the last command";
+
+/// An expression watched at every stop: a register or number, optionally combined with another
+/// using one of `+ * / % ==`, which work like the ALU instructions they stand for.
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct Watch {
+    text: String,
+    left: Operand,
+    operation: Option<(Opcode, Operand)>,
+}
+impl Watch {
+    fn from_str(s: &str) -> Option<Self> {
+        let terms: Vec<&str> = s.split_whitespace().collect();
+        let left = Operand::from_str(terms.first()?)?;
+
+        let operation = match terms.len() {
+            1 => None,
+            3 => {
+                let opcode = match terms[1] {
+                    "+" => Opcode::Add,
+                    "*" => Opcode::Mul,
+                    "/" => Opcode::Div,
+                    "%" => Opcode::Mod,
+                    "==" => Opcode::Eql,
+                    _ => return None,
+                };
+
+                Some((opcode, Operand::from_str(terms[2])?))
+            },
+            _ => return None,
+        };
+
+        Some(Self { text: terms.join(" "), left, operation })
+    }
+    fn evaluate(&self, state: &State) -> Option<isize> {
+        let left = self.left.get_value(state);
+
+        match self.operation {
+            Some((opcode, right)) => opcode.apply(left, right.get_value(state)),
+            None => Some(left),
+        }
+    }
+}
+
+/// Steps through an ALU program an instruction at a time, driven by commands read a line at a
+/// time, so a program's registers can be watched as it consumes its tape.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Debugger {
+    instructions: Vec<Instruction>,
+    state: State,
+    /// The index of the next instruction to run.
+    next: usize,
+    breakpoints: BTreeSet<usize>,
+    break_on_input: bool,
+    watches: Vec<Watch>,
+    trace: bool,
+}
+impl Debugger {
+    pub fn new(blocks: &Vec<Block>, tape: &Vec<isize>) -> Self {
+        Self {
+            instructions: blocks.iter().flat_map(|x| x.instructions.iter().cloned()).collect(),
+            state: State::new(tape),
+            next: 0,
+            breakpoints: BTreeSet::<usize>::new(),
+            break_on_input: false,
+            watches: Vec::<Watch>::new(),
+            trace: true,
+        }
+    }
+    /// Reads commands from `input` until it ends or a `quit`, writing a prompt before each.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, output: &mut W) -> io::Result<()> {
+        let mut last = String::new();
+        let mut lines = input.lines();
+
+        loop {
+            write!(output, "(alu {}) ", self.next)?;
+            output.flush()?;
+
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => { writeln!(output)?; return Ok(()); },
+            };
+
+            let command = if line.trim().len() == 0 { last.clone() } else { line.trim().to_string() };
+
+            if !self.command(&command, output)? { return Ok(()); }
+
+            last = command;
+        }
+    }
+    /// Runs one command, returning false once the debugger should stop.
+    fn command<W: Write>(&mut self, command: &str, output: &mut W) -> io::Result<bool> {
+        let (name, argument) = command.split_once(' ').map_or((command, ""), |(x, y)| (x, y.trim()));
+
+        match (name, argument) {
+            ("", _) => (),
+            ("step" | "s", "") => self.step(1, output)?,
+            ("step" | "s", count) => match count.parse::<usize>() {
+                Ok(count) => self.step(count, output)?,
+                Err(_) => writeln!(output, "bad step count: {}", count)?,
+            },
+            ("continue" | "c", "") => self.step(usize::MAX, output)?,
+            ("break" | "b", "inp") => self.break_on_input = true,
+            ("delete" | "d", "inp") => self.break_on_input = false,
+            ("break" | "b", index) | ("delete" | "d", index) => match index.parse::<usize>() {
+                Ok(index) if index < self.instructions.len() => {
+                    if name.starts_with('b') { self.breakpoints.insert(index); }
+                    else { self.breakpoints.remove(&index); }
+                },
+                _ => writeln!(output, "bad instruction index: {}", index)?,
+            },
+            ("watch" | "w", expression) => match Watch::from_str(expression) {
+                Some(watch) => {
+                    self.watches.push(watch);
+                    self.show_watches(output)?;
+                },
+                None => writeln!(output, "bad watch expression: {}", expression)?,
+            },
+            ("unwatch", index) => match index.parse::<usize>() {
+                Ok(index) if index < self.watches.len() => { self.watches.remove(index); },
+                _ => writeln!(output, "bad watch number: {}", index)?,
+            },
+            ("trace", "on") => self.trace = true,
+            ("trace", "off") => self.trace = false,
+            ("list" | "l", "") => self.list(output)?,
+            ("state", "") => writeln!(output, "{}", self.state.to_string())?,
+            ("tape", "") => writeln!(output, "{:?}", self.state.tape)?,
+            ("help" | "h", "") => writeln!(output, "{}", HELP)?,
+            ("quit" | "q", "") => return Ok(false),
+            _ => writeln!(output, "unknown command: {} (try help)", command)?,
+        }
+
+        Ok(true)
+    }
+    /// Runs up to `count` instructions, stopping early at a breakpoint, a fault or the end.
+    fn step<W: Write>(&mut self, count: usize, output: &mut W) -> io::Result<()> {
+        let mut executed = 0usize;
+
+        while executed < count {
+            if self.next == self.instructions.len() {
+                writeln!(output, "the program has ended")?;
+                break;
+            }
+
+            let instruction = &self.instructions[self.next];
+
+            if executed > 0 {
+                if self.breakpoints.contains(&self.next) {
+                    writeln!(output, "breakpoint at {}", self.next)?;
+                    break;
+                }
+
+                if self.break_on_input && instruction.opcode == Opcode::Inp {
+                    writeln!(output, "input at {}", self.next)?;
+                    break;
+                }
+            }
+
+            if let Some(fault) = self.fault(instruction) {
+                writeln!(output, "can't run {} at {}: {}", instruction.to_string(), self.next, fault)?;
+                break;
+            }
+
+            instruction.execute(&mut self.state);
+
+            if self.trace { writeln!(output, "{}", trace(instruction, &self.state))?; }
+
+            self.next += 1;
+            executed += 1;
+        }
+
+        if let Some(instruction) = self.instructions.get(self.next) {
+            writeln!(output, "next {}: {}", self.next, instruction.to_string())?;
+        }
+
+        self.show_watches(output)
+    }
+    /// Why `instruction` can't run in the current state, rather than letting it panic.
+    fn fault(&self, instruction: &Instruction) -> Option<&'static str> {
+        if instruction.opcode == Opcode::Inp {
+            return if self.state.tape.len() == 0 { Some("the tape is empty") } else { None };
+        }
+
+        let left = instruction.operands[0].get_value(&self.state);
+        let right = instruction.operands[1].get_value(&self.state);
+
+        match (instruction.opcode.apply(left, right), instruction.opcode) {
+            (Some(_), _) => None,
+            (None, Opcode::Div) => Some("division by zero"),
+            (None, Opcode::Mod) => Some("modulo of a negative number or by one below 1"),
+            (None, _) => Some("the result overflowed"),
+        }
+    }
+    fn list<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        let start = self.next.saturating_sub(3);
+        let end = (self.next+4).min(self.instructions.len());
+
+        for index in start..end {
+            let marker = if index == self.next { "=>" } else if self.breakpoints.contains(&index) { " *" } else { "  " };
+
+            writeln!(output, "{} {:4} {}", marker, index, self.instructions[index].to_string())?;
+        }
+
+        Ok(())
+    }
+    fn show_watches<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        for (index, watch) in self.watches.iter().enumerate() {
+            match watch.evaluate(&self.state) {
+                Some(value) => writeln!(output, "{}: {} = {}", index, watch.text, value)?,
+                None => writeln!(output, "{}: {} is undefined", index, watch.text)?,
+            }
+        }
+
+        Ok(())
+    }
+    pub fn state(&self) -> (isize, isize, isize, isize) {
+        (self.state.get_register(Register::W), self.state.get_register(Register::X),
+         self.state.get_register(Register::Y), self.state.get_register(Register::Z))
+    }
+}
+
+/// An instruction padded out to a column, followed by the state it left behind.
+fn trace(instruction: &Instruction, state: &State) -> String {
+    format!("{:10}{}", instruction.to_string(), state.to_string())
+}
+
+/// Reads a tape given on the command line: either comma-separated numbers, or a model number
+/// whose digits are each an input.
+pub fn parse_tape(s: &str) -> Option<Vec<isize>> {
+    if s.contains(',') { s.split(',').map(|x| x.trim().parse::<isize>().ok()).collect() }
+    else { s.chars().map(|x| x.to_digit(10).map(|x| x as isize)).collect() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::read_instructions;
+    use common::Reader;
+
+    fn session(program: &str, tape: &str, commands: &str) -> (Debugger, String) {
+        let blocks = read_instructions(&mut Reader::new(program.as_bytes())).unwrap();
+        let mut debugger = Debugger::new(&blocks, &parse_tape(tape).unwrap());
+        let mut output = Vec::<u8>::new();
+
+        debugger.run(commands.as_bytes(), &mut output).unwrap();
+
+        (debugger, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn stepping_and_tracing() {
+        let (debugger, output) = session("inp z\ninp x\nmul z 3\neql z x\n", "3,9", "step\n\nwatch z * 3\ntape\nstep 5\nquit\n");
+
+        assert_eq!(output, "(alu 0) inp z     [w=0,x=0,y=0,z=3,tape=[9]]
+next 1: inp x
+(alu 1) inp x     [w=0,x=9,y=0,z=3,tape=[]]
+next 2: mul z,3
+(alu 2) 0: z * 3 = 9
+(alu 2) []
+(alu 2) mul z,3   [w=0,x=9,y=0,z=9,tape=[]]
+eql z,x   [w=0,x=9,y=0,z=1,tape=[]]
+the program has ended
+0: z * 3 = 3
+(alu 4) ");
+        assert_eq!(debugger.state(), (0, 9, 0, 1));
+    }
+    #[test]
+    fn breakpoints() {
+        let program = "inp w\nadd x w\ninp w\nadd y w\nmul y 2\ninp w\n";
+        let (debugger, output) = session(program, "123", "trace off\nbreak 4\ncontinue\ncontinue\n");
+
+        assert_eq!(output, "(alu 0) (alu 0) (alu 0) breakpoint at 4\nnext 4: mul y,2\n(alu 4) the program has ended\n(alu 6) \n");
+        assert_eq!(debugger.state(), (3, 1, 4, 0));
+
+        let (debugger, output) = session(program, "123", "trace off\nbreak inp\nc\ndelete inp\nc\nbreak 9\nwatch q\nfly\n");
+
+        assert!(output.starts_with("(alu 0) (alu 0) (alu 0) input at 2\nnext 2: inp w\n(alu 2) (alu 2) the program has ended\n"));
+        assert!(output.ends_with("bad instruction index: 9\n(alu 6) bad watch expression: q\n(alu 6) unknown command: fly (try help)\n(alu 6) \n"));
+        assert_eq!(debugger.state(), (3, 1, 4, 0));
+    }
+    #[test]
+    fn faults() {
+        let (debugger, output) = session("inp x\ndiv x y\n", "", "trace off\nc\n");
+
+        assert_eq!(output, "(alu 0) (alu 0) can't run inp x at 0: the tape is empty\nnext 0: inp x\n(alu 0) \n");
+        assert_eq!(debugger.state(), (0, 0, 0, 0));
+
+        let (_, output) = session("inp x\ndiv x y\n", "7", "trace off\nc\nwatch x % y\n");
+
+        assert_eq!(output, "(alu 0) (alu 0) can't run div x,y at 1: division by zero\nnext 1: div x,y\n(alu 1) 0: x % y is undefined\n(alu 1) \n");
+    }
+}
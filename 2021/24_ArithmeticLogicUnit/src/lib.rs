@@ -1,5 +1,14 @@
-use std::io;
 use std::collections::HashMap;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+mod debugger;
+mod optimizer;
+mod search;
+mod symbolic;
+pub use debugger::{parse_tape, Debugger};
+pub use optimizer::Program;
+pub use search::ModelSearch;
+pub use symbolic::{Constraint, Expression, SymbolicMonad};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 enum Opcode {
@@ -11,15 +20,26 @@ enum Opcode {
     Eql,
 }
 impl Opcode {
-    fn from_str(s: &str) -> Self {
+    fn from_str(s: &str) -> Option<Self> {
         match s {
-            "inp" => Self::Inp,
-            "add" => Self::Add,
-            "mul" => Self::Mul,
-            "div" => Self::Div,
-            "mod" => Self::Mod,
-            "eql" => Self::Eql,
-            _ => panic!("bad opcode"),
+            "inp" => Some(Self::Inp),
+            "add" => Some(Self::Add),
+            "mul" => Some(Self::Mul),
+            "div" => Some(Self::Div),
+            "mod" => Some(Self::Mod),
+            "eql" => Some(Self::Eql),
+            _ => None,
+        }
+    }
+    /// The result of the instruction on two values, or `None` where the ALU would crash.
+    fn apply(&self, left: isize, right: isize) -> Option<isize> {
+        match self {
+            Self::Inp => None,
+            Self::Add => left.checked_add(right),
+            Self::Mul => left.checked_mul(right),
+            Self::Div => left.checked_div(right),
+            Self::Mod => if left < 0 || right <= 0 { None } else { Some(left % right) },
+            Self::Eql => Some((left == right) as isize),
         }
     }
     fn to_string(&self) -> String {
@@ -34,21 +54,22 @@ impl Opcode {
     }
 }
 
+/// One of the four registers, named in `Program::compile` to say which results matter.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-enum Register {
+pub enum Register {
     W,
     X,
     Y,
     Z,
 }
 impl Register {
-    fn from_str(s: &str) -> Self {
+    fn from_str(s: &str) -> Option<Self> {
         match s {
-            "w" => Self::W,
-            "x" => Self::X,
-            "y" => Self::Y,
-            "z" => Self::Z,
-            _ => panic!("bad register"),
+            "w" => Some(Self::W),
+            "x" => Some(Self::X),
+            "y" => Some(Self::Y),
+            "z" => Some(Self::Z),
+            _ => None,
         }
     }
     fn to_string(&self) -> String {
@@ -111,9 +132,9 @@ enum Operand {
     Register(Register),
 }
 impl Operand {
-    fn from_str(s: &str) -> Self {
-        if let Ok(value) = s.parse::<isize>() { Self::Constant(value) }
-        else { Self::Register(Register::from_str(s)) }
+    fn from_str(s: &str) -> Option<Self> {
+        if let Ok(value) = s.parse::<isize>() { Some(Self::Constant(value)) }
+        else { Register::from_str(s).map(Self::Register) }
     }
     fn to_string(&self) -> String {
         match self {
@@ -135,18 +156,29 @@ struct Instruction {
     operands: Vec<Operand>,
 }
 impl Instruction {
-    fn from_str(s: &str) -> Self {
-        let mut chunks: Vec<&str> = s.split(" ").collect();
-        let opcode = Opcode::from_str(chunks[0]);
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let mut chunks = s.split(" ");
+        let opcode_str = chunks.next().unwrap_or(s);
+        let opcode = Opcode::from_str(opcode_str).ok_or(line.error(opcode_str, "inp, add, mul, div, mod or eql"))?;
         let mut operands = Vec::<Operand>::new();
 
-        chunks.remove(0);
+        // the first operand is where the result goes, so it has to be a register
+        if let Some(target) = chunks.next() {
+            let register = Register::from_str(target).ok_or(line.error(target, "a register w, x, y or z"))?;
+            operands.push(Operand::Register(register));
+        }
 
-        for chunk in &chunks {
-            operands.push(Operand::from_str(chunk));
+        for chunk in chunks {
+            operands.push(Operand::from_str(chunk).ok_or(line.error(chunk, "a register or a number"))?);
         }
 
-        Self { opcode, operands }
+        let arity = if opcode == Opcode::Inp { 1 } else { 2 };
+
+        if operands.len() != arity {
+            return Err(line.error(s, &format!("{} with {} operand{}", opcode_str, arity, if arity == 1 { "" } else { "s" })));
+        }
+
+        Ok(Self { opcode, operands })
     }
     fn to_string(&self) -> String {
         if self.operands.len() == 1 {
@@ -207,7 +239,7 @@ impl Instruction {
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Block {
+pub struct Block {
     instructions: Vec<Instruction>,
 }
 impl Block {
@@ -217,23 +249,6 @@ impl Block {
     }
     fn execute(&self, state: &mut State) {
         self.instructions.iter().for_each(|x| x.execute(state));
-        
-        /*
-        for instruction in &self.instructions {
-            let inst = instruction.to_string();
-            
-            instruction.execute(state);
-
-            let st = state.to_string();
-            
-            println!("{}{}{}",
-                     inst,
-                     std::str::from_utf8(&vec![' ' as u8; 10-inst.len()]).unwrap(),
-                     st);
-        }
-
-        println!("");
-         */
     }
     fn is_peek_block(&self) -> bool {
         self.instructions.iter()
@@ -312,13 +327,16 @@ impl Block {
     }
 }
 
+/// Solves MONAD by recognising its blocks: those with `div z 1` push a digit plus an offset
+/// onto `z` as a base 26 stack, and those with `div z 26` pop it and compare it to their own
+/// digit. It's quick, but only works on programs laid out exactly that way.
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Keygen {
+pub struct Keygen {
     serial: HashMap<usize, (isize, isize)>,
     links: HashMap<usize, usize>,
 }
 impl Keygen {
-    fn generate(blocks: &Vec<Block>) -> Self {
+    pub fn generate(blocks: &Vec<Block>) -> Self {
         let mut serial = HashMap::<usize, (isize, isize)>::new();
         let mut links = HashMap::<usize, usize>::new();
         let mut offset_stack = Vec::<(usize, isize)>::new();
@@ -370,7 +388,7 @@ impl Keygen {
 
         Self { serial, links }
     }
-    fn get_lower_serial(&self) -> Vec<isize> {
+    pub fn get_lower_serial(&self) -> Vec<isize> {
         let mut result = vec![0isize; 14];
 
         for (index, range) in &self.serial {
@@ -379,7 +397,7 @@ impl Keygen {
 
         result
     }
-    fn get_upper_serial(&self) -> Vec<isize> {
+    pub fn get_upper_serial(&self) -> Vec<isize> {
         let mut result = vec![0isize; 14];
 
         for (index, range) in &self.serial {
@@ -390,64 +408,110 @@ impl Keygen {
     }
 }
  
-fn read_instructions() -> Result<Vec<Block>, ()> {
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+fn read_instructions(input: &mut Reader) -> Result<Vec<Block>, ParseError> {
     let mut blocks = Vec::<Block>::new();
 
-    while let Ok(size) = stdin.read_line(&mut buffer) {
-        if size == 0 { break; }
-        if size == 1 { continue; }
-
-        let instruction = Instruction::from_str(buffer.trim());
+    while let Some(line) = input.next_line() {
+        let instruction = Instruction::from_str(line.text(), &line)?;
 
         if instruction.opcode == Opcode::Inp {
             blocks.push(Block::new());
         }
+        else if blocks.len() == 0 {
+            return Err(line.error(line.text(), "a program starting with inp"));
+        }
 
         let index = blocks.len()-1;
         blocks[index].push(&instruction);
         
-        buffer.clear();
     }
 
-    if blocks.len() == 0 { Err(()) }
+    if blocks.len() == 0 { Err(input.end("an inp instruction")) }
     else { Ok(blocks) }
 }
 
-fn part1() {
-    if let Ok(blocks) = read_instructions() {
-        let keygen = Keygen::generate(&blocks);
-        let mut state = State::new(&keygen.get_upper_serial());
-        keygen.get_upper_serial().iter().for_each(|x| print!("{}", x));
-        println!("");
+fn check_serial(blocks: &Vec<Block>, serial: &Vec<isize>) -> Answer {
+    let mut state = State::new(serial);
 
-        for block in &blocks {
-            block.execute(&mut state);
-        }
+    for block in blocks {
+        block.execute(&mut state);
+    }
+
+    assert!(state.get_register(Register::Z) == 0);
+
+    serial.iter().fold(0, |acc, x| acc*10 + x).into()
+}
 
-        assert!(state.get_register(Register::Z) == 0);
+pub struct ArithmeticLogicUnit;
+impl Solution for ArithmeticLogicUnit {
+    type Input = Vec<Block>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_instructions(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(blocks: &Self::Input) -> Answer {
+        SymbolicMonad::analyze(blocks).largest().map_or(Answer::None, |x| check_serial(blocks, &x))
+    }
+    fn part2(blocks: &Self::Input) -> Answer {
+        SymbolicMonad::analyze(blocks).smallest().map_or(Answer::None, |x| check_serial(blocks, &x))
     }
-    else { panic!("couldn't read instructions!"); }
 }
 
-fn part2() {
-    if let Ok(blocks) = read_instructions() {
-        let keygen = Keygen::generate(&blocks);
-        let mut state = State::new(&keygen.get_lower_serial());
-        keygen.get_lower_serial().iter().for_each(|x| print!("{}", x));
-        println!("");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        for block in &blocks {
-            block.execute(&mut state);
-        }
+    fn run(program: &str, tape: &Vec<isize>) -> State {
+        let blocks = read_instructions(&mut Reader::new(program.as_bytes())).unwrap();
+        let mut state = State::new(tape);
 
-        assert!(state.get_register(Register::Z) == 0);
+        blocks.iter().for_each(|x| x.execute(&mut state));
+
+        state
     }
-    else { panic!("couldn't read instructions!"); }
-}
 
-fn main() {
-    // part1();
-    part2();
+    #[test]
+    fn negate() {
+        let state = run("inp x\nmul x -1\n", &vec![5]);
+
+        assert_eq!(state.get_register(Register::X), -5);
+    }
+    #[test]
+    fn three_times() {
+        let program = "inp z\ninp x\nmul z 3\neql z x\n";
+
+        assert_eq!(run(program, &vec![3, 9]).get_register(Register::Z), 1);
+        assert_eq!(run(program, &vec![3, 8]).get_register(Register::Z), 0);
+    }
+    #[test]
+    fn binary_digits() {
+        let program = "inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2
+";
+        let state = run(program, &vec![13]);
+
+        assert_eq!(state.get_register(Register::W), 1);
+        assert_eq!(state.get_register(Register::X), 1);
+        assert_eq!(state.get_register(Register::Y), 0);
+        assert_eq!(state.get_register(Register::Z), 1);
+    }
+    #[test]
+    fn malformed_programs() {
+        let error = |program: &str| read_instructions(&mut Reader::new(program.as_bytes())).unwrap_err().to_string();
+
+        assert_eq!(error("inp x\nsub x 1\n"), "line 2, column 1: expected inp, add, mul, div, mod or eql, found \"sub\"");
+        assert_eq!(error("inp x\nadd 2 x\n"), "line 2, column 5: expected a register w, x, y or z, found \"2\"");
+        assert_eq!(error("inp x\nadd x q\n"), "line 2, column 7: expected a register or a number, found \"q\"");
+        assert_eq!(error("inp x\nmul x\n"), "line 2, column 1: expected mul with 2 operands, found \"mul x\"");
+        assert_eq!(error("add x 1\n"), "line 1, column 1: expected a program starting with inp, found \"add x 1\"");
+    }
 }
@@ -0,0 +1,451 @@
+use super::{Block, Opcode, Operand, Register};
+
+/// An instruction over an array of registers, with its second operand already resolved into a
+/// register index (`R`) or a constant (`C`), so running one is a single match.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Op {
+    Inp(usize),
+    Set(usize, isize),
+    Copy(usize, usize),
+    AddC(usize, isize),
+    AddR(usize, usize),
+    MulC(usize, isize),
+    MulR(usize, usize),
+    DivC(usize, isize),
+    DivR(usize, usize),
+    ModC(usize, isize),
+    ModR(usize, usize),
+    EqlC(usize, isize),
+    EqlR(usize, usize),
+    /// `eql a b` followed by `eql a 0`.
+    NeqC(usize, isize),
+    NeqR(usize, usize),
+}
+impl Op {
+    fn lower(opcode: Opcode, target: usize, operand: Option<Operand>) -> Self {
+        match (opcode, operand) {
+            (Opcode::Inp, _) => Self::Inp(target),
+            (Opcode::Add, Some(Operand::Constant(c))) => Self::AddC(target, c),
+            (Opcode::Add, Some(Operand::Register(r))) => Self::AddR(target, index(r)),
+            (Opcode::Mul, Some(Operand::Constant(c))) => Self::MulC(target, c),
+            (Opcode::Mul, Some(Operand::Register(r))) => Self::MulR(target, index(r)),
+            (Opcode::Div, Some(Operand::Constant(c))) => Self::DivC(target, c),
+            (Opcode::Div, Some(Operand::Register(r))) => Self::DivR(target, index(r)),
+            (Opcode::Mod, Some(Operand::Constant(c))) => Self::ModC(target, c),
+            (Opcode::Mod, Some(Operand::Register(r))) => Self::ModR(target, index(r)),
+            (Opcode::Eql, Some(Operand::Constant(c))) => Self::EqlC(target, c),
+            (Opcode::Eql, Some(Operand::Register(r))) => Self::EqlR(target, index(r)),
+            (_, None) => panic!("{} needs two operands", opcode.to_string()),
+        }
+    }
+    /// The register the op writes to.
+    fn target(&self) -> usize {
+        match *self {
+            Self::Inp(a) | Self::Set(a, _) | Self::Copy(a, _) | Self::AddC(a, _) | Self::AddR(a, _) |
+            Self::MulC(a, _) | Self::MulR(a, _) | Self::DivC(a, _) | Self::DivR(a, _) | Self::ModC(a, _) |
+            Self::ModR(a, _) | Self::EqlC(a, _) | Self::EqlR(a, _) | Self::NeqC(a, _) | Self::NeqR(a, _) => a,
+        }
+    }
+    /// The registers the op reads.
+    fn sources(&self) -> Vec<usize> {
+        match *self {
+            Self::Inp(_) | Self::Set(_, _) => vec![],
+            Self::Copy(_, b) => vec![b],
+            Self::AddC(a, _) | Self::MulC(a, _) | Self::DivC(a, _) | Self::ModC(a, _) | Self::EqlC(a, _) | Self::NeqC(a, _) => vec![a],
+            Self::AddR(a, b) | Self::MulR(a, b) | Self::DivR(a, b) | Self::ModR(a, b) | Self::EqlR(a, b) | Self::NeqR(a, b) => vec![a, b],
+        }
+    }
+    /// The register the op reads besides its target, if it reads one.
+    fn operand(&self) -> Option<usize> {
+        match *self {
+            Self::Copy(_, b) | Self::AddR(_, b) | Self::MulR(_, b) | Self::DivR(_, b) |
+            Self::ModR(_, b) | Self::EqlR(_, b) | Self::NeqR(_, b) => Some(b),
+            _ => None,
+        }
+    }
+    /// The same op with its register operand replaced by a constant.
+    fn with_constant(&self, c: isize) -> Self {
+        match *self {
+            Self::Copy(a, _) => Self::Set(a, c),
+            Self::AddR(a, _) => Self::AddC(a, c),
+            Self::MulR(a, _) => Self::MulC(a, c),
+            Self::DivR(a, _) => Self::DivC(a, c),
+            Self::ModR(a, _) => Self::ModC(a, c),
+            Self::EqlR(a, _) => Self::EqlC(a, c),
+            Self::NeqR(a, _) => Self::NeqC(a, c),
+            op => op,
+        }
+    }
+    /// The result of an op with a constant operand on a known value, unless it would crash.
+    fn fold(&self, value: isize) -> Option<isize> {
+        match *self {
+            Self::AddC(_, c) => Opcode::Add.apply(value, c),
+            Self::MulC(_, c) => Opcode::Mul.apply(value, c),
+            Self::DivC(_, c) => Opcode::Div.apply(value, c),
+            Self::ModC(_, c) => Opcode::Mod.apply(value, c),
+            Self::EqlC(_, c) => Opcode::Eql.apply(value, c),
+            Self::NeqC(_, c) => Some((value != c) as isize),
+            _ => None,
+        }
+    }
+    fn to_string(&self) -> String {
+        let name = |r: usize| ["w", "x", "y", "z"][r];
+
+        match *self {
+            Self::Inp(a) => format!("inp {}", name(a)),
+            Self::Set(a, c) => format!("set {},{}", name(a), c),
+            Self::Copy(a, b) => format!("set {},{}", name(a), name(b)),
+            Self::AddC(a, c) => format!("add {},{}", name(a), c),
+            Self::AddR(a, b) => format!("add {},{}", name(a), name(b)),
+            Self::MulC(a, c) => format!("mul {},{}", name(a), c),
+            Self::MulR(a, b) => format!("mul {},{}", name(a), name(b)),
+            Self::DivC(a, c) => format!("div {},{}", name(a), c),
+            Self::DivR(a, b) => format!("div {},{}", name(a), name(b)),
+            Self::ModC(a, c) => format!("mod {},{}", name(a), c),
+            Self::ModR(a, b) => format!("mod {},{}", name(a), name(b)),
+            Self::EqlC(a, c) => format!("eql {},{}", name(a), c),
+            Self::EqlR(a, b) => format!("eql {},{}", name(a), name(b)),
+            Self::NeqC(a, c) => format!("neq {},{}", name(a), c),
+            Self::NeqR(a, b) => format!("neq {},{}", name(a), name(b)),
+        }
+    }
+}
+
+fn index(register: Register) -> usize {
+    match register {
+        Register::W => 0,
+        Register::X => 1,
+        Register::Y => 2,
+        Register::Z => 3,
+    }
+}
+
+/// An ALU program optimised for running many tapes through: constants are folded, `eql`
+/// chains become single comparisons, and stores nothing reads are dropped. Programs are assumed
+/// to be valid, so a division by zero that only fed a dead register won't happen any more.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Program {
+    ops: Vec<Op>,
+}
+impl Program {
+    /// Compiles a program whose only results that matter are the `live` registers; the others
+    /// are left with whatever values are convenient when it finishes.
+    pub fn compile(blocks: &Vec<Block>, live: &[Register]) -> Self {
+        Self::compile_from(blocks, live, [Some(0); 4])
+    }
+    /// Compiles a single block to run from any state, keeping every register live.
+    pub fn compile_block(block: &Block) -> Self {
+        Self::compile_from(std::slice::from_ref(block), &[Register::W, Register::X, Register::Y, Register::Z], [None; 4])
+    }
+    fn compile_from(blocks: &[Block], live: &[Register], start: [Option<isize>; 4]) -> Self {
+        let ops: Vec<Op> = blocks.iter()
+            .flat_map(|x| x.instructions.iter())
+            .map(|x| {
+                let target = match x.operands[0] {
+                    Operand::Register(r) => index(r),
+                    Operand::Constant(_) => panic!("cannot store in constant"),
+                };
+
+                Op::lower(x.opcode, target, x.operands.get(1).copied())
+            })
+            .collect();
+
+        let ops = fold_constants(&ops, start);
+        let ops = fuse_comparisons(&ops);
+        let ops = fold_constants(&ops, start);
+        let ops = remove_dead_stores(&ops, &live.iter().map(|x| index(*x)).collect::<Vec<usize>>());
+
+        Self { ops }
+    }
+    pub fn len(&self) -> usize { self.ops.len() }
+    /// Which registers, in `w, x, y, z` order, the program reads before writing to them.
+    pub fn live_in(&self) -> [bool; 4] {
+        let mut written = [false; 4];
+        let mut live = [false; 4];
+
+        for op in &self.ops {
+            op.sources().iter().filter(|&&x| !written[x]).for_each(|&x| live[x] = true);
+            written[op.target()] = true;
+        }
+
+        live
+    }
+    /// Which registers, in `w, x, y, z` order, the program writes to at all.
+    pub fn written(&self) -> [bool; 4] {
+        let mut written = [false; 4];
+
+        self.ops.iter().for_each(|x| written[x.target()] = true);
+        written
+    }
+    /// Runs the program on a tape, returning the registers in `w, x, y, z` order, or `None` if
+    /// the tape ran out.
+    pub fn run(&self, tape: &[isize]) -> Option<[isize; 4]> {
+        self.run_from([0; 4], tape)
+    }
+    /// Runs the program starting from the given registers rather than zeroes.
+    pub fn run_from(&self, registers: [isize; 4], tape: &[isize]) -> Option<[isize; 4]> {
+        let mut r = registers;
+        let mut input = tape.iter();
+
+        for op in &self.ops {
+            match *op {
+                Op::Inp(a) => r[a] = *input.next()?,
+                Op::Set(a, c) => r[a] = c,
+                Op::Copy(a, b) => r[a] = r[b],
+                Op::AddC(a, c) => r[a] += c,
+                Op::AddR(a, b) => r[a] += r[b],
+                Op::MulC(a, c) => r[a] *= c,
+                Op::MulR(a, b) => r[a] *= r[b],
+                Op::DivC(a, c) => r[a] /= c,
+                Op::DivR(a, b) => r[a] /= r[b],
+                Op::ModC(a, c) => r[a] %= c,
+                Op::ModR(a, b) => r[a] %= r[b],
+                Op::EqlC(a, c) => r[a] = (r[a] == c) as isize,
+                Op::EqlR(a, b) => r[a] = (r[a] == r[b]) as isize,
+                Op::NeqC(a, c) => r[a] = (r[a] != c) as isize,
+                Op::NeqR(a, b) => r[a] = (r[a] != r[b]) as isize,
+            }
+        }
+
+        Some(r)
+    }
+    /// Runs the program on every tape at once, an op at a time across all of them, so what an
+    /// op does is only worked out once per batch rather than once per tape. Tapes too short for
+    /// the program get `None`, and are left out of the batch so that running on past their end
+    /// can't divide by zero.
+    pub fn run_batch(&self, tapes: &[Vec<isize>]) -> Vec<Option<[isize; 4]>> {
+        let inputs = self.ops.iter().filter(|x| matches!(x, Op::Inp(_))).count();
+        let running: Vec<&Vec<isize>> = tapes.iter().filter(|x| x.len() >= inputs).collect();
+        let mut r = [vec![0isize; running.len()], vec![0isize; running.len()], vec![0isize; running.len()], vec![0isize; running.len()]];
+        let mut next = 0usize;
+
+        for op in &self.ops {
+            match *op {
+                Op::Inp(a) => {
+                    r[a].iter_mut().zip(running.iter()).for_each(|(x, tape)| *x = tape[next]);
+                    next += 1;
+                },
+                Op::Set(a, c) => r[a].iter_mut().for_each(|x| *x = c),
+                Op::Copy(a, b) => lanes(&mut r, a, b, |_, y| y),
+                Op::AddC(a, c) => r[a].iter_mut().for_each(|x| *x += c),
+                Op::AddR(a, b) => lanes(&mut r, a, b, |x, y| x + y),
+                Op::MulC(a, c) => r[a].iter_mut().for_each(|x| *x *= c),
+                Op::MulR(a, b) => lanes(&mut r, a, b, |x, y| x * y),
+                Op::DivC(a, c) => r[a].iter_mut().for_each(|x| *x /= c),
+                Op::DivR(a, b) => lanes(&mut r, a, b, |x, y| x / y),
+                Op::ModC(a, c) => r[a].iter_mut().for_each(|x| *x %= c),
+                Op::ModR(a, b) => lanes(&mut r, a, b, |x, y| x % y),
+                Op::EqlC(a, c) => r[a].iter_mut().for_each(|x| *x = (*x == c) as isize),
+                Op::EqlR(a, b) => lanes(&mut r, a, b, |x, y| (x == y) as isize),
+                Op::NeqC(a, c) => r[a].iter_mut().for_each(|x| *x = (*x != c) as isize),
+                Op::NeqR(a, b) => lanes(&mut r, a, b, |x, y| (x != y) as isize),
+            }
+        }
+
+        let mut lane = 0..running.len();
+
+        tapes.iter()
+            .map(|tape| if tape.len() < inputs { None } else { lane.next().map(|i| [r[0][i], r[1][i], r[2][i], r[3][i]]) })
+            .collect()
+    }
+    pub fn to_string(&self) -> String {
+        self.ops.iter().map(|x| x.to_string()).collect::<Vec<String>>().join("\n")
+    }
+}
+
+/// Sets every lane of register `a` to `f` of it and the same lane of register `b`.
+fn lanes<F: Fn(isize, isize) -> isize>(registers: &mut [Vec<isize>; 4], a: usize, b: usize, f: F) {
+    if a == b {
+        registers[a].iter_mut().for_each(|x| *x = f(*x, *x));
+        return;
+    }
+
+    let (target, source) = if a < b {
+        let (low, high) = registers.split_at_mut(b);
+        (&mut low[a], &high[0])
+    }
+    else {
+        let (low, high) = registers.split_at_mut(a);
+        (&mut high[0], &low[b])
+    };
+
+    target.iter_mut().zip(source.iter()).for_each(|(x, y)| *x = f(*x, *y));
+}
+
+/// Tracks which registers hold a known value, starting from `start`, replacing register
+/// operands that are known with constants and ops whose result is known with a `set`.
+fn fold_constants(ops: &[Op], start: [Option<isize>; 4]) -> Vec<Op> {
+    let mut known = start;
+    let mut result = Vec::<Op>::new();
+
+    for op in ops {
+        let mut op = *op;
+
+        if let Some(c) = op.operand().and_then(|b| known[b]) { op = op.with_constant(c); }
+
+        let a = op.target();
+        let before = known[a];
+
+        op = match (op, before) {
+            (Op::AddC(_, 0), _) | (Op::MulC(_, 1), _) | (Op::DivC(_, 1), _) => continue,
+            (Op::MulC(_, 0), _) | (Op::MulR(_, _), Some(0)) => Op::Set(a, 0),
+            (Op::AddR(_, b), Some(0)) => Op::Copy(a, b),
+            (Op::Copy(_, b), _) if b == a => continue,
+            (op, Some(value)) => op.fold(value).map_or(op, |x| Op::Set(a, x)),
+            (op, None) => op,
+        };
+
+        // setting a register to the value it already holds does nothing
+        if let Op::Set(_, c) = op {
+            if before == Some(c) { continue; }
+        }
+
+        known[a] = match op {
+            Op::Set(_, c) => Some(c),
+            _ => None,
+        };
+
+        result.push(op);
+    }
+
+    result
+}
+
+/// Turns `eql a b` followed by `eql a 0` into `neq a b`, which MONAD uses to test its digits.
+fn fuse_comparisons(ops: &[Op]) -> Vec<Op> {
+    let mut result = Vec::<Op>::new();
+
+    for op in ops {
+        let fused = match (result.last(), op) {
+            (Some(&Op::EqlR(a, b)), &Op::EqlC(c, 0)) if a == c => Some(Op::NeqR(a, b)),
+            (Some(&Op::EqlC(a, b)), &Op::EqlC(c, 0)) if a == c => Some(Op::NeqC(a, b)),
+            _ => None,
+        };
+
+        match fused {
+            Some(fused) => *result.last_mut().unwrap() = fused,
+            None => result.push(*op),
+        }
+    }
+
+    result
+}
+
+/// Walks the program backwards dropping every op whose result is overwritten or never read.
+/// Inputs always stay, since dropping one would shift the rest of the tape.
+fn remove_dead_stores(ops: &[Op], live_out: &[usize]) -> Vec<Op> {
+    let mut live = [false; 4];
+    let mut result = Vec::<Op>::new();
+
+    live_out.iter().for_each(|&x| live[x] = true);
+
+    for op in ops.iter().rev() {
+        let a = op.target();
+
+        if !live[a] && !matches!(op, Op::Inp(_)) { continue; }
+
+        if matches!(op, Op::Inp(_) | Op::Set(_, _) | Op::Copy(_, _)) { live[a] = false; }
+
+        op.sources().iter().for_each(|&x| live[x] = true);
+        result.push(*op);
+    }
+
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use super::*;
+    use super::super::{read_instructions, State};
+    use common::Reader;
+
+    fn monad() -> Vec<Block> {
+        read_instructions(&mut Reader::new(include_str!("../input.txt").as_bytes())).unwrap()
+    }
+    /// Model numbers from a small linear congruential generator, so the tests repeat.
+    fn tapes(count: usize) -> Vec<Vec<isize>> {
+        let mut seed = 0x2021_1224u64;
+
+        (0..count).map(|_| (0..14).map(|_| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((seed >> 33) % 9 + 1) as isize
+        }).collect()).collect()
+    }
+    fn interpret(blocks: &Vec<Block>, tape: &Vec<isize>) -> [isize; 4] {
+        let mut state = State::new(tape);
+
+        blocks.iter().for_each(|x| x.execute(&mut state));
+
+        [Register::W, Register::X, Register::Y, Register::Z].map(|x| state.get_register(x))
+    }
+
+    #[test]
+    fn optimized_programs() {
+        let program = "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z 1\nadd x 12\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\n";
+        let blocks = read_instructions(&mut Reader::new(program.as_bytes())).unwrap();
+
+        assert_eq!(Program::compile(&blocks, &[Register::W, Register::X, Register::Y, Register::Z]).to_string(),
+                   "inp w\nset x,12\nneq x,w\nset y,25\nmul y,x\nadd y,1");
+        assert_eq!(Program::compile(&blocks, &[Register::Z]).to_string(), "inp w");
+    }
+    #[test]
+    fn matches_the_interpreter() {
+        let blocks = monad();
+        let all = Program::compile(&blocks, &[Register::W, Register::X, Register::Y, Register::Z]);
+        let z = Program::compile(&blocks, &[Register::Z]);
+
+        assert_eq!((blocks.iter().map(|x| x.instructions.len()).sum::<usize>(), all.len(), z.len()), (252, 181, 181));
+
+        let tapes = tapes(1000);
+        let batch = all.run_batch(&tapes);
+        let z_batch = z.run_batch(&tapes);
+
+        for (i, tape) in tapes.iter().enumerate() {
+            let registers = interpret(&blocks, tape);
+
+            assert_eq!(all.run(tape), Some(registers));
+            assert_eq!(batch[i], Some(registers));
+            assert_eq!(z.run(tape).unwrap()[3], registers[3]);
+            assert_eq!(z_batch[i].unwrap()[3], registers[3]);
+        }
+
+        assert_eq!(z.run(&[1, 2, 3]), None);
+        assert_eq!(z.run_batch(&[vec![1, 2, 3], tapes[0].clone()])[0], None);
+    }
+    #[test]
+    fn short_tapes_in_a_batch() {
+        let program = "inp w\ninp x\ndiv w x\ninp y\nmod w y\n";
+        let blocks = read_instructions(&mut Reader::new(program.as_bytes())).unwrap();
+        let program = Program::compile(&blocks, &[Register::W]);
+
+        assert_eq!(program.run(&[7]), None);
+        assert_eq!(program.run_batch(&[vec![7], vec![9, 2, 3], vec![8, 1], vec![]]), vec![None, Some([1, 2, 3, 0]), None, None]);
+    }
+    #[test]
+    #[ignore = "measures wall-clock time, which depends on the machine and its load"]
+    fn twenty_times_faster() {
+        let blocks = monad();
+        let program = Program::compile(&blocks, &[Register::Z]);
+        let tapes = tapes(2000);
+        let time = |f: &dyn Fn() -> isize| {
+            (0..3).map(|_| {
+                let start = Instant::now();
+
+                std::hint::black_box(f());
+                start.elapsed()
+            }).min().unwrap_or(Duration::ZERO)
+        };
+
+        let interpret_all = || tapes.iter().map(|x| interpret(&blocks, x)[3]).sum();
+        let run_all = || program.run_batch(&tapes).iter().map(|x| x.unwrap()[3]).sum();
+
+        assert_eq!(interpret_all(), run_all());
+
+        let interpreted = time(&interpret_all);
+        let compiled = time(&run_all);
+
+        assert!(interpreted > compiled * 20, "interpreted in {:?}, compiled in {:?}", interpreted, compiled);
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Block, Opcode, Operand, Program, Register};
+use super::symbolic::{combine, register_index, DIGITS};
+
+/// The state a block starts in, with any register that's overwritten before it's read, by this
+/// block or any after it, zeroed out so states that only differ there are searched once.
+type Key = (usize, [isize; 4]);
+
+/// Searches every model number a digit at a time, a block per digit, remembering which states
+/// at the start of each block lead somewhere. For MONAD that's just `z`, but the registers that
+/// matter are worked out from the blocks themselves: those the rest of the program reads before
+/// writing, with `z` read at the end. States are also cut off once
+/// `z` is too big to get back to 0, but only past blocks that can be shown never to shrink `z`
+/// except by dividing it; see `shrink_factor`. Any other program is searched without pruning.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ModelSearch {
+    programs: Vec<Program>,
+    /// Which registers the blocks from each one on read before writing.
+    live_in: Vec<[bool; 4]>,
+    /// How big `z` can be at the start of each block and still get back to 0 by the end, if
+    /// that can be told; see `shrink_factor`.
+    limits: Vec<Option<isize>>,
+}
+impl ModelSearch {
+    pub fn new(blocks: &Vec<Block>) -> Self {
+        let programs: Vec<Program> = blocks.iter().map(Program::compile_block).collect();
+        let mut live_in = vec![[false, false, false, true]; programs.len()+1];
+
+        for i in (0..programs.len()).rev() {
+            let (reads, written) = (programs[i].live_in(), programs[i].written());
+
+            live_in[i] = [0, 1, 2, 3].map(|r| reads[r] || (live_in[i+1][r] && !written[r]));
+        }
+
+        live_in.pop();
+        // what each register can hold at the start of the next block, starting from zero
+        let mut ranges = [(0, 0); 4];
+        let factors: Vec<Option<isize>> = blocks.iter().map(|x| shrink_factor(x, &mut ranges)).collect();
+        let mut limits = vec![Some(1isize); blocks.len()+1];
+
+        for i in (0..blocks.len()).rev() {
+            limits[i] = match (limits[i+1], factors[i]) {
+                (Some(limit), Some(factor)) => limit.checked_mul(factor),
+                _ => None,
+            };
+        }
+
+        limits.pop();
+
+        Self { programs, live_in, limits }
+    }
+    pub fn largest(&self) -> Option<Vec<isize>> {
+        self.find(&[9, 8, 7, 6, 5, 4, 3, 2, 1])
+    }
+    pub fn smallest(&self) -> Option<Vec<isize>> {
+        self.find(&[1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+    /// How many model numbers are accepted.
+    pub fn count(&self) -> u64 {
+        self.count_from(0, [0; 4], &mut HashMap::<Key, u64>::new())
+    }
+    /// The first model number accepted trying each digit in `order`, most significant first.
+    fn find(&self, order: &[isize]) -> Option<Vec<isize>> {
+        let mut digits = Vec::<isize>::new();
+
+        if self.find_from(0, [0; 4], order, &mut digits, &mut HashSet::<Key>::new()) { Some(digits) }
+        else { None }
+    }
+    fn find_from(&self, block: usize, registers: [isize; 4], order: &[isize], digits: &mut Vec<isize>, dead: &mut HashSet<Key>) -> bool {
+        if block == self.programs.len() { return registers[3] == 0; }
+
+        let key = self.key(block, registers);
+
+        if self.hopeless(block, registers) || dead.contains(&key) { return false; }
+
+        for &digit in order {
+            if let Some(next) = self.programs[block].run_from(registers, &[digit]) {
+                digits.push(digit);
+
+                if self.find_from(block+1, next, order, digits, dead) { return true; }
+
+                digits.pop();
+            }
+        }
+
+        dead.insert(key);
+        false
+    }
+    fn count_from(&self, block: usize, registers: [isize; 4], counts: &mut HashMap<Key, u64>) -> u64 {
+        if block == self.programs.len() { return (registers[3] == 0) as u64; }
+        if self.hopeless(block, registers) { return 0; }
+
+        let key = self.key(block, registers);
+
+        if let Some(&count) = counts.get(&key) { return count; }
+
+        let count = (1..=9)
+            .filter_map(|digit| self.programs[block].run_from(registers, &[digit]))
+            .map(|next| self.count_from(block+1, next, counts))
+            .sum();
+
+        counts.insert(key, count);
+        count
+    }
+    fn key(&self, block: usize, registers: [isize; 4]) -> Key {
+        let live = self.live_in[block];
+
+        (block, [0, 1, 2, 3].map(|i| if live[i] { registers[i] } else { 0 }))
+    }
+    fn hopeless(&self, block: usize, registers: [isize; 4]) -> bool {
+        self.limits[block].map_or(false, |limit| registers[3] >= limit)
+    }
+}
+
+/// The most a block can shrink a non-negative `z` by: the product of what it divides `z` by,
+/// given `ranges`, what each register can hold when the block starts, which are updated to
+/// where it ends. Adding to `z` or multiplying it only counts as not shrinking it where the
+/// ranges prove the other operand is at least 0 or 1. A block that changes `z` any other way
+/// gets no factor, which turns off pruning up to and including that block.
+fn shrink_factor(block: &Block, ranges: &mut [(isize, isize); 4]) -> Option<isize> {
+    let mut factor = Some(1isize);
+
+    for instruction in &block.instructions {
+        let target = match instruction.operands[0] {
+            Operand::Register(register) => register_index(register),
+            Operand::Constant(_) => return None,
+        };
+
+        if instruction.opcode == Opcode::Inp {
+            if target == register_index(Register::Z) { factor = None; }
+
+            ranges[target] = DIGITS;
+            continue;
+        }
+
+        let right = match instruction.operands[1] {
+            Operand::Register(register) => ranges[register_index(register)],
+            Operand::Constant(c) => (c, c),
+        };
+
+        if target == register_index(Register::Z) {
+            factor = match instruction.opcode {
+                Opcode::Add if right.0 >= 0 => factor,
+                Opcode::Mul if right.0 >= 1 => factor,
+                Opcode::Div if right.0 > 0 => factor.and_then(|x| x.checked_mul(right.1)),
+                _ => None,
+            };
+        }
+
+        ranges[target] = combine(instruction.opcode, ranges[target], right);
+    }
+
+    factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{read_instructions, Keygen, SymbolicMonad};
+    use common::Reader;
+
+    #[test]
+    fn agrees_with_keygen() {
+        let blocks = read_instructions(&mut Reader::new(include_str!("../input.txt").as_bytes())).unwrap();
+        let search = ModelSearch::new(&blocks);
+        let keygen = Keygen::generate(&blocks);
+
+        assert_eq!(search.largest(), Some(keygen.get_upper_serial()));
+        assert_eq!(search.smallest(), Some(keygen.get_lower_serial()));
+        assert_eq!(search.largest(), SymbolicMonad::analyze(&blocks).largest());
+
+        // every pair of linked digits has the same number of choices on either side
+        let choices: u64 = keygen.links.iter()
+            .filter(|(left, right)| left < right)
+            .map(|(left, _)| (keygen.serial[left].1 - keygen.serial[left].0 + 1) as u64)
+            .product();
+
+        assert_eq!(search.count(), choices);
+    }
+    #[test]
+    fn programs_without_a_stack() {
+        // the sum of the digits has to be 10, with no div z to prune by
+        let program = "inp w\nadd z w\ninp w\nadd z w\ninp w\nadd z w\nadd z -10\n";
+        let search = ModelSearch::new(&read_instructions(&mut Reader::new(program.as_bytes())).unwrap());
+
+        assert_eq!(search.largest(), Some(vec![8, 1, 1]));
+        assert_eq!(search.smallest(), Some(vec![1, 1, 8]));
+        assert_eq!(search.count(), 36);
+        assert_eq!(search.limits, vec![None, None, None]);
+    }
+    #[test]
+    fn registers_passed_through_blocks() {
+        // y is set in the first block and only read in the third
+        let program = "inp w\nadd y w\ninp x\ninp w\nadd z y\nadd z -9\n";
+        let blocks = read_instructions(&mut Reader::new(program.as_bytes())).unwrap();
+        let search = ModelSearch::new(&blocks);
+        let symbolic = SymbolicMonad::analyze(&blocks);
+
+        assert_eq!(search.largest(), symbolic.largest());
+        assert_eq!(search.smallest(), symbolic.smallest());
+        assert_eq!(search.largest(), Some(vec![9, 9, 9]));
+        assert_eq!(search.smallest(), Some(vec![9, 1, 1]));
+        assert_eq!(search.count(), 81);
+    }
+    #[test]
+    fn registers_that_shrink_z() {
+        // the second digit is taken away from the first, which only pruning by z's size would miss
+        let program = "inp w\nadd z w\ninp w\nmul w -1\nadd z w\n";
+        let search = ModelSearch::new(&read_instructions(&mut Reader::new(program.as_bytes())).unwrap());
+
+        assert_eq!(search.largest(), Some(vec![9, 9]));
+        assert_eq!(search.smallest(), Some(vec![1, 1]));
+        assert_eq!(search.count(), 9);
+        assert_eq!(search.limits, vec![None, None]);
+
+        // multiplying by something that can be 0 or dividing by something that can be negative
+        // doesn't prove anything either
+        let program = "inp w\nadd z w\ninp w\nadd w -1\nmul z w\n";
+        let search = ModelSearch::new(&read_instructions(&mut Reader::new(program.as_bytes())).unwrap());
+        assert_eq!(search.count(), 9);
+
+        let program = "inp w\nadd z 9\ninp w\nadd w -10\ndiv z w\nadd z 1\n";
+        let search = ModelSearch::new(&read_instructions(&mut Reader::new(program.as_bytes())).unwrap());
+        assert_eq!(search.count(), 45);
+    }
+}
@@ -0,0 +1,321 @@
+use super::{Block, Instruction, Opcode, Operand, Register};
+
+/// The bounds of a value nothing is known about.
+const UNBOUNDED: (isize, isize) = (isize::MIN, isize::MAX);
+
+/// The values a model number's digits can take.
+pub(super) const DIGITS: (isize, isize) = (1, 9);
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+enum Node {
+    Constant(isize),
+    /// The nth input read from the tape.
+    Input(usize),
+    Operation(Opcode, Box<Expression>, Box<Expression>),
+}
+
+/// A register's value as a function of the input digits, simplified as it's built and carrying
+/// the range of values it can take when every digit is from 1 to 9.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Expression {
+    node: Node,
+    range: (isize, isize),
+}
+impl Expression {
+    fn constant(value: isize) -> Self {
+        Self { node: Node::Constant(value), range: (value, value) }
+    }
+    fn input(index: usize) -> Self {
+        Self { node: Node::Input(index), range: DIGITS }
+    }
+    /// Combines two expressions, folding constants and undoing the `z * 26 + digit` encoding
+    /// MONAD programs push onto `z` wherever the ranges show that's safe.
+    fn operation(opcode: Opcode, left: Expression, right: Expression) -> Self {
+        let range = combine(opcode, left.range, right.range);
+
+        if range.0 == range.1 { return Self::constant(range.0); }
+
+        match (opcode, &left.node, &right.node) {
+            (Opcode::Add, Node::Constant(0), _) | (Opcode::Mul, Node::Constant(1), _) => return right,
+            (Opcode::Add, _, Node::Constant(0)) | (Opcode::Mul, _, Node::Constant(1)) | (Opcode::Div, _, Node::Constant(1)) => return left,
+            (Opcode::Eql, _, _) if left == right => return Self::constant(1),
+            (Opcode::Div, _, &Node::Constant(d)) if d > 0 => {
+                if let Some((quotient, _)) = left.split(d) { return quotient; }
+            },
+            (Opcode::Mod, _, &Node::Constant(m)) if m > 0 => {
+                if left.range.0 >= 0 && left.range.1 < m { return left; }
+                if let Some((_, remainder)) = left.split(m) { return remainder; }
+            },
+            _ => (),
+        }
+
+        Self { node: Node::Operation(opcode, Box::new(left), Box::new(right)), range }
+    }
+    /// Splits `q * d + r` into `q` and `r` if `q` can't be negative and `r` is always below `d`.
+    fn split(&self, d: isize) -> Option<(Expression, Expression)> {
+        if let Node::Operation(Opcode::Add, left, right) = &self.node {
+            for (product, remainder) in [(left, right), (right, left)].iter() {
+                if let Node::Operation(Opcode::Mul, quotient, factor) = &product.node {
+                    if factor.node == Node::Constant(d) && quotient.range.0 >= 0 && remainder.range.0 >= 0 && remainder.range.1 < d {
+                        return Some((quotient.as_ref().clone(), remainder.as_ref().clone()));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+    /// The range of values the expression can take if each input is within its bounds.
+    fn range_with(&self, inputs: &[(isize, isize)]) -> (isize, isize) {
+        match &self.node {
+            Node::Constant(value) => (*value, *value),
+            Node::Input(index) => inputs[*index],
+            Node::Operation(opcode, left, right) => combine(*opcode, left.range_with(inputs), right.range_with(inputs)),
+        }
+    }
+    /// The expression's value for the given inputs, or `None` if the ALU would crash on them.
+    fn evaluate(&self, inputs: &[isize]) -> Option<isize> {
+        match &self.node {
+            Node::Constant(value) => Some(*value),
+            Node::Input(index) => Some(inputs[*index]),
+            Node::Operation(opcode, left, right) => opcode.apply(left.evaluate(inputs)?, right.evaluate(inputs)?),
+        }
+    }
+    pub fn to_string(&self) -> String {
+        match &self.node {
+            Node::Constant(value) => value.to_string(),
+            Node::Input(index) => format!("d{}", index),
+            Node::Operation(opcode, left, right) => {
+                let symbol = match opcode {
+                    Opcode::Add => "+",
+                    Opcode::Mul => "*",
+                    Opcode::Div => "/",
+                    Opcode::Mod => "%",
+                    _ => "==",
+                };
+
+                format!("({} {} {})", left.to_string(), symbol, right.to_string())
+            },
+        }
+    }
+}
+
+/// The range of an operation's result given the ranges of its operands.
+pub(super) fn combine(opcode: Opcode, left: (isize, isize), right: (isize, isize)) -> (isize, isize) {
+    if left.0 == left.1 && right.0 == right.1 {
+        return opcode.apply(left.0, right.0).map_or(UNBOUNDED, |x| (x, x));
+    }
+
+    let corners = |f: fn(i128, i128) -> i128| {
+        let values = [f(left.0 as i128, right.0 as i128), f(left.0 as i128, right.1 as i128),
+                      f(left.1 as i128, right.0 as i128), f(left.1 as i128, right.1 as i128)];
+        let clamp = |x: i128| x.max(isize::MIN as i128).min(isize::MAX as i128) as isize;
+
+        (clamp(*values.iter().min().unwrap()), clamp(*values.iter().max().unwrap()))
+    };
+
+    match opcode {
+        Opcode::Add => (left.0.saturating_add(right.0), left.1.saturating_add(right.1)),
+        Opcode::Mul => corners(|a, b| a * b),
+        // dividing by a range that doesn't cross zero is monotonic in both operands
+        Opcode::Div if right.0 > 0 || right.1 < 0 => corners(|a, b| a / b),
+        Opcode::Mod if left.0 >= 0 && right.0 > 0 => (0, left.1.min(right.1-1)),
+        Opcode::Eql if left.1 < right.0 || right.1 < left.0 => (0, 0),
+        Opcode::Eql => (0, 1),
+        _ => UNBOUNDED,
+    }
+}
+
+/// Something that has to hold for the program to take one particular path.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Constraint {
+    left: Expression,
+    right: Expression,
+    equal: bool,
+}
+impl Constraint {
+    /// Whether the constraint could still hold with each input within its bounds.
+    fn possible(&self, inputs: &[(isize, isize)]) -> bool {
+        let (left, right) = (self.left.range_with(inputs), self.right.range_with(inputs));
+
+        if self.equal { left.0 <= right.1 && right.0 <= left.1 }
+        else { !(left.0 == left.1 && right.0 == right.1 && left.0 == right.0) }
+    }
+    fn holds(&self, inputs: &[isize]) -> bool {
+        match (self.left.evaluate(inputs), self.right.evaluate(inputs)) {
+            (Some(left), Some(right)) => (left == right) == self.equal,
+            _ => false,
+        }
+    }
+    pub fn to_string(&self) -> String {
+        format!("{} {} {}", self.left.to_string(), if self.equal { "==" } else { "!=" }, self.right.to_string())
+    }
+}
+
+/// Runs a program over unknown inputs, splitting at every `eql` whose result depends on them.
+/// Each path that can end with `z` at 0 is kept along with the constraints that lead there, so
+/// valid model numbers can be found without knowing how the program is laid out.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SymbolicMonad {
+    inputs: usize,
+    paths: Vec<Vec<Constraint>>,
+}
+impl SymbolicMonad {
+    pub fn analyze(blocks: &Vec<Block>) -> Self {
+        let instructions: Vec<Instruction> = blocks.iter().flat_map(|x| x.instructions.iter().cloned()).collect();
+        let inputs = instructions.iter().filter(|x| x.opcode == Opcode::Inp).count();
+        let mut paths = Vec::<Vec<Constraint>>::new();
+        let registers = [Expression::constant(0), Expression::constant(0), Expression::constant(0), Expression::constant(0)];
+
+        explore(&instructions, 0, registers, 0, Vec::<Constraint>::new(), &mut paths);
+
+        Self { inputs, paths }
+    }
+    /// The constraints on each path that can be valid.
+    pub fn paths(&self) -> &Vec<Vec<Constraint>> {
+        &self.paths
+    }
+    pub fn largest(&self) -> Option<Vec<isize>> {
+        self.paths.iter().filter_map(|x| solve(x, self.inputs, true)).max()
+    }
+    pub fn smallest(&self) -> Option<Vec<isize>> {
+        self.paths.iter().filter_map(|x| solve(x, self.inputs, false)).min()
+    }
+}
+
+pub(super) fn register_index(register: Register) -> usize {
+    match register {
+        Register::W => 0,
+        Register::X => 1,
+        Register::Y => 2,
+        Register::Z => 3,
+    }
+}
+
+fn explore(instructions: &[Instruction], start: usize, mut registers: [Expression; 4], mut inputs: usize, constraints: Vec<Constraint>, paths: &mut Vec<Vec<Constraint>>) {
+    for index in start..instructions.len() {
+        let instruction = &instructions[index];
+        let target = match instruction.operands[0] {
+            Operand::Register(register) => register_index(register),
+            Operand::Constant(_) => panic!("cannot store in constant"),
+        };
+
+        if instruction.opcode == Opcode::Inp {
+            registers[target] = Expression::input(inputs);
+            inputs += 1;
+            continue;
+        }
+
+        let right = match instruction.operands[1] {
+            Operand::Register(register) => registers[register_index(register)].clone(),
+            Operand::Constant(value) => Expression::constant(value),
+        };
+        let result = Expression::operation(instruction.opcode, registers[target].clone(), right.clone());
+
+        if instruction.opcode == Opcode::Eql && result.range == (0, 1) {
+            // the comparison could go either way, so follow both
+            for &equal in [true, false].iter() {
+                let mut constraints = constraints.clone();
+                let mut registers = registers.clone();
+
+                constraints.push(Constraint { left: registers[target].clone(), right: right.clone(), equal });
+                registers[target] = Expression::constant(equal as isize);
+                explore(instructions, index+1, registers, inputs, constraints, paths);
+            }
+
+            return;
+        }
+
+        registers[target] = result;
+    }
+
+    let z = &registers[register_index(Register::Z)];
+
+    if z.range.0 > 0 || z.range.1 < 0 { return; }
+
+    let mut constraints = constraints;
+
+    if z.node != Node::Constant(0) {
+        constraints.push(Constraint { left: z.clone(), right: Expression::constant(0), equal: true });
+    }
+
+    paths.push(constraints);
+}
+
+/// Finds the largest or smallest digits meeting every constraint, choosing digits from the
+/// most significant down and backing out as soon as a constraint's ranges can't meet.
+fn solve(constraints: &[Constraint], inputs: usize, largest: bool) -> Option<Vec<isize>> {
+    let mut bounds = vec![DIGITS; inputs];
+
+    if !constraints.iter().all(|x| x.possible(&bounds)) { return None; }
+    if assign(constraints, &mut bounds, 0, largest) {
+        Some(bounds.iter().map(|x| x.0).collect())
+    }
+    else { None }
+}
+
+fn assign(constraints: &[Constraint], bounds: &mut Vec<(isize, isize)>, index: usize, largest: bool) -> bool {
+    if index == bounds.len() {
+        let digits: Vec<isize> = bounds.iter().map(|x| x.0).collect();
+
+        return constraints.iter().all(|x| x.holds(&digits));
+    }
+
+    for i in 0..9 {
+        let digit = if largest { DIGITS.1 - i } else { DIGITS.0 + i };
+
+        bounds[index] = (digit, digit);
+
+        if constraints.iter().all(|x| x.possible(bounds)) && assign(constraints, bounds, index+1, largest) { return true; }
+    }
+
+    bounds[index] = DIGITS;
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{read_instructions, Keygen};
+    use common::Reader;
+
+    fn analyze(program: &str) -> SymbolicMonad {
+        SymbolicMonad::analyze(&read_instructions(&mut Reader::new(program.as_bytes())).unwrap())
+    }
+
+    #[test]
+    fn simplified_expressions() {
+        let z = Expression::operation(Opcode::Add,
+                                      Expression::operation(Opcode::Mul, Expression::input(0), Expression::constant(26)),
+                                      Expression::operation(Opcode::Add, Expression::input(1), Expression::constant(4)));
+
+        assert_eq!(z.to_string(), "((d0 * 26) + (d1 + 4))");
+        assert_eq!(z.range, (31, 247));
+        assert_eq!(Expression::operation(Opcode::Mod, z.clone(), Expression::constant(26)).to_string(), "(d1 + 4)");
+        assert_eq!(Expression::operation(Opcode::Div, z.clone(), Expression::constant(26)).to_string(), "d0");
+        assert_eq!(Expression::operation(Opcode::Eql, z.clone(), Expression::input(2)), Expression::constant(0));
+        assert_eq!(Expression::operation(Opcode::Mul, z.clone(), Expression::constant(0)), Expression::constant(0));
+    }
+    #[test]
+    fn differently_shaped_programs() {
+        // z is 0 only when the second digit is 3 more than the first, and the third is even
+        let program = "inp x\ninp y\nadd x 3\neql x y\neql x 0\nadd z x\ninp w\nmod w 2\nadd z w\n";
+        let monad = analyze(program);
+
+        assert_eq!(monad.largest(), Some(vec![6, 9, 8]));
+        assert_eq!(monad.smallest(), Some(vec![1, 4, 2]));
+        assert_eq!(monad.paths().len(), 1);
+        assert_eq!(monad.paths()[0].iter().map(|x| x.to_string()).collect::<Vec<String>>(), vec!["(d0 + 3) == d1", "(d2 % 2) == 0"]);
+
+        assert_eq!(analyze("inp x\nadd z x\n").largest(), None);
+    }
+    #[test]
+    fn agrees_with_keygen() {
+        let blocks = read_instructions(&mut Reader::new(include_str!("../input.txt").as_bytes())).unwrap();
+        let monad = SymbolicMonad::analyze(&blocks);
+        let keygen = Keygen::generate(&blocks);
+
+        assert_eq!(monad.largest(), Some(keygen.get_upper_serial()));
+        assert_eq!(monad.smallest(), Some(keygen.get_lower_serial()));
+    }
+}
@@ -0,0 +1,120 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Edges, Grid};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum TilePiece {
+    Empty,
+    East,
+    South,
+}
+impl TilePiece {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '>' => Some(Self::East),
+            'v' => Some(Self::South),
+            _ => None,
+        }
+    }
+    #[allow(dead_code)]
+    fn to_char(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::East => '>',
+            Self::South => 'v',
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SeaFloor {
+    map: Grid<TilePiece>,
+}
+impl SeaFloor {
+    fn herd(&mut self, piece: TilePiece, dx: isize, dy: isize) -> usize {
+        // the whole herd looks before any of it moves
+        let migrations: Vec<(Coordinate, Coordinate)> = self.map.iter()
+            .filter(|(_, &tile)| tile == piece)
+            .filter_map(|(coord, _)| self.map.offset(coord, dx, dy).map(|dest| (coord, dest)))
+            .filter(|&(_, dest)| self.map[dest] == TilePiece::Empty)
+            .collect();
+
+        for &(coord, dest) in &migrations {
+            self.map.swap(coord, dest);
+        }
+
+        migrations.len()
+    }
+    fn migrate(&mut self) -> usize {
+        self.herd(TilePiece::East, 1, 0) + self.herd(TilePiece::South, 0, 1)
+    }
+    #[allow(dead_code)]
+    fn print(&self) {
+        println!("{}", self.map.render(TilePiece::to_char));
+    }
+}
+
+fn migrate_seafloor(seafloor: &mut SeaFloor) -> usize {
+    let mut step = 0usize;
+
+    loop {
+        step += 1;
+        let migration_check = seafloor.migrate();
+
+        // println!("step {}:", step);
+        // seafloor.print();
+
+        // if step == 30 { panic!("break"); }
+
+        if migration_check == 0 { return step; }
+    }
+}
+
+fn read_seafloor(input: &mut Reader) -> Result<SeaFloor, ParseError> {
+    let map = Grid::read(input, "'.', '>' or 'v'", TilePiece::from_char)?.with_edges(Edges::Wrapping);
+
+    Ok(SeaFloor { map })
+}
+            
+pub struct SeaCucumber;
+impl Solution for SeaCucumber {
+    type Input = SeaFloor;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_seafloor(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(seafloor: &Self::Input) -> Answer {
+        let mut seafloor = seafloor.clone();
+        
+        migrate_seafloor(&mut seafloor).into()
+    }
+    fn part2(_: &Self::Input) -> Answer {
+        // there is no second puzzle on the last day
+        Answer::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(SeaCucumber::solve(EXAMPLE, 1), Ok(Answer::from(58)));
+    }
+    #[test]
+    fn no_part2() {
+        assert_eq!(SeaCucumber::solve(EXAMPLE, 2), Ok(Answer::None));
+    }
+}
@@ -0,0 +1,78 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Elf {
+    calories: Vec<u64>,
+}
+impl Elf {
+    fn total(&self) -> u64 { self.calories.iter().sum() }
+}
+
+fn read_elves(input: &mut Reader) -> Result<Vec<Elf>, ParseError> {
+    let mut elves = Vec::<Elf>::new();
+
+    while let Some(group) = input.next_group() {
+        let mut calories = Vec::<u64>::new();
+
+        for line in group.lines() {
+            calories.push(line.parse(line.text(), "a calorie count")?);
+        }
+
+        elves.push(Elf { calories });
+    }
+
+    if elves.len() == 0 { Err(input.end("an elf's calorie counts")) }
+    else { Ok(elves) }
+}
+
+fn sorted_totals(elves: &Vec<Elf>) -> Vec<u64> {
+    let mut totals: Vec<u64> = elves.iter().map(|x| x.total()).collect();
+    totals.sort_by(|a,b| b.cmp(a));
+
+    totals
+}
+
+pub struct CalorieCounting;
+impl Solution for CalorieCounting {
+    type Input = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_elves(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(elves: &Self::Input) -> Answer {
+        sorted_totals(elves)[0].into()
+    }
+    fn part2(elves: &Self::Input) -> Answer {
+        sorted_totals(elves).iter().take(3).sum::<u64>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(CalorieCounting::solve(EXAMPLE, 1), Ok(Answer::from(24000)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(CalorieCounting::solve(EXAMPLE, 2), Ok(Answer::from(45000)));
+    }
+}
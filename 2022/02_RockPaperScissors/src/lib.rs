@@ -0,0 +1,109 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+impl Shape {
+    fn from_index(index: u8) -> Self {
+        match index % 3 {
+            0 => Self::Rock,
+            1 => Self::Paper,
+            _ => Self::Scissors,
+        }
+    }
+    fn score(&self) -> u32 { *self as u32 + 1 }
+    /// The outcome of playing `self` against `other`.
+    fn against(&self, other: Shape) -> Outcome {
+        match (3 + *self as u8 - other as u8) % 3 {
+            0 => Outcome::Draw,
+            1 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+impl Outcome {
+    fn score(&self) -> u32 { *self as u32 * 3 }
+}
+
+/// One line of the strategy guide: the opponent's shape and the column that's either our
+/// shape or the outcome we want, depending on which part is being played.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Round {
+    opponent: Shape,
+    column: u8,
+}
+
+fn read_guide(input: &mut Reader) -> Result<Vec<Round>, ParseError> {
+    let mut rounds = Vec::<Round>::new();
+
+    while let Some(line) = input.next_line() {
+        let (opponent, column) = line.split_once(line.text(), " ", "a round like A Y")?;
+
+        let opponent = match opponent {
+            "A" | "B" | "C" => Shape::from_index(opponent.as_bytes()[0] - b'A'),
+            _ => return Err(line.error(opponent, "A, B or C")),
+        };
+        let column = match column {
+            "X" | "Y" | "Z" => column.as_bytes()[0] - b'X',
+            _ => return Err(line.error(column, "X, Y or Z")),
+        };
+
+        rounds.push(Round { opponent, column });
+    }
+
+    if rounds.len() == 0 { Err(input.end("a round like A Y")) }
+    else { Ok(rounds) }
+}
+
+pub struct RockPaperScissors;
+impl Solution for RockPaperScissors {
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_guide(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(rounds: &Self::Input) -> Answer {
+        rounds.iter().map(|round| {
+            let shape = Shape::from_index(round.column);
+
+            shape.score() + shape.against(round.opponent).score()
+        }).sum::<u32>().into()
+    }
+    fn part2(rounds: &Self::Input) -> Answer {
+        rounds.iter().map(|round| {
+            // a draw plays the same shape, a win the next one and a loss the one before
+            let shape = Shape::from_index(round.opponent as u8 + round.column + 2);
+
+            shape.score() + shape.against(round.opponent).score()
+        }).sum::<u32>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "A Y
+B X
+C Z
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(RockPaperScissors::solve(EXAMPLE, 1), Ok(Answer::from(15)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(RockPaperScissors::solve(EXAMPLE, 2), Ok(Answer::from(12)));
+    }
+}
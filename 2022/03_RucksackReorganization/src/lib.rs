@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+type Items = HashSet<char>;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Rucksack {
+    left: Items,
+    right: Items,
+}
+impl Rucksack {
+    fn all(&self) -> Items {
+        self.left.union(&self.right).copied().collect()
+    }
+    fn overlap(&self) -> char {
+        *self.left.intersection(&self.right).next().unwrap()
+    }
+}
+
+fn priority(item: char) -> u32 {
+    if item.is_ascii_lowercase() { item as u32 - 'a' as u32 + 1 }
+    else { item as u32 - 'A' as u32 + 27 }
+}
+
+fn read_rucksacks(input: &mut Reader) -> Result<Vec<Rucksack>, ParseError> {
+    let mut rucksacks = Vec::<Rucksack>::new();
+
+    while let Some(line) = input.next_line() {
+        let items = line.map_chars("an item from a to z or A to Z", |x| if x.is_ascii_alphabetic() { Some(x) } else { None })?;
+
+        if items.len() % 2 != 0 { return Err(line.error(line.text(), "an even number of items")); }
+
+        let (left, right) = items.split_at(items.len() / 2);
+
+        if !left.iter().any(|x| right.contains(x)) {
+            return Err(line.error(line.text(), "an item in both compartments"));
+        }
+
+        rucksacks.push(Rucksack { left: left.iter().copied().collect(), right: right.iter().copied().collect() });
+    }
+
+    if rucksacks.len() == 0 { Err(input.end("a rucksack")) }
+    else if rucksacks.len() % 3 != 0 { Err(input.end("a rucksack to finish the group of three")) }
+    else { Ok(rucksacks) }
+}
+
+pub struct RucksackReorganization;
+impl Solution for RucksackReorganization {
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_rucksacks(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        rucksacks.iter().map(|x| priority(x.overlap())).sum::<u32>().into()
+    }
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        rucksacks.chunks(3).map(|group| {
+            let badge = group[1..].iter().fold(group[0].all(), |common, x| common.intersection(&x.all()).copied().collect());
+
+            badge.iter().map(|x| priority(*x)).sum::<u32>()
+        }).sum::<u32>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(RucksackReorganization::solve(EXAMPLE, 1), Ok(Answer::from(157)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(RucksackReorganization::solve(EXAMPLE, 2), Ok(Answer::from(70)));
+    }
+}
@@ -0,0 +1,70 @@
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Section {
+    low: u32,
+    high: u32,
+}
+impl Section {
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let (low, high) = line.split_once(s, "-", "a section like 2-4")?;
+
+        Ok(Self { low: line.parse(low, "a section number")?, high: line.parse(high, "a section number")? })
+    }
+    fn contains(&self, other: &Section) -> bool {
+        other.low >= self.low && other.high <= self.high
+    }
+    fn overlaps(&self, other: &Section) -> bool {
+        self.low <= other.high && other.low <= self.high
+    }
+}
+
+fn read_pairs(input: &mut Reader) -> Result<Vec<(Section, Section)>, ParseError> {
+    let mut pairs = Vec::<(Section, Section)>::new();
+
+    while let Some(line) = input.next_line() {
+        let (first, second) = line.split_once(line.text(), ",", "a pair like 2-4,6-8")?;
+
+        pairs.push((Section::from_str(first, &line)?, Section::from_str(second, &line)?));
+    }
+
+    if pairs.len() == 0 { Err(input.end("a pair like 2-4,6-8")) }
+    else { Ok(pairs) }
+}
+
+pub struct CampCleanup;
+impl Solution for CampCleanup {
+    type Input = Vec<(Section, Section)>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_pairs(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(pairs: &Self::Input) -> Answer {
+        pairs.iter().filter(|(a,b)| a.contains(b) || b.contains(a)).count().into()
+    }
+    fn part2(pairs: &Self::Input) -> Answer {
+        pairs.iter().filter(|(a,b)| a.overlaps(b)).count().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(CampCleanup::solve(EXAMPLE, 1), Ok(Answer::from(2)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(CampCleanup::solve(EXAMPLE, 2), Ok(Answer::from(4)));
+    }
+}
@@ -0,0 +1,122 @@
+use common::{Answer, Error, Group, ParseError, Reader, Solution};
+
+type Stack = Vec<char>;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Procedure {
+    stacks: Vec<Stack>,
+    moves: Vec<Move>,
+}
+impl Procedure {
+    /// Runs every move, lifting one crate at a time or, with `in_order`, all of them at once.
+    fn rearrange(&self, in_order: bool) -> String {
+        let mut stacks = self.stacks.clone();
+
+        for step in &self.moves {
+            let from = &mut stacks[step.from];
+            let mut lifted = from.split_off(from.len().saturating_sub(step.count));
+
+            if !in_order { lifted.reverse(); }
+
+            stacks[step.to].extend(lifted);
+        }
+
+        stacks.iter().filter_map(|x| x.last()).collect()
+    }
+}
+
+fn read_stacks(drawing: &Group) -> Result<Vec<Stack>, ParseError> {
+    let labels = drawing.last();
+    let count = labels.text().split_whitespace().count();
+
+    if count == 0 { return Err(labels.error(labels.text(), "a row of stack numbers")); }
+
+    let mut stacks = vec![Stack::new(); count];
+
+    for line in drawing.lines().take(drawing.len()-1) {
+        let row = line.text().as_bytes();
+
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match row.get(1+i*4) {
+                Some(b' ') | None => (),
+                Some(c) if c.is_ascii_uppercase() => stack.insert(0, *c as char),
+                Some(_) => return Err(line.error(line.text().get(i*4..).unwrap_or(line.text()), "a crate like [A]")),
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn read_procedure(input: &mut Reader) -> Result<Procedure, ParseError> {
+    let drawing = input.next_group().ok_or(input.end("a drawing of the stacks"))?;
+    let stacks = read_stacks(&drawing)?;
+    let mut moves = Vec::<Move>::new();
+
+    while let Some(line) = input.next_line() {
+        let tokens: Vec<&str> = line.text().split(' ').collect();
+
+        if tokens.len() != 6 || tokens[0] != "move" || tokens[2] != "from" || tokens[4] != "to" {
+            return Err(line.error(line.text(), "a move like move 1 from 2 to 1"));
+        }
+
+        let count = line.parse(tokens[1], "a number of crates")?;
+        let from: usize = line.parse(tokens[3], "a stack number")?;
+        let to: usize = line.parse(tokens[5], "a stack number")?;
+
+        if from < 1 || from > stacks.len() { return Err(line.error(tokens[3], &format!("a stack from 1 to {}", stacks.len()))); }
+        if to < 1 || to > stacks.len() { return Err(line.error(tokens[5], &format!("a stack from 1 to {}", stacks.len()))); }
+
+        moves.push(Move { count, from: from-1, to: to-1 });
+    }
+
+    if moves.len() == 0 { Err(input.end("a move like move 1 from 2 to 1")) }
+    else { Ok(Procedure { stacks, moves }) }
+}
+
+pub struct SupplyStacks;
+impl Solution for SupplyStacks {
+    type Input = Procedure;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_procedure(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(procedure: &Self::Input) -> Answer {
+        procedure.rearrange(false).into()
+    }
+    fn part2(procedure: &Self::Input) -> Answer {
+        procedure.rearrange(true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(SupplyStacks::solve(EXAMPLE, 1), Ok(Answer::from("CMZ")));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(SupplyStacks::solve(EXAMPLE, 2), Ok(Answer::from("MCD")));
+    }
+}
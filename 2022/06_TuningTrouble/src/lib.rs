@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn read_datastream(input: &mut Reader) -> Result<Vec<char>, ParseError> {
+    let line = input.expect_line("a datastream")?;
+
+    line.map_chars("a letter from a to z", |x| if x.is_ascii_lowercase() { Some(x) } else { None })
+}
+
+/// The number of characters read by the end of the first `size` characters that are all different.
+fn find_marker(datastream: &Vec<char>, size: usize) -> Option<usize> {
+    datastream.windows(size)
+        .position(|x| x.iter().collect::<HashSet<&char>>().len() == size)
+        .map(|x| x+size)
+}
+
+pub struct TuningTrouble;
+impl Solution for TuningTrouble {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_datastream(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(datastream: &Self::Input) -> Answer {
+        find_marker(datastream, 4).map_or(Answer::None, |x| x.into())
+    }
+    fn part2(datastream: &Self::Input) -> Answer {
+        find_marker(datastream, 14).map_or(Answer::None, |x| x.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(TuningTrouble::solve(EXAMPLE, 1), Ok(Answer::from(7)));
+        assert_eq!(TuningTrouble::solve("bvwbjplbgvbhsrlpgdmjqwftvncz", 1), Ok(Answer::from(5)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(TuningTrouble::solve(EXAMPLE, 2), Ok(Answer::from(19)));
+        assert_eq!(TuningTrouble::solve("bvwbjplbgvbhsrlpgdmjqwftvncz", 2), Ok(Answer::from(23)));
+    }
+}
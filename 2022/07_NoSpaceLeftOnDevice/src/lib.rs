@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+const DISK_SIZE: u64 = 70000000;
+const UPDATE_SIZE: u64 = 30000000;
+
+/// The total size of every directory seen in the terminal output, keyed by its path from `/`.
+type Filesystem = HashMap<Vec<String>, u64>;
+
+fn read_terminal(input: &mut Reader) -> Result<Filesystem, ParseError> {
+    let mut filesystem = Filesystem::new();
+    let mut path = Vec::<String>::new();
+
+    filesystem.insert(path.clone(), 0);
+
+    while let Some(line) = input.next_line() {
+        let tokens: Vec<&str> = line.text().split(' ').collect();
+
+        match tokens.as_slice() {
+            ["$", "cd", "/"] => path.clear(),
+            ["$", "cd", ".."] => if path.pop().is_none() { return Err(line.error(tokens[2], "a directory below /")); },
+            ["$", "cd", name] => {
+                path.push(name.to_string());
+                filesystem.entry(path.clone()).or_insert(0);
+            },
+            ["$", "ls"] => (),
+            ["dir", name] => { filesystem.entry([path.clone(), vec![name.to_string()]].concat()).or_insert(0); },
+            [size, _] => {
+                let size: u64 = line.parse(size, "a file size")?;
+
+                for depth in 0..=path.len() {
+                    *filesystem.get_mut(&path[..depth]).unwrap() += size;
+                }
+            },
+            _ => return Err(line.error(line.text(), "a command, a directory or a file")),
+        }
+    }
+
+    if filesystem.len() == 1 && filesystem[&vec![]] == 0 { Err(input.end("a command")) }
+    else { Ok(filesystem) }
+}
+
+pub struct NoSpaceLeftOnDevice;
+impl Solution for NoSpaceLeftOnDevice {
+    type Input = Filesystem;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_terminal(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(filesystem: &Self::Input) -> Answer {
+        filesystem.values().filter(|x| **x <= 100000).sum::<u64>().into()
+    }
+    fn part2(filesystem: &Self::Input) -> Answer {
+        let free = DISK_SIZE - filesystem[&vec![]];
+        let needed = UPDATE_SIZE.saturating_sub(free);
+
+        (*filesystem.values().filter(|x| **x >= needed).min().unwrap()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(NoSpaceLeftOnDevice::solve(EXAMPLE, 1), Ok(Answer::from(95437)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(NoSpaceLeftOnDevice::solve(EXAMPLE, 2), Ok(Answer::from(24933642)));
+    }
+}
@@ -0,0 +1,70 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Grid, Neighborhood};
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Forest {
+    trees: Grid<u32>,
+}
+impl Forest {
+    fn read(input: &mut Reader) -> Result<Self, ParseError> {
+        Ok(Self { trees: Grid::read(input, "a tree height from 0 to 9", |x| x.to_digit(10))? })
+    }
+    fn is_visible(&self, coord: Coordinate) -> bool {
+        let height = self.trees[coord];
+
+        Neighborhood::Four.offsets().iter().any(|&(dx, dy)| {
+            self.trees.ray(coord, dx, dy).all(|x| self.trees[x] < height)
+        })
+    }
+    fn scenic_score(&self, coord: Coordinate) -> usize {
+        let height = self.trees[coord];
+
+        Neighborhood::Four.offsets().iter().map(|&(dx, dy)| {
+            let mut distance = 0;
+
+            for tree in self.trees.ray(coord, dx, dy) {
+                distance += 1;
+
+                if self.trees[tree] >= height { break; }
+            }
+
+            distance
+        }).product()
+    }
+}
+
+pub struct TreetopTreeHouse;
+impl Solution for TreetopTreeHouse {
+    type Input = Forest;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Forest::read(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(forest: &Self::Input) -> Answer {
+        forest.trees.coordinates().filter(|x| forest.is_visible(*x)).count().into()
+    }
+    fn part2(forest: &Self::Input) -> Answer {
+        forest.trees.coordinates().map(|x| forest.scenic_score(x)).max().unwrap().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(TreetopTreeHouse::solve(EXAMPLE, 1), Ok(Answer::from(21)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(TreetopTreeHouse::solve(EXAMPLE, 2), Ok(Answer::from(8)));
+    }
+}
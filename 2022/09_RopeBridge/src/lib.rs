@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Coordinate(i32, i32);
+impl Coordinate {
+    /// Moves this knot one step toward `head` if the two have come apart.
+    fn follow(&self, head: Coordinate) -> Self {
+        let (dx, dy) = (head.0-self.0, head.1-self.1);
+
+        if dx.abs() <= 1 && dy.abs() <= 1 { *self }
+        else { Self(self.0+dx.signum(), self.1+dy.signum()) }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Motion {
+    delta: (i32, i32),
+    steps: usize,
+}
+
+fn read_motions(input: &mut Reader) -> Result<Vec<Motion>, ParseError> {
+    let mut motions = Vec::<Motion>::new();
+
+    while let Some(line) = input.next_line() {
+        let (direction, steps) = line.split_once(line.text(), " ", "a motion like R 4")?;
+
+        let delta = match direction {
+            "L" => (-1,0),
+            "U" => (0,1),
+            "R" => (1,0),
+            "D" => (0,-1),
+            _ => return Err(line.error(direction, "L, U, R or D")),
+        };
+
+        motions.push(Motion { delta, steps: line.parse(steps, "a number of steps")? });
+    }
+
+    if motions.len() == 0 { Err(input.end("a motion like R 4")) }
+    else { Ok(motions) }
+}
+
+/// Drags a rope of `knots` knots through the motions and counts where its tail has been.
+fn simulate(motions: &Vec<Motion>, knots: usize) -> usize {
+    let mut rope = vec![Coordinate(0,0); knots];
+    let mut visited = HashSet::<Coordinate>::new();
+
+    visited.insert(rope[knots-1]);
+
+    for motion in motions {
+        for _ in 0..motion.steps {
+            rope[0] = Coordinate(rope[0].0+motion.delta.0, rope[0].1+motion.delta.1);
+
+            for i in 1..knots {
+                rope[i] = rope[i].follow(rope[i-1]);
+            }
+
+            visited.insert(rope[knots-1]);
+        }
+    }
+
+    visited.len()
+}
+
+pub struct RopeBridge;
+impl Solution for RopeBridge {
+    type Input = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_motions(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(motions: &Self::Input) -> Answer {
+        simulate(motions, 2).into()
+    }
+    fn part2(motions: &Self::Input) -> Answer {
+        simulate(motions, 10).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+    const LARGER_EXAMPLE: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(RopeBridge::solve(EXAMPLE, 1), Ok(Answer::from(13)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(RopeBridge::solve(EXAMPLE, 2), Ok(Answer::from(1)));
+        assert_eq!(RopeBridge::solve(LARGER_EXAMPLE, 2), Ok(Answer::from(36)));
+    }
+}
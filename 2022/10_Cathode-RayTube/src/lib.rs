@@ -0,0 +1,242 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn read_program(input: &mut Reader) -> Result<Vec<Instruction>, ParseError> {
+    let mut program = Vec::<Instruction>::new();
+
+    while let Some(line) = input.next_line() {
+        let instruction = match line.text().split_once(' ') {
+            None if line.text() == "noop" => Instruction::Noop,
+            Some(("addx", value)) => Instruction::Addx(line.parse(value, "a number to add")?),
+            _ => return Err(line.error(line.text(), "noop or addx V")),
+        };
+
+        program.push(instruction);
+    }
+
+    if program.len() == 0 { Err(input.end("an instruction")) }
+    else { Ok(program) }
+}
+
+/// The value of the X register during each cycle, starting with the first.
+fn execute(program: &Vec<Instruction>) -> Vec<i32> {
+    let mut x = 1i32;
+    let mut cycles = Vec::<i32>::new();
+
+    for instruction in program {
+        match instruction {
+            Instruction::Noop => cycles.push(x),
+            Instruction::Addx(value) => {
+                cycles.push(x);
+                cycles.push(x);
+                x += value;
+            },
+        }
+    }
+
+    cycles
+}
+
+fn draw(cycles: &Vec<i32>) -> String {
+    let mut rows = Vec::<String>::new();
+
+    for row in cycles.chunks(SCREEN_WIDTH).take(SCREEN_HEIGHT) {
+        rows.push(row.iter().enumerate().map(|(pixel, x)| if (pixel as i32 - x).abs() <= 1 { '#' } else { ' ' }).collect());
+    }
+
+    rows.join("\n")
+}
+
+pub struct CathodeRayTube;
+impl Solution for CathodeRayTube {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_program(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(program: &Self::Input) -> Answer {
+        let cycles = execute(program);
+
+        (20..=cycles.len()).step_by(SCREEN_WIDTH).map(|x| x as i32 * cycles[x-1]).sum::<i32>().into()
+    }
+    fn part2(program: &Self::Input) -> Answer {
+        draw(&execute(program)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(CathodeRayTube::solve(EXAMPLE, 1), Ok(Answer::from(13140)));
+    }
+    #[test]
+    fn part2_example() {
+        let screen = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+        assert_eq!(CathodeRayTube::solve(EXAMPLE, 2), Ok(Answer::from(screen.replace('.', " "))));
+    }
+}
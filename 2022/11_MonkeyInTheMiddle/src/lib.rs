@@ -0,0 +1,171 @@
+use std::collections::VecDeque;
+use common::{Answer, Error, Group, Line, ParseError, Reader, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+impl Operation {
+    fn apply(&self, worry: u64) -> u64 {
+        match self {
+            Self::Add(n) => worry + n,
+            Self::Multiply(n) => worry * n,
+            Self::Square => worry * worry,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+/// The rest of an indented line after `prefix`, which is also what's expected if it's missing.
+fn field<'a>(line: &Line<'a>, prefix: &str) -> Result<&'a str, ParseError> {
+    line.text().trim_start().strip_prefix(prefix).ok_or(line.error(line.text(), prefix))
+}
+
+impl Monkey {
+    fn read_monkey(group: &Group) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = group.lines().collect();
+
+        if lines.len() != 6 { return Err(group.last().error(group.last().text(), "six lines describing a monkey")); }
+
+        field(&lines[0], "Monkey ")?;
+
+        let mut items = VecDeque::<u64>::new();
+
+        for item in field(&lines[1], "Starting items: ")?.split(", ") {
+            items.push_back(lines[1].parse(item, "a worry level")?);
+        }
+
+        let operation = match field(&lines[2], "Operation: new = old ")?.split_once(' ') {
+            Some(("*", "old")) => Operation::Square,
+            Some(("*", n)) => Operation::Multiply(lines[2].parse(n, "a number or old")?),
+            Some(("+", n)) => Operation::Add(lines[2].parse(n, "a number")?),
+            _ => return Err(lines[2].error(lines[2].text(), "an operation like new = old * 19")),
+        };
+
+        let divisor = lines[3].parse(field(&lines[3], "Test: divisible by ")?, "a divisor")?;
+
+        if divisor == 0 { return Err(lines[3].error(lines[3].text(), "a divisor above 0")); }
+
+        Ok(Self {
+            items,
+            operation,
+            divisor,
+            if_true: lines[4].parse(field(&lines[4], "If true: throw to monkey ")?, "a monkey number")?,
+            if_false: lines[5].parse(field(&lines[5], "If false: throw to monkey ")?, "a monkey number")?,
+        })
+    }
+}
+
+fn read_monkeys(input: &mut Reader) -> Result<Vec<Monkey>, ParseError> {
+    let mut groups = Vec::<Group>::new();
+    let mut monkeys = Vec::<Monkey>::new();
+
+    while let Some(group) = input.next_group() {
+        monkeys.push(Monkey::read_monkey(&group)?);
+        groups.push(group);
+    }
+
+    if monkeys.len() == 0 { return Err(input.end("a monkey")); }
+
+    // a monkey can throw to one that comes after it, so targets are checked once all are read
+    for (monkey, group) in monkeys.iter().zip(groups.iter()) {
+        if monkey.if_true >= monkeys.len() || monkey.if_false >= monkeys.len() {
+            let line = if monkey.if_true >= monkeys.len() { group.lines().nth(4).unwrap() } else { group.last() };
+
+            return Err(line.error(line.text(), &format!("a monkey below {}", monkeys.len())));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+/// Plays `rounds` rounds of keep away and multiplies the two highest inspection counts. Without
+/// `relief` worry levels are kept small by the product of every divisor, which no test can tell apart.
+fn monkey_business(monkeys: &Vec<Monkey>, rounds: usize, relief: bool) -> u64 {
+    let mut monkeys = monkeys.clone();
+    let mut inspections = vec![0u64; monkeys.len()];
+    let modulus: u64 = monkeys.iter().map(|x| x.divisor).product();
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            while let Some(item) = monkeys[i].items.pop_front() {
+                let monkey = &monkeys[i];
+                let worry = if relief { monkey.operation.apply(item) / 3 } else { monkey.operation.apply(item) % modulus };
+                let target = if worry % monkey.divisor == 0 { monkey.if_true } else { monkey.if_false };
+
+                inspections[i] += 1;
+                monkeys[target].items.push_back(worry);
+            }
+        }
+    }
+
+    inspections.sort_by(|a,b| b.cmp(a));
+    inspections[0] * inspections.get(1).copied().unwrap_or(1)
+}
+
+pub struct MonkeyInTheMiddle;
+impl Solution for MonkeyInTheMiddle {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_monkeys(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(monkeys: &Self::Input) -> Answer {
+        monkey_business(monkeys, 20, true).into()
+    }
+    fn part2(monkeys: &Self::Input) -> Answer {
+        monkey_business(monkeys, 10000, false).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(MonkeyInTheMiddle::solve(EXAMPLE, 1), Ok(Answer::from(10605)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(MonkeyInTheMiddle::solve(EXAMPLE, 2), Ok(Answer::from(2713310158u64)));
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::VecDeque;
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Grid, Neighborhood};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Square {
+    Start,
+    End,
+    Height(u8),
+}
+impl Square {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'S' => Some(Self::Start),
+            'E' => Some(Self::End),
+            'a'..='z' => Some(Self::Height(c as u8 - b'a')),
+            _ => None,
+        }
+    }
+    fn height(&self) -> u8 {
+        match self {
+            Self::Start => 0,
+            Self::End => 25,
+            Self::Height(h) => *h,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Heightmap {
+    squares: Grid<Square>,
+    start: Coordinate,
+    end: Coordinate,
+}
+impl Heightmap {
+    fn read(input: &mut Reader) -> Result<Self, ParseError> {
+        let squares = Grid::read(input, "a height from a to z, S or E", Square::from_char)?;
+        let find = |square: Square| squares.iter().find(|(_, x)| **x == square).map(|(c, _)| c);
+
+        let start = find(Square::Start).ok_or(input.end("the start marked S"))?;
+        let end = find(Square::End).ok_or(input.end("the best signal marked E"))?;
+
+        Ok(Self { squares, start, end })
+    }
+    /// The fewest steps to reach each square from the best signal, walking backwards so a step
+    /// can go down any amount but up at most one.
+    fn distances_to_end(&self) -> Grid<Option<usize>> {
+        let mut distances = Grid::new(self.squares.width(), self.squares.height(), None);
+        let mut queue = VecDeque::<Coordinate>::new();
+
+        distances[self.end] = Some(0);
+        queue.push_back(self.end);
+
+        while let Some(coord) = queue.pop_front() {
+            let distance = distances[coord].unwrap();
+            let height = self.squares[coord].height();
+
+            for neighbor in self.squares.neighbors(coord, Neighborhood::Four) {
+                if distances[neighbor].is_some() || self.squares[neighbor].height() + 1 < height { continue; }
+
+                distances[neighbor] = Some(distance+1);
+                queue.push_back(neighbor);
+            }
+        }
+
+        distances
+    }
+}
+
+pub struct HillClimbingAlgorithm;
+impl Solution for HillClimbingAlgorithm {
+    type Input = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Heightmap::read(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(heightmap: &Self::Input) -> Answer {
+        heightmap.distances_to_end()[heightmap.start].map_or(Answer::None, |x| x.into())
+    }
+    fn part2(heightmap: &Self::Input) -> Answer {
+        let distances = heightmap.distances_to_end();
+
+        heightmap.squares.iter()
+            .filter(|(_, x)| x.height() == 0)
+            .filter_map(|(c, _)| distances[c])
+            .min()
+            .map_or(Answer::None, |x| x.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(HillClimbingAlgorithm::solve(EXAMPLE, 1), Ok(Answer::from(31)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(HillClimbingAlgorithm::solve(EXAMPLE, 2), Ok(Answer::from(29)));
+    }
+}
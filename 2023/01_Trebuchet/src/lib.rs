@@ -0,0 +1,79 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn read_calibration(input: &mut Reader) -> Result<Vec<String>, ParseError> {
+    let mut lines = Vec::<String>::new();
+
+    while let Some(line) = input.next_line() {
+        lines.push(line.text().to_string());
+    }
+
+    if lines.len() == 0 { Err(input.end("a line of the calibration document")) }
+    else { Ok(lines) }
+}
+
+/// The digit that starts at the front of `s`, spelled out as a word too if `words` is set.
+fn digit_at(s: &str, words: bool) -> Option<u32> {
+    if let Some(digit) = s.chars().next().and_then(|x| x.to_digit(10)) { return Some(digit); }
+    if !words { return None; }
+
+    DIGIT_WORDS.iter().position(|x| s.starts_with(x)).map(|x| x as u32 + 1)
+}
+
+/// The first and last digit of each line as a two digit number, added up. Digits can
+/// overlap when they're words, e.g. eightwo is 8 then 2.
+fn calibrate(lines: &Vec<String>, words: bool) -> u32 {
+    lines.iter().map(|line| {
+        let digits: Vec<u32> = (0..line.len()).filter_map(|i| line.get(i..).and_then(|x| digit_at(x, words))).collect();
+
+        match (digits.first(), digits.last()) {
+            (Some(first), Some(last)) => first*10 + last,
+            _ => 0,
+        }
+    }).sum()
+}
+
+pub struct Trebuchet;
+impl Solution for Trebuchet {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_calibration(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(lines: &Self::Input) -> Answer {
+        calibrate(lines, false).into()
+    }
+    fn part2(lines: &Self::Input) -> Answer {
+        calibrate(lines, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const WORDS_EXAMPLE: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Trebuchet::solve(EXAMPLE, 1), Ok(Answer::from(142)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(Trebuchet::solve(WORDS_EXAMPLE, 2), Ok(Answer::from(281)));
+    }
+}
@@ -0,0 +1,106 @@
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+/// A handful of cubes as red, green and blue counts.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub struct Cubes {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+impl Cubes {
+    fn from_str(s: &str, line: &Line) -> Result<Self, ParseError> {
+        let mut cubes = Cubes::default();
+
+        for draw in s.split(", ") {
+            let (count, color) = line.split_once(draw, " ", "a count of cubes like 3 blue")?;
+            let count = line.parse(count, "a number of cubes")?;
+
+            match color {
+                "red" => cubes.red = count,
+                "green" => cubes.green = count,
+                "blue" => cubes.blue = count,
+                _ => return Err(line.error(color, "red, green or blue")),
+            }
+        }
+
+        Ok(cubes)
+    }
+    fn fits_in(&self, bag: &Cubes) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+    fn max(&self, other: &Cubes) -> Cubes {
+        Cubes { red: self.red.max(other.red), green: self.green.max(other.green), blue: self.blue.max(other.blue) }
+    }
+    fn power(&self) -> u32 { self.red * self.green * self.blue }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Game {
+    id: u32,
+    handfuls: Vec<Cubes>,
+}
+
+fn read_games(input: &mut Reader) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::<Game>::new();
+
+    while let Some(line) = input.next_line() {
+        let (game, handfuls) = line.split_once(line.text(), ": ", "a game like Game 1: 3 blue, 4 red")?;
+        let id = game.strip_prefix("Game ").ok_or(line.error(game, "Game followed by its number"))?;
+        let id = line.parse(id, "a game number")?;
+        let mut cubes = Vec::<Cubes>::new();
+
+        for handful in handfuls.split("; ") {
+            cubes.push(Cubes::from_str(handful, &line)?);
+        }
+
+        games.push(Game { id, handfuls: cubes });
+    }
+
+    if games.len() == 0 { Err(input.end("a game like Game 1: 3 blue, 4 red")) }
+    else { Ok(games) }
+}
+
+pub struct CubeConundrum;
+impl Solution for CubeConundrum {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_games(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(games: &Self::Input) -> Answer {
+        let bag = Cubes { red: 12, green: 13, blue: 14 };
+
+        games.iter()
+            .filter(|game| game.handfuls.iter().all(|x| x.fits_in(&bag)))
+            .map(|game| game.id)
+            .sum::<u32>()
+            .into()
+    }
+    fn part2(games: &Self::Input) -> Answer {
+        games.iter()
+            .map(|game| game.handfuls.iter().fold(Cubes::default(), |fewest, x| fewest.max(x)).power())
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(CubeConundrum::solve(EXAMPLE, 1), Ok(Answer::from(8)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(CubeConundrum::solve(EXAMPLE, 2), Ok(Answer::from(2286)));
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Grid, Neighborhood};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Cell {
+    Empty,
+    Digit(u32),
+    Symbol(char),
+}
+impl Cell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '0'..='9' => Some(Self::Digit(c.to_digit(10).unwrap())),
+            _ if c.is_ascii_graphic() => Some(Self::Symbol(c)),
+            _ => None,
+        }
+    }
+}
+
+/// A number on the schematic, with the cells its digits cover.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PartNumber {
+    value: u32,
+    cells: Vec<Coordinate>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Schematic {
+    cells: Grid<Cell>,
+    numbers: Vec<PartNumber>,
+}
+impl Schematic {
+    fn read(input: &mut Reader) -> Result<Self, ParseError> {
+        let cells = Grid::read(input, "a digit, a symbol or .", Cell::from_char)?;
+        let mut numbers = Vec::<PartNumber>::new();
+
+        for y in 0..cells.height() {
+            let mut current: Option<PartNumber> = None;
+
+            for (x, cell) in cells.row(y).iter().enumerate() {
+                match (cell, current.as_mut()) {
+                    (Cell::Digit(d), Some(number)) => {
+                        number.value = number.value*10 + d;
+                        number.cells.push(Coordinate(x, y));
+                    },
+                    (Cell::Digit(d), None) => current = Some(PartNumber { value: *d, cells: vec![Coordinate(x, y)] }),
+                    _ => if let Some(number) = current.take() { numbers.push(number); },
+                }
+            }
+
+            if let Some(number) = current.take() { numbers.push(number); }
+        }
+
+        Ok(Self { cells, numbers })
+    }
+    /// The indexes into `numbers` of every number touching `coord`, diagonals included.
+    fn adjacent_numbers(&self, coord: Coordinate) -> HashSet<usize> {
+        let touching: HashSet<Coordinate> = self.cells.neighbors(coord, Neighborhood::Eight).into_iter().collect();
+
+        self.numbers.iter()
+            .enumerate()
+            .filter(|(_, number)| number.cells.iter().any(|x| touching.contains(x)))
+            .map(|(i, _)| i)
+            .collect()
+    }
+    fn symbols(&self) -> impl Iterator<Item=(Coordinate, char)> + '_ {
+        self.cells.iter().filter_map(|(coord, cell)| match cell {
+            Cell::Symbol(c) => Some((coord, *c)),
+            _ => None,
+        })
+    }
+}
+
+pub struct GearRatios;
+impl Solution for GearRatios {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Schematic::read(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(schematic: &Self::Input) -> Answer {
+        let parts: HashSet<usize> = schematic.symbols().flat_map(|(coord, _)| schematic.adjacent_numbers(coord)).collect();
+
+        parts.iter().map(|x| schematic.numbers[*x].value).sum::<u32>().into()
+    }
+    fn part2(schematic: &Self::Input) -> Answer {
+        schematic.symbols()
+            .filter(|(_, c)| *c == '*')
+            .map(|(coord, _)| schematic.adjacent_numbers(coord))
+            .filter(|x| x.len() == 2)
+            .map(|x| x.iter().map(|i| schematic.numbers[*i].value as u64).product::<u64>())
+            .sum::<u64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(GearRatios::solve(EXAMPLE, 1), Ok(Answer::from(4361)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(GearRatios::solve(EXAMPLE, 2), Ok(Answer::from(467835)));
+    }
+}
@@ -0,0 +1,86 @@
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+/// The race sheet as written, since the second part reads its columns as one number each.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Sheet {
+    times: Vec<String>,
+    distances: Vec<String>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+impl Race {
+    /// How many ways of holding the button go further than the record.
+    fn ways_to_win(&self) -> usize {
+        (1..self.time).filter(|hold| hold * (self.time - hold) > self.distance).count()
+    }
+}
+
+/// The numbers on a line like `Time: 7 15 30`.
+fn read_row(line: &Line, label: &str) -> Result<Vec<String>, ParseError> {
+    let (name, numbers) = line.split_once(line.text(), ":", &format!("{}: followed by numbers", label))?;
+
+    if name != label { return Err(line.error(name, label)); }
+
+    let mut row = Vec::<String>::new();
+
+    for number in numbers.split_whitespace() {
+        line.parse::<u64>(number, "a number")?;
+        row.push(number.to_string());
+    }
+
+    if row.len() == 0 { Err(line.end("a number")) }
+    else { Ok(row) }
+}
+
+fn read_sheet(input: &mut Reader) -> Result<Sheet, ParseError> {
+    let times = read_row(&input.expect_line("a line of times")?, "Time")?;
+    let line = input.expect_line("a line of distances")?;
+    let distances = read_row(&line, "Distance")?;
+
+    if distances.len() != times.len() { return Err(line.error(line.text(), &format!("{} distances", times.len()))); }
+
+    Ok(Sheet { times, distances })
+}
+
+pub struct WaitForIt;
+impl Solution for WaitForIt {
+    type Input = Sheet;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_sheet(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(sheet: &Self::Input) -> Answer {
+        sheet.times.iter()
+            .zip(sheet.distances.iter())
+            .map(|(time, distance)| Race { time: time.parse().unwrap(), distance: distance.parse().unwrap() }.ways_to_win())
+            .product::<usize>()
+            .into()
+    }
+    fn part2(sheet: &Self::Input) -> Answer {
+        let race = Race { time: sheet.times.concat().parse().unwrap(), distance: sheet.distances.concat().parse().unwrap() };
+
+        race.ways_to_win().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(WaitForIt::solve(EXAMPLE, 1), Ok(Answer::from(288)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(WaitForIt::solve(EXAMPLE, 2), Ok(Answer::from(71503)));
+    }
+}
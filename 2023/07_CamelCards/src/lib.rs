@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+const CARDS: &str = "23456789TJQKA";
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Hand {
+    cards: Vec<char>,
+    bid: u64,
+}
+impl Hand {
+    /// What kind of hand this is, with jokers standing in for whatever card makes it strongest.
+    fn hand_type(&self, jokers: bool) -> HandType {
+        let mut counts = HashMap::<char, usize>::new();
+
+        for card in &self.cards { *counts.entry(*card).or_insert(0) += 1; }
+
+        let wild = if jokers { counts.remove(&'J').unwrap_or(0) } else { 0 };
+        let mut counts: Vec<usize> = counts.into_values().collect();
+        counts.sort_by(|a,b| b.cmp(a));
+
+        match counts.as_slice() {
+            [] => HandType::FiveOfAKind,
+            [_] => HandType::FiveOfAKind,
+            [a, _] if a+wild == 4 => HandType::FourOfAKind,
+            [_, _] => HandType::FullHouse,
+            [a, _, _] if a+wild == 3 => HandType::ThreeOfAKind,
+            [_, _, _] => HandType::TwoPair,
+            [_, _, _, _] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+    /// Sorts by hand type, then card by card, with jokers the weakest card if they're wild.
+    fn strength(&self, jokers: bool) -> (HandType, Vec<usize>) {
+        let value = |card: &char| if jokers && *card == 'J' { 0 } else { CARDS.find(*card).unwrap() + 1 };
+
+        (self.hand_type(jokers), self.cards.iter().map(value).collect())
+    }
+}
+
+fn read_hands(input: &mut Reader) -> Result<Vec<Hand>, ParseError> {
+    let mut hands = Vec::<Hand>::new();
+
+    while let Some(line) = input.next_line() {
+        let (cards, bid) = line.split_once(line.text(), " ", "a hand like 32T3K 765")?;
+
+        if cards.chars().count() != 5 { return Err(line.error(cards, "five cards")); }
+        if let Some(card) = cards.chars().find(|x| !CARDS.contains(*x)) {
+            return Err(line.error(&card.to_string(), "a card from 2 to 9, T, J, Q, K or A"));
+        }
+
+        hands.push(Hand { cards: cards.chars().collect(), bid: line.parse(bid, "a bid")? });
+    }
+
+    if hands.len() == 0 { Err(input.end("a hand like 32T3K 765")) }
+    else { Ok(hands) }
+}
+
+fn total_winnings(hands: &Vec<Hand>, jokers: bool) -> u64 {
+    let mut ranked: Vec<(HandType, Vec<usize>, u64)> = hands.iter().map(|x| {
+        let (hand_type, values) = x.strength(jokers);
+        (hand_type, values, x.bid)
+    }).collect();
+
+    ranked.sort();
+    ranked.iter().enumerate().map(|(rank, (_, _, bid))| (rank as u64 + 1) * bid).sum()
+}
+
+pub struct CamelCards;
+impl Solution for CamelCards {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_hands(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(hands: &Self::Input) -> Answer {
+        total_winnings(hands, false).into()
+    }
+    fn part2(hands: &Self::Input) -> Answer {
+        total_winnings(hands, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(CamelCards::solve(EXAMPLE, 1), Ok(Answer::from(6440)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(CamelCards::solve(EXAMPLE, 2), Ok(Answer::from(5905)));
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use common::{Answer, Error, Line, ParseError, Reader, Solution};
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Network {
+    directions: Vec<char>,
+    nodes: HashMap<String, (String, String)>,
+}
+impl Network {
+    /// Steps taken from `start` until reaching a node that satisfies `is_end`.
+    fn steps(&self, start: &str, is_end: impl Fn(&str) -> bool) -> usize {
+        let mut node = start;
+        let mut steps = 0usize;
+
+        while !is_end(node) {
+            let (left, right) = &self.nodes[node];
+
+            node = if self.directions[steps % self.directions.len()] == 'L' { left } else { right };
+            steps += 1;
+        }
+
+        steps
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn read_node<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+    let (node, next) = line.split_once(line.text(), " = ", "a node like AAA = (BBB, CCC)")?;
+    let pair = next.strip_prefix('(').and_then(|x| x.strip_suffix(')')).ok_or(line.error(next, "a pair like (BBB, CCC)"))?;
+    let (left, right) = line.split_once(pair, ", ", "a pair like (BBB, CCC)")?;
+
+    Ok((node, left, right))
+}
+
+fn read_network(input: &mut Reader) -> Result<Network, ParseError> {
+    let line = input.expect_line("a line of L and R directions")?;
+    let directions = line.map_chars("L or R", |x| if x == 'L' || x == 'R' { Some(x) } else { None })?;
+    let group = input.next_group().ok_or(input.end("a node like AAA = (BBB, CCC)"))?;
+    let mut nodes = HashMap::<String, (String, String)>::new();
+
+    for line in group.lines() {
+        let (node, left, right) = read_node(&line)?;
+
+        nodes.insert(node.to_string(), (left.to_string(), right.to_string()));
+    }
+
+    // nodes can lead to ones further down, so where they lead is checked once all are read
+    for line in group.lines() {
+        let (_, left, right) = read_node(&line)?;
+
+        if let Some(next) = [left, right].iter().find(|x| !nodes.contains_key(**x)) {
+            return Err(line.error(next, "a node in the network"));
+        }
+    }
+
+    Ok(Network { directions, nodes })
+}
+
+pub struct HauntedWasteland;
+impl Solution for HauntedWasteland {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_network(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(network: &Self::Input) -> Answer {
+        if !network.nodes.contains_key("AAA") { return Answer::None; }
+
+        network.steps("AAA", |x| x == "ZZZ").into()
+    }
+    fn part2(network: &Self::Input) -> Answer {
+        // each ghost's path loops back on itself right after its end node, so they all line
+        // up at the least common multiple of their lengths
+        network.nodes.keys()
+            .filter(|x| x.ends_with('A'))
+            .map(|x| network.steps(x, |x| x.ends_with('Z')))
+            .fold(1, |lcm, x| lcm / gcd(lcm, x) * x)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const GHOST_EXAMPLE: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(HauntedWasteland::solve(EXAMPLE, 1), Ok(Answer::from(6)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(HauntedWasteland::solve(GHOST_EXAMPLE, 2), Ok(Answer::from(6)));
+    }
+}
@@ -0,0 +1,62 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn read_histories(input: &mut Reader) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut histories = Vec::<Vec<i64>>::new();
+
+    while let Some(line) = input.next_line() {
+        let mut history = Vec::<i64>::new();
+
+        for value in line.text().split_whitespace() {
+            history.push(line.parse(value, "a value")?);
+        }
+
+        histories.push(history);
+    }
+
+    if histories.len() == 0 { Err(input.end("a history of values")) }
+    else { Ok(histories) }
+}
+
+/// Extrapolates the value after the end of `history` by taking differences until they're all zero.
+fn extrapolate(history: &Vec<i64>) -> i64 {
+    if history.iter().all(|x| *x == 0) { return 0; }
+
+    let differences: Vec<i64> = history.windows(2).map(|x| x[1]-x[0]).collect();
+
+    history[history.len()-1] + extrapolate(&differences)
+}
+
+pub struct MirageMaintainance;
+impl Solution for MirageMaintainance {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_histories(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(histories: &Self::Input) -> Answer {
+        histories.iter().map(extrapolate).sum::<i64>().into()
+    }
+    fn part2(histories: &Self::Input) -> Answer {
+        // extrapolating backwards is extrapolating forwards through the reversed history
+        histories.iter().map(|x| extrapolate(&x.iter().rev().copied().collect())).sum::<i64>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(MirageMaintainance::solve(EXAMPLE, 1), Ok(Answer::from(114)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(MirageMaintainance::solve(EXAMPLE, 2), Ok(Answer::from(2)));
+    }
+}
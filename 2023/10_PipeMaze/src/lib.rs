@@ -0,0 +1,175 @@
+use common::{Answer, Error, ParseError, Reader, Solution};
+use grid::{Coordinate, Grid};
+
+const NORTH: (isize, isize) = (0,-1);
+const SOUTH: (isize, isize) = (0,1);
+const WEST: (isize, isize) = (-1,0);
+const EAST: (isize, isize) = (1,0);
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    Ground,
+    Start,
+    Pipe((isize, isize), (isize, isize)),
+}
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Ground),
+            'S' => Some(Self::Start),
+            '|' => Some(Self::Pipe(NORTH, SOUTH)),
+            '-' => Some(Self::Pipe(WEST, EAST)),
+            'L' => Some(Self::Pipe(NORTH, EAST)),
+            'J' => Some(Self::Pipe(NORTH, WEST)),
+            '7' => Some(Self::Pipe(SOUTH, WEST)),
+            'F' => Some(Self::Pipe(SOUTH, EAST)),
+            _ => None,
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Self::Ground => '.',
+            Self::Start => 'S',
+            Self::Pipe(NORTH, SOUTH) => '|',
+            Self::Pipe(WEST, EAST) => '-',
+            Self::Pipe(NORTH, EAST) => 'L',
+            Self::Pipe(NORTH, WEST) => 'J',
+            Self::Pipe(SOUTH, WEST) => '7',
+            Self::Pipe(SOUTH, EAST) => 'F',
+            Self::Pipe(_, _) => '?',
+        }
+    }
+    fn connects(&self, direction: (isize, isize)) -> bool {
+        match self {
+            Self::Pipe(a, b) => *a == direction || *b == direction,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Sketch {
+    tiles: Grid<Tile>,
+    /// The tiles making up the loop through the start, in order from the start.
+    main_loop: Vec<Coordinate>,
+}
+impl Sketch {
+    fn read(input: &mut Reader) -> Result<Self, ParseError> {
+        let mut tiles = Grid::read(input, "a pipe, . or S", Tile::from_char)?;
+        let start = tiles.iter().find(|(_, x)| **x == Tile::Start).map(|(c, _)| c).ok_or(input.end("the start marked S"))?;
+
+        // the start is whichever pipe joins up with the two pipes pointing at it
+        let joined: Vec<(isize, isize)> = [NORTH, SOUTH, WEST, EAST].iter().copied()
+            .filter(|&(dx, dy)| tiles.offset(start, dx, dy).map_or(false, |x| tiles[x].connects((-dx, -dy))))
+            .collect();
+
+        if joined.len() != 2 { return Err(input.end("two pipes connected to the start")); }
+
+        tiles[start] = Tile::Pipe(joined[0], joined[1]);
+
+        let main_loop = Self::trace(&tiles, start)?;
+
+        Ok(Self { tiles, main_loop })
+    }
+    /// Follows the pipes from the start back round to it, as long as each one leads to another
+    /// on the map that connects back.
+    fn trace(tiles: &Grid<Tile>, start: Coordinate) -> Result<Vec<Coordinate>, ParseError> {
+        let mut path = vec![start];
+        let mut heading = match tiles[start] { Tile::Pipe(a, _) => a, _ => unreachable!() };
+
+        loop {
+            let current = path[path.len()-1];
+            let error = |at: Coordinate, expected: &str| ParseError::BadToken {
+                line: at.1+1, column: at.0+1, token: tiles[at].to_char().to_string(), expected: expected.to_string(),
+            };
+            let next = tiles.offset(current, heading.0, heading.1).ok_or_else(|| error(current, "a pipe that stays on the map"))?;
+
+            if next == start { break; }
+
+            heading = match tiles[next] {
+                Tile::Pipe(a, b) if a == (-heading.0, -heading.1) => b,
+                Tile::Pipe(a, b) if b == (-heading.0, -heading.1) => a,
+                _ => return Err(error(next, "a pipe continuing the loop")),
+            };
+            path.push(next);
+        }
+
+        Ok(path)
+    }
+}
+
+pub struct PipeMaze;
+impl Solution for PipeMaze {
+    type Input = Sketch;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Sketch::read(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(sketch: &Self::Input) -> Answer {
+        (sketch.main_loop.len() / 2).into()
+    }
+    fn part2(sketch: &Self::Input) -> Answer {
+        let mut on_loop = Grid::new(sketch.tiles.width(), sketch.tiles.height(), false);
+
+        for &coord in &sketch.main_loop { on_loop[coord] = true; }
+
+        // scanning each row, a tile is inside once the loop has been crossed an odd number of
+        // times, counting only the pipes that reach north so a run along the loop counts once
+        let mut enclosed = 0usize;
+
+        for y in 0..sketch.tiles.height() {
+            let mut inside = false;
+
+            for x in 0..sketch.tiles.width() {
+                let coord = Coordinate(x, y);
+
+                if !on_loop[coord] { if inside { enclosed += 1; } }
+                else if sketch.tiles[coord].connects(NORTH) { inside = !inside; }
+            }
+        }
+
+        enclosed.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    const ENCLOSED_EXAMPLE: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(PipeMaze::solve(EXAMPLE, 1), Ok(Answer::from(8)));
+    }
+    #[test]
+    fn part2_example() {
+        assert_eq!(PipeMaze::solve(ENCLOSED_EXAMPLE, 2), Ok(Answer::from(10)));
+    }
+    #[test]
+    fn loops_that_dont_close() {
+        let error = |text: &str| PipeMaze::parse(text).unwrap_err().to_string();
+
+        assert_eq!(error("S-\n|.\nL-\n"), "line 3, column 2: expected a pipe that stays on the map, found \"-\"");
+        assert_eq!(error("..F7.\n.FJ|.\nSJ.L-\n|F--J\nLJ...\n"), "line 3, column 5: expected a pipe continuing the loop, found \"-\"");
+        // the map cut off partway through
+        assert_eq!(error("..F7.\n.FJ|.\nSJ.L7\n|F--J\n"), "line 4, column 1: expected a pipe that stays on the map, found \"|\"");
+    }
+}
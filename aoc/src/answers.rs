@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use common::{Answer, Line, ParseError};
+
+/// What the ledger says about one part's answer.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Verdict {
+    Pass,
+    /// The answer doesn't match the one recorded, which is kept here.
+    Fail(String),
+    /// Nothing is recorded for this part yet.
+    Unknown,
+}
+
+/// The form answers are compared and recorded in: trailing whitespace is dropped from every
+/// line, as are blank lines before and after, so drawn answers survive editors and copy-paste.
+pub fn normalize(answer: &str) -> String {
+    let lines: Vec<&str> = answer.lines().map(|x| x.trim_end()).collect();
+    let start = lines.iter().position(|x| !x.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|x| !x.is_empty()).map_or(start, |x| x+1);
+
+    lines[start..end].join("\n")
+}
+
+/// The known answers for each day's `input.txt`, read from `answers.toml`.
+///
+/// Only the subset of TOML the ledger needs is understood: `[year.day]` tables
+/// holding `part1`/`part2` keys whose values are integers, basic strings or
+/// multi-line literal strings (for the days that draw their answer).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Answers {
+    entries: HashMap<(usize, usize, usize), String>,
+}
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) => Err(format!("couldn't open {}: {}", path.display(), e)),
+        }
+    }
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut entries = HashMap::<(usize, usize, usize), String>::new();
+        let mut table: Option<(usize, usize)> = None;
+        let mut lines = text.lines().enumerate();
+
+        while let Some((index, text)) = lines.next() {
+            let line = Line::new(index+1, text);
+            let trimmed = text.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with("#") { continue; }
+
+            if trimmed.starts_with("[") {
+                if !trimmed.ends_with("]") { return Err(line.error(trimmed, "a [year.day] table header")); }
+
+                let header = trimmed[1..trimmed.len()-1].trim();
+                let (year, day) = line.split_once(header, ".", "a [year.day] table header")?;
+
+                table = Some((line.parse(year, "a year")?, line.parse(day, "a day")?));
+                continue;
+            }
+
+            let (year, day) = table.ok_or(line.error(trimmed, "a [year.day] table header"))?;
+            let (key, value) = line.split_once(trimmed, "=", "part1 = <answer> or part2 = <answer>")?;
+            let (key, value) = (key.trim(), value.trim());
+
+            let part = match key {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(line.error(key, "part1 or part2")),
+            };
+
+            let answer = if let Some(mut rest) = value.strip_prefix("'''") {
+                let mut rows = Vec::<&str>::new();
+                let mut last_line = line.number();
+                // the newline straight after the opening quotes isn't part of the string
+                let skip_first = rest.is_empty();
+
+                loop {
+                    if let Some(end) = rest.find("'''") {
+                        rows.push(&rest[..end]);
+                        break;
+                    }
+
+                    rows.push(rest);
+
+                    let (next_index, next) = lines.next().ok_or(ParseError::unexpected_end(last_line, "the closing '''"))?;
+                    last_line = next_index+1;
+                    rest = next;
+                }
+
+                if skip_first { rows.remove(0); }
+
+                rows.join("\n")
+            }
+            else if value.starts_with("\"") {
+                if value.len() < 2 || !value.ends_with("\"") { return Err(line.error(value, "a quoted string")); }
+
+                unescape(&value[1..value.len()-1]).ok_or(line.error(value, "a string with \\n, \\t, \\\" or \\\\ escapes"))?
+            }
+            else {
+                line.parse::<i128>(value, "an integer or a string")?.to_string()
+            };
+
+            if entries.insert((year, day, part), answer).is_some() {
+                return Err(line.error(key, "each answer to be recorded once"));
+            }
+        }
+
+        Ok(Self { entries })
+    }
+    pub fn get(&self, year: usize, day: usize, part: usize) -> Option<&str> {
+        self.entries.get(&(year, day, part)).map(|x| x.as_str())
+    }
+    /// Compares `answer` to the recorded one in their normalized forms. A part with no answer,
+    /// like the second half of day 25, passes as long as nothing is recorded for it either.
+    pub fn check(&self, year: usize, day: usize, part: usize, answer: &Answer) -> Verdict {
+        match self.get(year, day, part) {
+            Some(expected) if !answer.is_none() && normalize(expected) == normalize(&answer.to_string()) => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None if answer.is_none() => Verdict::Pass,
+            None => Verdict::Unknown,
+        }
+    }
+    /// Records `answer` as the right one for the part, or forgets the part if it has no answer.
+    pub fn record(&mut self, year: usize, day: usize, part: usize, answer: &Answer) {
+        if answer.is_none() { self.entries.remove(&(year, day, part)); }
+        else { self.entries.insert((year, day, part), normalize(&answer.to_string())); }
+    }
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_toml()).map_err(|e| format!("couldn't write {}: {}", path.display(), e))
+    }
+    /// Writes the ledger out in year and day order, in the same subset of TOML that `parse` reads.
+    pub fn to_toml(&self) -> String {
+        let mut days: Vec<(usize, usize)> = self.entries.keys().map(|&(year, day, _)| (year, day)).collect();
+        days.sort();
+        days.dedup();
+
+        let mut tables = Vec::<String>::new();
+
+        for (year, day) in days {
+            let mut table = format!("[{}.{:02}]\n", year, day);
+
+            for part in 1..=2 {
+                if let Some(answer) = self.get(year, day, part) {
+                    table.push_str(&format!("part{} = {}\n", part, to_value(answer)));
+                }
+            }
+
+            tables.push(table);
+        }
+
+        format!("{}\n{}", HEADER, tables.join("\n"))
+    }
+}
+
+const HEADER: &str = "# The answers to each day's input.txt, checked by `aoc verify` and the regression
+# tests in aoc/tests/regression.rs. `aoc verify --record` rewrites this file.
+";
+
+fn to_value(answer: &str) -> String {
+    if answer.parse::<i128>().is_ok_and(|x| x.to_string() == answer) { return answer.to_string(); }
+    if answer.contains('\n') && !answer.contains("'''") { return format!("'''\n{}'''", answer); }
+
+    format!("\"{}\"", escape(answer))
+}
+
+fn escape(value: &str) -> String {
+    let mut result = String::new();
+
+    for c in value.chars() {
+        match c {
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+fn unescape(value: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' { result.push(c); continue; }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            '"' => result.push('"'),
+            '\\' => result.push('\\'),
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tables_and_values() {
+        let answers = Answers::parse("# comment\n[2020.01]\npart1 = 514579\npart2 = \"a\\\"b\"\n\n[2021.13]\npart2 = '''\n##\n# \n'''\n").unwrap();
+
+        assert_eq!(answers.get(2020, 1, 1), Some("514579"));
+        assert_eq!(answers.get(2020, 1, 2), Some("a\"b"));
+        assert_eq!(answers.get(2021, 13, 1), None);
+        assert_eq!(answers.get(2021, 13, 2), Some("##\n# \n"));
+    }
+    #[test]
+    fn multi_line_string_closing_on_last_row() {
+        let answers = Answers::parse("[2021.13]\npart2 = '''\n##\n# '''\n").unwrap();
+
+        assert_eq!(answers.get(2021, 13, 2), Some("##\n# "));
+    }
+    #[test]
+    fn normalized_answers() {
+        assert_eq!(normalize("\n #  \r\n# #\t\n\n"), " #\n# #");
+        assert_eq!(normalize("1067724"), "1067724");
+        assert_eq!(normalize("  \n"), "");
+    }
+    #[test]
+    fn verdicts() {
+        let answers = Answers::parse("[2021.13]\npart1 = 607\npart2 = '''\n##  \n#\n'''\n\n[2021.25]\npart1 = 337\n").unwrap();
+
+        assert_eq!(answers.check(2021, 13, 1, &Answer::from(607)), Verdict::Pass);
+        assert_eq!(answers.check(2021, 13, 1, &Answer::from(608)), Verdict::Fail("607".to_string()));
+        assert_eq!(answers.check(2021, 13, 2, &Answer::from("##\n#   ")), Verdict::Pass);
+        assert_eq!(answers.check(2021, 25, 2, &Answer::None), Verdict::Pass);
+        assert_eq!(answers.check(2021, 25, 1, &Answer::None), Verdict::Fail("337".to_string()));
+        assert_eq!(answers.check(2021, 14, 1, &Answer::from(1)), Verdict::Unknown);
+    }
+    #[test]
+    fn recorded_answers_round_trip() {
+        let mut answers = Answers::parse("[2021.13]\npart1 = 607\n").unwrap();
+
+        answers.record(2021, 13, 2, &Answer::from(" ##\n#  #  \n"));
+        answers.record(2020, 4, 1, &Answer::from("say \"hi\"\\"));
+        answers.record(2020, 4, 2, &Answer::from(-3));
+        answers.record(2021, 13, 1, &Answer::None);
+
+        let toml = answers.to_toml();
+
+        assert!(toml.ends_with("[2020.04]\npart1 = \"say \\\"hi\\\"\\\\\"\npart2 = -3\n\n[2021.13]\npart2 = '''\n ##\n#  #'''\n"));
+        assert_eq!(Answers::parse(&toml), Ok(answers));
+    }
+    #[test]
+    fn rejects_keys_outside_a_table() {
+        assert!(Answers::parse("part1 = 1\n").is_err());
+        assert_eq!(Answers::parse("[2020.01]\npart3 = 1\n").unwrap_err().to_string(), "line 2, column 1: expected part1 or part2, found \"part3\"");
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use common::{Error, Solution};
+
+pub type BenchFn = fn(&str, usize) -> Result<Timings, Error>;
+
+/// The stages of a solution that get timed separately.
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Every sample taken of one day, a `Vec` per stage in the order of `STAGES`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Timings {
+    pub samples: [Vec<Duration>; 3],
+}
+impl Timings {
+    pub fn stats(&self) -> [Stats; 3] {
+        [Stats::new(&self.samples[0]), Stats::new(&self.samples[1]), Stats::new(&self.samples[2])]
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        if sorted.is_empty() { return Self { median: Duration::ZERO, min: Duration::ZERO, max: Duration::ZERO }; }
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 1 { sorted[middle] } else { (sorted[middle-1] + sorted[middle]) / 2 };
+
+        Self { median, min: sorted[0], max: sorted[sorted.len()-1] }
+    }
+}
+
+/// Parses `input` and solves both parts `iterations` times, timing each stage on its own.
+pub fn time<S: Solution>(input: &str, iterations: usize) -> Result<Timings, Error> {
+    let mut samples = [Vec::<Duration>::new(), Vec::<Duration>::new(), Vec::<Duration>::new()];
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        samples[2].push(start.elapsed());
+    }
+
+    Ok(Timings { samples })
+}
+
+/// The header of the results file, one row per day and stage with times in nanoseconds.
+pub const CSV_HEADER: &str = "year,day,name,stage,iterations,median_ns,min_ns,max_ns";
+
+pub fn csv_rows(year: usize, day: usize, name: &str, timings: &Timings) -> String {
+    let mut rows = String::new();
+
+    for (stage, (stats, samples)) in STAGES.iter().zip(timings.stats().iter().zip(timings.samples.iter())) {
+        writeln!(rows, "{},{},{},{},{},{},{},{}", year, day, name, stage, samples.len(),
+                 stats.median.as_nanos(), stats.min.as_nanos(), stats.max.as_nanos()).unwrap();
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration { Duration::from_millis(n) }
+
+    #[test]
+    fn stats_of_samples() {
+        assert_eq!(Stats::new(&[ms(3), ms(1), ms(2)]), Stats { median: ms(2), min: ms(1), max: ms(3) });
+        assert_eq!(Stats::new(&[ms(4), ms(1), ms(2), ms(9)]), Stats { median: ms(3), min: ms(1), max: ms(9) });
+        assert_eq!(Stats::new(&[]).max, Duration::ZERO);
+    }
+    #[test]
+    fn rows_per_stage() {
+        let timings = Timings { samples: [vec![ms(1)], vec![ms(2), ms(4)], vec![]] };
+
+        assert_eq!(csv_rows(2021, 9, "SmokeBasin", &timings),
+                   "2021,9,SmokeBasin,parse,1,1000000,1000000,1000000\n\
+                    2021,9,SmokeBasin,part1,2,3000000,2000000,4000000\n\
+                    2021,9,SmokeBasin,part2,0,0,0,0\n");
+    }
+}
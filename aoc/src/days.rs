@@ -0,0 +1,88 @@
+use common::{Answer, Error, Solution};
+
+use crate::bench::{self, BenchFn};
+
+pub type SolveFn = fn(&str, usize) -> Result<Answer, Error>;
+
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub year: usize,
+    pub day: usize,
+    pub name: &'static str,
+    pub path: &'static str,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+}
+
+pub const DAYS: &[Day] = &[
+    Day { year: 2020, day: 1, name: "ReportRepair", path: "2020/01_ReportRepair", solve: reportrepair::ReportRepair::solve, bench: bench::time::<reportrepair::ReportRepair> },
+    Day { year: 2020, day: 2, name: "PasswordPhilosophy", path: "2020/02_PasswordPhilosophy", solve: passwordphilosophy::PasswordPhilosophy::solve, bench: bench::time::<passwordphilosophy::PasswordPhilosophy> },
+    Day { year: 2020, day: 3, name: "TobogganTrajectory", path: "2020/03_TobogganTrajectory", solve: toboggantrajectory::TobogganTrajectory::solve, bench: bench::time::<toboggantrajectory::TobogganTrajectory> },
+    Day { year: 2020, day: 4, name: "PassportProcessing", path: "2020/04_PassportProcessing", solve: passportprocessing::PassportProcessing::solve, bench: bench::time::<passportprocessing::PassportProcessing> },
+    Day { year: 2020, day: 5, name: "BinaryBoarding", path: "2020/05_BinaryBoarding", solve: binaryboarding::BinaryBoarding::solve, bench: bench::time::<binaryboarding::BinaryBoarding> },
+    Day { year: 2020, day: 6, name: "CustomCustoms", path: "2020/06_CustomCustoms", solve: customcustoms::CustomCustoms::solve, bench: bench::time::<customcustoms::CustomCustoms> },
+    Day { year: 2020, day: 7, name: "HandyHaversacks", path: "2020/07_HandyHaversacks", solve: handyhaversacks::HandyHaversacks::solve, bench: bench::time::<handyhaversacks::HandyHaversacks> },
+    Day { year: 2020, day: 8, name: "HandheldHalting", path: "2020/08_HandheldHalting", solve: handheldhalting::HandheldHalting::solve, bench: bench::time::<handheldhalting::HandheldHalting> },
+    Day { year: 2020, day: 9, name: "EncodingError", path: "2020/09_EncodingError", solve: encodingerror::EncodingError::solve, bench: bench::time::<encodingerror::EncodingError> },
+    Day { year: 2020, day: 10, name: "AdapterArray", path: "2020/10_AdapterArray", solve: adapterarray::AdapterArray::solve, bench: bench::time::<adapterarray::AdapterArray> },
+    Day { year: 2020, day: 11, name: "SeatingSystem", path: "2020/11_SeatingSystem", solve: seatingsystem::SeatingSystem::solve, bench: bench::time::<seatingsystem::SeatingSystem> },
+    Day { year: 2020, day: 12, name: "RainRisk", path: "2020/12_RainRisk", solve: rainrisk::RainRisk::solve, bench: bench::time::<rainrisk::RainRisk> },
+    Day { year: 2020, day: 13, name: "ShuttleSearch", path: "2020/13_ShuttleSearch", solve: shuttlesearch::ShuttleSearch::solve, bench: bench::time::<shuttlesearch::ShuttleSearch> },
+    Day { year: 2020, day: 14, name: "DockingData", path: "2020/14_DockingData", solve: dockingdata::DockingData::solve, bench: bench::time::<dockingdata::DockingData> },
+    Day { year: 2020, day: 15, name: "RambunctiousRecitation", path: "2020/15_RambunctiousRecitation", solve: rambunctiousrecitation::RambunctiousRecitation::solve, bench: bench::time::<rambunctiousrecitation::RambunctiousRecitation> },
+    Day { year: 2020, day: 16, name: "TicketTranslation", path: "2020/16_TicketTranslation", solve: tickettranslation::TicketTranslation::solve, bench: bench::time::<tickettranslation::TicketTranslation> },
+    Day { year: 2020, day: 17, name: "ConwayCubes", path: "2020/17_ConwayCubes", solve: conwaycubes::ConwayCubes::solve, bench: bench::time::<conwaycubes::ConwayCubes> },
+    Day { year: 2020, day: 18, name: "OperationOrder", path: "2020/18_OperationOrder", solve: operationorder::OperationOrder::solve, bench: bench::time::<operationorder::OperationOrder> },
+    Day { year: 2020, day: 19, name: "MonsterMessages", path: "2020/19_MonsterMessages", solve: monstermessages::MonsterMessages::solve, bench: bench::time::<monstermessages::MonsterMessages> },
+    Day { year: 2020, day: 20, name: "JurassicJigsaw", path: "2020/20_JurassicJigsaw", solve: jurassicjigsaw::JurassicJigsaw::solve, bench: bench::time::<jurassicjigsaw::JurassicJigsaw> },
+    Day { year: 2020, day: 21, name: "AllergenAssessment", path: "2020/21_AllergenAssessment", solve: allergenassessment::AllergenAssessment::solve, bench: bench::time::<allergenassessment::AllergenAssessment> },
+    Day { year: 2021, day: 1, name: "SonarSweep", path: "2021/01_SonarSweep", solve: sonarsweep::SonarSweep::solve, bench: bench::time::<sonarsweep::SonarSweep> },
+    Day { year: 2021, day: 2, name: "Dive", path: "2021/02_Dive", solve: dive::Dive::solve, bench: bench::time::<dive::Dive> },
+    Day { year: 2021, day: 3, name: "BinaryDiagnostic", path: "2021/03_BinaryDiagnostic", solve: binarydiagnostic::BinaryDiagnostic::solve, bench: bench::time::<binarydiagnostic::BinaryDiagnostic> },
+    Day { year: 2021, day: 4, name: "GiantSquid", path: "2021/04_GiantSquid", solve: giantsquid::GiantSquid::solve, bench: bench::time::<giantsquid::GiantSquid> },
+    Day { year: 2021, day: 5, name: "HydrothermalVenture", path: "2021/05_HydrothermalVenture", solve: hydrothermalventure::HydrothermalVenture::solve, bench: bench::time::<hydrothermalventure::HydrothermalVenture> },
+    Day { year: 2021, day: 6, name: "Lanternfish", path: "2021/06_Lanternfish", solve: lanternfish::Lanternfish::solve, bench: bench::time::<lanternfish::Lanternfish> },
+    Day { year: 2021, day: 7, name: "TheTreacheryOfWhales", path: "2021/07_TheTreacheryOfWhales", solve: thetreacheryofwhales::TheTreacheryOfWhales::solve, bench: bench::time::<thetreacheryofwhales::TheTreacheryOfWhales> },
+    Day { year: 2021, day: 8, name: "SevenSegmentSearch", path: "2021/08_SevenSegmentSearch", solve: sevensegmentsearch::SevenSegmentSearch::solve, bench: bench::time::<sevensegmentsearch::SevenSegmentSearch> },
+    Day { year: 2021, day: 9, name: "SmokeBasin", path: "2021/09_SmokeBasin", solve: smokebasin::SmokeBasin::solve, bench: bench::time::<smokebasin::SmokeBasin> },
+    Day { year: 2021, day: 10, name: "SyntaxScoring", path: "2021/10_SyntaxScoring", solve: syntaxscoring::SyntaxScoring::solve, bench: bench::time::<syntaxscoring::SyntaxScoring> },
+    Day { year: 2021, day: 11, name: "DumboOctopus", path: "2021/11_DumboOctopus", solve: dumbooctopus::DumboOctopus::solve, bench: bench::time::<dumbooctopus::DumboOctopus> },
+    Day { year: 2021, day: 12, name: "PassagePathing", path: "2021/12_PassagePathing", solve: passagepathing::PassagePathing::solve, bench: bench::time::<passagepathing::PassagePathing> },
+    Day { year: 2021, day: 13, name: "TransparentOrigami", path: "2021/13_TransparentOrigami", solve: transparentorigami::TransparentOrigami::solve, bench: bench::time::<transparentorigami::TransparentOrigami> },
+    Day { year: 2021, day: 14, name: "ExtendedPolymerization", path: "2021/14_ExtendedPolymerization", solve: extendedpolymerization::ExtendedPolymerization::solve, bench: bench::time::<extendedpolymerization::ExtendedPolymerization> },
+    Day { year: 2021, day: 15, name: "Chiton", path: "2021/15_Chiton", solve: chiton::Chiton::solve, bench: bench::time::<chiton::Chiton> },
+    Day { year: 2021, day: 16, name: "PacketDecoder", path: "2021/16_PacketDecoder", solve: packetdecoder::PacketDecoder::solve, bench: bench::time::<packetdecoder::PacketDecoder> },
+    Day { year: 2021, day: 17, name: "TrickShot", path: "2021/17_TrickShot", solve: trickshot::TrickShot::solve, bench: bench::time::<trickshot::TrickShot> },
+    Day { year: 2021, day: 18, name: "Snailfish", path: "2021/18_Snailfish", solve: snailfish::Snailfish::solve, bench: bench::time::<snailfish::Snailfish> },
+    Day { year: 2021, day: 19, name: "BeaconScanner", path: "2021/19_BeaconScanner", solve: beaconscanner::BeaconScanner::solve, bench: bench::time::<beaconscanner::BeaconScanner> },
+    Day { year: 2021, day: 20, name: "TrenchMap", path: "2021/20_TrenchMap", solve: trenchmap::TrenchMap::solve, bench: bench::time::<trenchmap::TrenchMap> },
+    Day { year: 2021, day: 21, name: "DiracDice", path: "2021/21_DiracDice", solve: diracdice::DiracDice::solve, bench: bench::time::<diracdice::DiracDice> },
+    Day { year: 2021, day: 22, name: "ReactorReboot", path: "2021/22_ReactorReboot", solve: reactorreboot::ReactorReboot::solve, bench: bench::time::<reactorreboot::ReactorReboot> },
+    Day { year: 2021, day: 23, name: "Amphipod", path: "2021/23_Amphipod", solve: amphipod::AmphipodBurrow::solve, bench: bench::time::<amphipod::AmphipodBurrow> },
+    Day { year: 2021, day: 24, name: "ArithmeticLogicUnit", path: "2021/24_ArithmeticLogicUnit", solve: arithmeticlogicunit::ArithmeticLogicUnit::solve, bench: bench::time::<arithmeticlogicunit::ArithmeticLogicUnit> },
+    Day { year: 2021, day: 25, name: "SeaCucumber", path: "2021/25_SeaCucumber", solve: seacucumber::SeaCucumber::solve, bench: bench::time::<seacucumber::SeaCucumber> },
+    Day { year: 2022, day: 1, name: "CalorieCounting", path: "2022/01_CalorieCounting", solve: caloriecounting::CalorieCounting::solve, bench: bench::time::<caloriecounting::CalorieCounting> },
+    Day { year: 2022, day: 2, name: "RockPaperScissors", path: "2022/02_RockPaperScissors", solve: rockpaperscissors::RockPaperScissors::solve, bench: bench::time::<rockpaperscissors::RockPaperScissors> },
+    Day { year: 2022, day: 3, name: "RucksackReorganization", path: "2022/03_RucksackReorganization", solve: rucksackreorganization::RucksackReorganization::solve, bench: bench::time::<rucksackreorganization::RucksackReorganization> },
+    Day { year: 2022, day: 4, name: "CampCleanup", path: "2022/04_CampCleanup", solve: campcleanup::CampCleanup::solve, bench: bench::time::<campcleanup::CampCleanup> },
+    Day { year: 2022, day: 5, name: "SupplyStacks", path: "2022/05_SupplyStacks", solve: supplystacks::SupplyStacks::solve, bench: bench::time::<supplystacks::SupplyStacks> },
+    Day { year: 2022, day: 6, name: "TuningTrouble", path: "2022/06_TuningTrouble", solve: tuningtrouble::TuningTrouble::solve, bench: bench::time::<tuningtrouble::TuningTrouble> },
+    Day { year: 2022, day: 7, name: "NoSpaceLeftOnDevice", path: "2022/07_NoSpaceLeftOnDevice", solve: nospaceleftondevice::NoSpaceLeftOnDevice::solve, bench: bench::time::<nospaceleftondevice::NoSpaceLeftOnDevice> },
+    Day { year: 2022, day: 8, name: "TreetopTreeHouse", path: "2022/08_TreetopTreeHouse", solve: treetoptreehouse::TreetopTreeHouse::solve, bench: bench::time::<treetoptreehouse::TreetopTreeHouse> },
+    Day { year: 2022, day: 9, name: "RopeBridge", path: "2022/09_RopeBridge", solve: ropebridge::RopeBridge::solve, bench: bench::time::<ropebridge::RopeBridge> },
+    Day { year: 2022, day: 10, name: "CathodeRayTube", path: "2022/10_Cathode-RayTube", solve: cathoderaytube::CathodeRayTube::solve, bench: bench::time::<cathoderaytube::CathodeRayTube> },
+    Day { year: 2022, day: 11, name: "MonkeyInTheMiddle", path: "2022/11_MonkeyInTheMiddle", solve: monkeyinthemiddle::MonkeyInTheMiddle::solve, bench: bench::time::<monkeyinthemiddle::MonkeyInTheMiddle> },
+    Day { year: 2022, day: 12, name: "HillClimbingAlgorithm", path: "2022/12_HillClimbingAlgorithm", solve: hillclimbingalgorithm::HillClimbingAlgorithm::solve, bench: bench::time::<hillclimbingalgorithm::HillClimbingAlgorithm> },
+    Day { year: 2023, day: 1, name: "Trebuchet", path: "2023/01_Trebuchet", solve: trebuchet::Trebuchet::solve, bench: bench::time::<trebuchet::Trebuchet> },
+    Day { year: 2023, day: 2, name: "CubeConundrum", path: "2023/02_CubeConundrum", solve: cubeconundrum::CubeConundrum::solve, bench: bench::time::<cubeconundrum::CubeConundrum> },
+    Day { year: 2023, day: 3, name: "GearRatios", path: "2023/03_GearRatios", solve: gearratios::GearRatios::solve, bench: bench::time::<gearratios::GearRatios> },
+    Day { year: 2023, day: 6, name: "WaitForIt", path: "2023/06_WaitForIt", solve: waitforit::WaitForIt::solve, bench: bench::time::<waitforit::WaitForIt> },
+    Day { year: 2023, day: 7, name: "CamelCards", path: "2023/07_CamelCards", solve: camelcards::CamelCards::solve, bench: bench::time::<camelcards::CamelCards> },
+    Day { year: 2023, day: 8, name: "HauntedWasteland", path: "2023/08_HauntedWasteland", solve: hauntedwasteland::HauntedWasteland::solve, bench: bench::time::<hauntedwasteland::HauntedWasteland> },
+    Day { year: 2023, day: 9, name: "MirageMaintainance", path: "2023/09_MirageMaintainance", solve: miragemaintainance::MirageMaintainance::solve, bench: bench::time::<miragemaintainance::MirageMaintainance> },
+    Day { year: 2023, day: 10, name: "PipeMaze", path: "2023/10_PipeMaze", solve: pipemaze::PipeMaze::solve, bench: bench::time::<pipemaze::PipeMaze> },
+];
+
+pub fn find_day(year: usize, day: usize) -> Option<&'static Day> {
+    DAYS.iter().find(|x| x.year == year && x.day == day)
+}
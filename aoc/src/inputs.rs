@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::days::Day;
+
+/// Where an imported input for the given day is kept. The cache lives under `inputs/` at the
+/// workspace root and isn't checked in.
+pub fn cache_path(root: &Path, year: usize, day: usize) -> PathBuf {
+    root.join("inputs").join(year.to_string()).join(format!("{:02}.txt", day))
+}
+
+/// Copies `file` into the cache, replacing anything imported for that day before.
+pub fn import(root: &Path, year: usize, day: usize, file: &Path) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) { return Err(format!("bad day: {}", day)); }
+
+    let input = fs::read_to_string(file).map_err(|e| format!("couldn't open {}: {}", file.display(), e))?;
+    let path = cache_path(root, year, day);
+    let parent = path.parent().unwrap();
+
+    fs::create_dir_all(parent).map_err(|e| format!("couldn't create {}: {}", parent.display(), e))?;
+    fs::write(&path, input).map_err(|e| format!("couldn't write {}: {}", path.display(), e))?;
+
+    Ok(path)
+}
+
+/// The input a day runs against by default: the cached one if it was imported, otherwise the
+/// `input.txt` next to the solution.
+pub fn locate(root: &Path, day: &Day) -> PathBuf {
+    let cached = cache_path(root, day.year, day.day);
+
+    if cached.is_file() { cached } else { root.join(day.path).join("input.txt") }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn import_then_locate() {
+        let root = env::temp_dir().join(format!("aoc-inputs-{}", process::id()));
+        let file = root.join("download.txt");
+        let day = crate::days::find_day(2021, 9).unwrap();
+
+        fs::create_dir_all(&root).unwrap();
+        fs::write(&file, "2199943210\n").unwrap();
+
+        assert_eq!(locate(&root, day), root.join("2021/09_SmokeBasin/input.txt"));
+        assert_eq!(import(&root, 2021, 9, &file), Ok(root.join("inputs/2021/09.txt")));
+        assert_eq!(locate(&root, day), root.join("inputs/2021/09.txt"));
+        assert_eq!(fs::read_to_string(root.join("inputs/2021/09.txt")).unwrap(), "2199943210\n");
+        assert!(import(&root, 2021, 26, &file).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,12 @@
+use std::env;
+use std::path::PathBuf;
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod inputs;
+pub mod scaffold;
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
@@ -0,0 +1,337 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+
+use aoc::answers::{Answers, Verdict};
+use aoc::bench;
+use aoc::days::{self, Day};
+use aoc::{inputs, scaffold};
+use aoc::workspace_root;
+
+const USAGE: &str = "usage:
+    aoc list
+    aoc run <year> <day> [--part <1|2>] [--input <path|->]
+    aoc bench [<year> [<day>]] [--iterations <n>] [--output <path, default target/bench.csv>]
+    aoc verify [<year> [<day>]] [--record]
+    aoc new <year> <day> <Name>
+    aoc input import <year> <day> <file>";
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+fn parse_year_day(year: &str, day: &str) -> Result<(usize, usize), String> {
+    let year = year.parse::<usize>().map_err(|_| format!("bad year: {}", year))?;
+    let day = day.parse::<usize>().map_err(|_| format!("bad day: {}", day))?;
+
+    Ok((year, day))
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct RunArgs {
+    year: usize,
+    day: usize,
+    part: Option<usize>,
+    input: Option<String>,
+}
+impl RunArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = Vec::<&String>::new();
+        let mut part = None;
+        let mut input = None;
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let value = iter.next().ok_or("--part needs a value")?;
+
+                    match value.parse::<usize>() {
+                        Ok(p) if p == 1 || p == 2 => part = Some(p),
+                        _ => return Err(format!("bad part: {}", value)),
+                    }
+                },
+                "--input" | "-i" => {
+                    input = Some(iter.next().ok_or("--input needs a value")?.clone());
+                },
+                _ => positional.push(arg),
+            }
+        }
+
+        if positional.len() != 2 { return Err(USAGE.to_string()); }
+
+        let (year, day) = parse_year_day(positional[0], positional[1])?;
+
+        Ok(Self { year, day, part, input })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct BenchArgs {
+    year: Option<usize>,
+    day: Option<usize>,
+    iterations: usize,
+    output: PathBuf,
+}
+impl BenchArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = Vec::<&String>::new();
+        let mut iterations = DEFAULT_ITERATIONS;
+        // under target/ so bench runs don't leave files in the source tree
+        let mut output = workspace_root().join("target").join("bench.csv");
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--iterations" | "-n" => {
+                    let value = iter.next().ok_or("--iterations needs a value")?;
+
+                    match value.parse::<usize>() {
+                        Ok(n) if n > 0 => iterations = n,
+                        _ => return Err(format!("bad iteration count: {}", value)),
+                    }
+                },
+                "--output" | "-o" => {
+                    output = PathBuf::from(iter.next().ok_or("--output needs a value")?);
+                },
+                _ => positional.push(arg),
+            }
+        }
+
+        let (year, day) = parse_selection(&positional)?;
+
+        Ok(Self { year, day, iterations, output })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VerifyArgs {
+    year: Option<usize>,
+    day: Option<usize>,
+    record: bool,
+}
+impl VerifyArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = Vec::<&String>::new();
+        let mut record = false;
+
+        for arg in args {
+            match arg.as_str() {
+                "--record" | "-r" => record = true,
+                _ => positional.push(arg),
+            }
+        }
+
+        let (year, day) = parse_selection(&positional)?;
+
+        Ok(Self { year, day, record })
+    }
+}
+
+/// Reads the optional `[<year> [<day>]]` that narrows down which days a command covers.
+fn parse_selection(positional: &[&String]) -> Result<(Option<usize>, Option<usize>), String> {
+    if positional.len() > 2 { return Err(USAGE.to_string()); }
+
+    let year = match positional.first() {
+        Some(y) => Some(y.parse::<usize>().map_err(|_| format!("bad year: {}", y))?),
+        None => None,
+    };
+    let day = match positional.get(1) {
+        Some(d) => Some(d.parse::<usize>().map_err(|_| format!("bad day: {}", d))?),
+        None => None,
+    };
+
+    Ok((year, day))
+}
+
+fn select_days(year: Option<usize>, day: Option<usize>) -> Result<Vec<&'static Day>, String> {
+    let selected: Vec<&Day> = days::DAYS.iter()
+        .filter(|x| year.is_none_or(|y| x.year == y) && day.is_none_or(|d| x.day == d))
+        .collect();
+
+    if selected.is_empty() {
+        return Err(match (year, day) {
+            (Some(y), Some(d)) => format!("no solution for {} day {}", y, d),
+            (Some(y), None) => format!("no solutions for {}", y),
+            _ => "no solutions".to_string(),
+        });
+    }
+
+    Ok(selected)
+}
+
+fn read_input(day: &Day, input: &Option<String>) -> Result<String, String> {
+    let path = match input {
+        Some(p) if p == "-" => {
+            let mut buffer = String::new();
+            
+            return io::stdin().read_to_string(&mut buffer)
+                .map(|_| buffer)
+                .map_err(|e| format!("couldn't read stdin: {}", e));
+        },
+        Some(p) => PathBuf::from(p),
+        None => inputs::locate(&workspace_root(), day),
+    };
+
+    fs::read_to_string(&path).map_err(|e| format!("couldn't open {}: {}", path.display(), e))
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    let day = days::find_day(args.year, args.day)
+        .ok_or(format!("no solution for {} day {}", args.year, args.day))?;
+
+    let parts: Vec<usize> = match args.part {
+        Some(p) => vec![p],
+        None => vec![1, 2],
+    };
+
+    let input = read_input(day, &args.input)?;
+
+    for part in parts {
+        let answer = (day.solve)(&input, part).map_err(|e| e.to_string())?;
+
+        if answer.is_none() {
+            if args.part.is_some() { return Err(format!("{} day {} has no part {}", day.year, day.day, part)); }
+            continue;
+        }
+
+        println!("{}", answer);
+    }
+
+    Ok(())
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let selected = select_days(args.year, args.day)?;
+    let mut results = format!("{}\n", bench::CSV_HEADER);
+
+    for day in selected {
+        let input = read_input(day, &None)?;
+        let timings = (day.bench)(&input, args.iterations)
+            .map_err(|e| format!("{} day {}: {}", day.year, day.day, e))?;
+
+        for (stage, stats) in bench::STAGES.iter().zip(timings.stats().iter()) {
+            println!("{} {:02} {:<24} {}  median {:>10.3?}  min {:>10.3?}  max {:>10.3?}",
+                     day.year, day.day, day.name, stage, stats.median, stats.min, stats.max);
+        }
+
+        results.push_str(&bench::csv_rows(day.year, day.day, day.name, &timings));
+    }
+
+    if let Some(parent) = args.output.parent().filter(|x| !x.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| format!("couldn't create {}: {}", parent.display(), e))?;
+    }
+
+    fs::write(&args.output, results).map_err(|e| format!("couldn't write {}: {}", args.output.display(), e))?;
+    eprintln!("results written to {}", args.output.display());
+
+    Ok(())
+}
+
+/// Solves every selected day and checks each part against `answers.toml`, or with `--record`
+/// writes what was solved into it.
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let selected = select_days(args.year, args.day)?;
+    let path = workspace_root().join("answers.toml");
+    let mut answers = Answers::load(&path)?;
+    let (mut passed, mut failed, mut unknown) = (0usize, 0usize, 0usize);
+
+    for day in selected {
+        let input = read_input(day, &None)?;
+
+        for part in 1..=2 {
+            let label = format!("{} {:02} {:<24} part{}", day.year, day.day, day.name, part);
+
+            let answer = match (day.solve)(&input, part) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("{}  fail  {}", label, e);
+                    failed += 1;
+                    continue;
+                },
+            };
+
+            if args.record {
+                answers.record(day.year, day.day, part, &answer);
+                continue;
+            }
+
+            match answers.check(day.year, day.day, part, &answer) {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail(expected) => {
+                    println!("{}  fail  expected {}, got {}", label, show(&expected), show(&answer.to_string()));
+                    failed += 1;
+                },
+                Verdict::Unknown => {
+                    println!("{}  unknown  got {}", label, show(&answer.to_string()));
+                    unknown += 1;
+                },
+            }
+        }
+    }
+
+    if args.record {
+        answers.save(&path)?;
+        eprintln!("answers recorded to {}", path.display());
+    }
+    else {
+        println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    }
+
+    if failed > 0 { Err(format!("{} parts failed", failed)) } else { Ok(()) }
+}
+
+/// An answer on one line, with drawn answers continuing on indented lines below it.
+fn show(answer: &str) -> String {
+    if answer.contains('\n') { format!("\n    {}", answer.replace('\n', "\n    ")) } else { answer.to_string() }
+}
+
+fn new(args: &[String]) -> Result<(), String> {
+    if args.len() != 3 { return Err(USAGE.to_string()); }
+
+    let (year, day) = parse_year_day(&args[0], &args[1])?;
+    let directory = scaffold::new_day(&workspace_root(), year, day, &args[2])?;
+
+    println!("created {}", directory.display());
+    println!("its answers are recorded as {:?} until `aoc verify --record` finds them", scaffold::UNSOLVED);
+
+    Ok(())
+}
+
+fn input(args: &[String]) -> Result<(), String> {
+    if args.len() != 4 || args[0] != "import" { return Err(USAGE.to_string()); }
+
+    let (year, day) = parse_year_day(&args[1], &args[2])?;
+    let path = inputs::import(&workspace_root(), year, day, &PathBuf::from(&args[3]))?;
+
+    println!("imported {}", path.display());
+
+    Ok(())
+}
+
+fn list() -> Result<(), String> {
+    for day in days::DAYS {
+        println!("{} {:02} {}", day.year, day.day, day.name);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(|x| x.as_str()) {
+        Some("list") => list(),
+        Some("run") => RunArgs::parse(&args[1..]).and_then(|x| run(&x)),
+        Some("bench") => BenchArgs::parse(&args[1..]).and_then(|x| bench(&x)),
+        Some("verify") => VerifyArgs::parse(&args[1..]).and_then(|x| verify(&x)),
+        Some("new") => new(&args[1..]),
+        Some("input") => input(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("aoc: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,296 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::Answer;
+
+use crate::answers::Answers;
+
+/// What a new day's answers are recorded as until `aoc verify --record` replaces them.
+pub const UNSOLVED: &str = "unsolved";
+
+/// Creates `<year>/<day>_<name>` under `root` with a solution skeleton and an empty
+/// `input.txt`, then registers it with the workspace, the runner and the regression tests. Its
+/// answers are recorded as `UNSOLVED` and its regression test is ignored until it's solved.
+pub fn new_day(root: &Path, year: usize, day: usize, name: &str) -> Result<PathBuf, String> {
+    if year < 2015 { return Err(format!("bad year: {}", year)); }
+    if !(1..=25).contains(&day) { return Err(format!("bad day: {}", day)); }
+    if !is_type_name(name) { return Err(format!("bad name: {} (expected something like SmokeBasin)", name)); }
+
+    let path = day_path(year, day, name);
+    let directory = root.join(&path);
+
+    if directory.exists() { return Err(format!("{} already exists", directory.display())); }
+
+    let workspace = read(&root.join("Cargo.toml"))?;
+    let runner = read(&root.join("aoc/Cargo.toml"))?;
+    let days = read(&root.join("aoc/src/days.rs"))?;
+    let tests = read(&root.join("aoc/tests/regression.rs"))?;
+    let mut answers = Answers::parse(&read(&root.join("answers.toml"))?).map_err(|e| format!("answers.toml: {}", e))?;
+
+    let workspace = add_member(&workspace, year, &path)?;
+    let runner = add_dependency(&runner, &package_name(name), &path)?;
+    let days = add_day(&days, year, day, name, &path)?;
+    let tests = add_regression(&tests, year, day, name)?;
+
+    if answers.get(year, day, 1).is_some() { return Err(format!("answers.toml already has answers for {} day {}", year, day)); }
+
+    for part in 1..=2 { answers.record(year, day, part, &Answer::from(UNSOLVED)); }
+
+    write(&directory.join("Cargo.toml"), &cargo_toml(name))?;
+    write(&directory.join("src/lib.rs"), &lib_rs(name))?;
+    write(&directory.join("input.txt"), "")?;
+    write(&root.join("Cargo.toml"), &workspace)?;
+    write(&root.join("aoc/Cargo.toml"), &runner)?;
+    write(&root.join("aoc/src/days.rs"), &days)?;
+    write(&root.join("aoc/tests/regression.rs"), &tests)?;
+    write(&root.join("answers.toml"), &answers.to_toml())?;
+
+    Ok(directory)
+}
+
+pub fn day_path(year: usize, day: usize, name: &str) -> String {
+    format!("{}/{:02}_{}", year, day, name)
+}
+
+pub fn package_name(name: &str) -> String {
+    name.to_lowercase()
+}
+
+fn is_type_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+
+    for c in name.chars() {
+        if c.is_ascii_uppercase() && !result.is_empty() { result.push('_'); }
+        result.push(c.to_ascii_lowercase());
+    }
+
+    result
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("couldn't open {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("couldn't create {}: {}", parent.display(), e))?;
+    }
+
+    fs::write(path, contents).map_err(|e| format!("couldn't write {}: {}", path.display(), e))
+}
+
+/// Adds the day to the workspace members, unless a `<year>/*` glob already covers it.
+fn add_member(workspace: &str, year: usize, path: &str) -> Result<String, String> {
+    if workspace.contains(&format!("\"{}/*\"", year)) { return Ok(workspace.to_string()); }
+
+    let start = workspace.find("members = [").ok_or("no members list in the workspace Cargo.toml")?;
+    let end = start + workspace[start..].find("\n]").ok_or("unterminated members list in the workspace Cargo.toml")?;
+
+    Ok(format!("{}\n    \"{}\",{}", &workspace[..end], path, &workspace[end..]))
+}
+
+/// Adds the day's crate to the runner's dependencies, after the last one.
+fn add_dependency(runner: &str, package: &str, path: &str) -> Result<String, String> {
+    let line = format!("{} = {{ path = \"../{}\" }}", package, path);
+
+    if runner.lines().any(|x| x.starts_with(&format!("{} = ", package))) {
+        return Err(format!("the runner already depends on {}", package));
+    }
+
+    let start = runner.find("[dependencies]\n").ok_or("no [dependencies] in aoc/Cargo.toml")?;
+    let section = &runner[start..];
+    let end = start + section.find("\n\n").unwrap_or(section.trim_end().len());
+
+    Ok(format!("{}\n{}{}", &runner[..end], line, &runner[end..]))
+}
+
+/// Adds the day to `DAYS`, keeping the table in year and day order.
+fn add_day(days: &str, year: usize, day: usize, name: &str, path: &str) -> Result<String, String> {
+    let package = package_name(name);
+    let entry = format!("    Day {{ year: {}, day: {}, name: \"{}\", path: \"{}\", solve: {}::{}::solve, bench: bench::time::<{}::{}> }},\n",
+                        year, day, name, path, package, name, package, name);
+
+    let start = days.find("pub const DAYS: &[Day] = &[\n").ok_or("no DAYS table in days.rs")?;
+    let mut offset = start + days[start..].find('\n').unwrap() + 1;
+
+    for line in days[offset..].split_inclusive('\n') {
+        let key = line.trim_start()
+            .strip_prefix("Day { year: ")
+            .and_then(|x| x.split_once(", day: "))
+            .and_then(|(y, rest)| Some((y.parse::<usize>().ok()?, rest.split(',').next()?.parse::<usize>().ok()?)));
+
+        match key {
+            Some(key) if key == (year, day) => return Err(format!("{} day {} is already registered", year, day)),
+            Some(key) if key < (year, day) => offset += line.len(),
+            _ => break,
+        }
+    }
+
+    Ok(format!("{}{}{}", &days[..offset], entry, &days[offset..]))
+}
+
+/// Adds an ignored regression test for the day to the `regression!` list, keeping it in year and
+/// day order.
+fn add_regression(tests: &str, year: usize, day: usize, name: &str) -> Result<String, String> {
+    let entry = format!("    #[ignore = \"not solved yet, record its answers with aoc verify --record\"]\n    y{}_{:02}_{}: {}, {};\n",
+                        year, day, snake_case(name), year, day);
+
+    let start = tests.find("regression! {\n").ok_or("no regression! list in aoc/tests/regression.rs")?;
+    let mut offset = start + "regression! {\n".len();
+    // where the entry goes: after the last earlier day, before any attributes of the next one
+    let mut insert = offset;
+
+    for line in tests[offset..].split_inclusive('\n') {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("#[") { offset += line.len(); continue; }
+
+        let key = trimmed.split_once(": ")
+            .and_then(|(_, rest)| rest.strip_suffix(';')?.split_once(", "))
+            .and_then(|(y, d)| Some((y.parse::<usize>().ok()?, d.parse::<usize>().ok()?)));
+
+        match key {
+            Some(key) if key == (year, day) => return Err(format!("{} day {} already has a regression test", year, day)),
+            Some(key) if key < (year, day) => { offset += line.len(); insert = offset; },
+            _ => break,
+        }
+    }
+
+    Ok(format!("{}{}{}", &tests[..insert], entry, &tests[insert..]))
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!("[package]
+name = \"{}\"
+version = \"0.1.0\"
+authors = [\"frank2 <frank2@dc949.org>\"]
+edition = \"2018\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = {{ path = \"../../common\" }}
+
+[lints]
+workspace = true
+", package_name(name))
+}
+
+fn lib_rs(name: &str) -> String {
+    SKELETON.replace("{name}", name).replace("{snake}", &snake_case(name))
+}
+
+const SKELETON: &str = "use common::{Answer, Error, ParseError, Reader, Solution};
+
+fn read_{snake}(input: &mut Reader) -> Result<Vec<String>, ParseError> {
+    let mut lines = Vec::<String>::new();
+
+    while let Some(line) = input.next_line() {
+        lines.push(line.text().to_string());
+    }
+
+    if lines.len() == 0 { Err(input.end(\"a line of input\")) }
+    else { Ok(lines) }
+}
+
+pub struct {name};
+impl Solution for {name} {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_{snake}(&mut Reader::new(input.as_bytes())).map_err(Error::Parse)
+    }
+    fn part1(_: &Self::Input) -> Answer {
+        Answer::None
+    }
+    fn part2(_: &Self::Input) -> Answer {
+        Answer::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = \"\";
+
+    #[test]
+    #[ignore = \"fill in the puzzle's example\"]
+    fn part1_example() {
+        assert_eq!({name}::solve(EXAMPLE, 1), Ok(Answer::from(0)));
+    }
+    #[test]
+    #[ignore = \"fill in the puzzle's example\"]
+    fn part2_example() {
+        assert_eq!({name}::solve(EXAMPLE, 2), Ok(Answer::from(0)));
+    }
+}
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "pub const DAYS: &[Day] = &[
+    Day { year: 2020, day: 1, name: \"ReportRepair\", path: \"2020/01_ReportRepair\", solve: reportrepair::ReportRepair::solve, bench: bench::time::<reportrepair::ReportRepair> },
+    Day { year: 2021, day: 9, name: \"SmokeBasin\", path: \"2021/09_SmokeBasin\", solve: smokebasin::SmokeBasin::solve, bench: bench::time::<smokebasin::SmokeBasin> },
+];
+";
+
+    #[test]
+    fn names() {
+        assert_eq!(day_path(2022, 1, "CalorieCounting"), "2022/01_CalorieCounting");
+        assert_eq!(package_name("CalorieCounting"), "caloriecounting");
+        assert_eq!(snake_case("CalorieCounting"), "calorie_counting");
+        assert!(is_type_name("Dive"));
+        assert!(!is_type_name("dive"));
+        assert!(!is_type_name("Calorie Counting"));
+    }
+    #[test]
+    fn days_stay_in_order() {
+        let days = add_day(DAYS, 2020, 2, "PasswordPhilosophy", "2020/02_PasswordPhilosophy").unwrap();
+        let names: Vec<&str> = days.lines().filter_map(|x| x.split("name: \"").nth(1)).map(|x| x.split('"').next().unwrap()).collect();
+        assert_eq!(names, vec!["ReportRepair", "PasswordPhilosophy", "SmokeBasin"]);
+
+        let days = add_day(DAYS, 2022, 1, "CalorieCounting", "2022/01_CalorieCounting").unwrap();
+        assert!(days.ends_with("bench: bench::time::<caloriecounting::CalorieCounting> },\n];\n"));
+
+        assert_eq!(add_day(DAYS, 2021, 9, "SmokeBasin", "2021/09_SmokeBasin"), Err("2021 day 9 is already registered".to_string()));
+    }
+    #[test]
+    fn regression_tests_stay_in_order() {
+        let tests = "regression! {
+    y2020_01_report_repair: 2020, 1;
+    #[ignore = \"slow\"]
+    y2021_19_beacon_scanner: 2021, 19;
+}
+";
+        let ignore = "    #[ignore = \"not solved yet, record its answers with aoc verify --record\"]\n";
+
+        assert_eq!(add_regression(tests, 2021, 1, "SonarSweep").unwrap(), format!("regression! {{
+    y2020_01_report_repair: 2020, 1;
+{}    y2021_01_sonar_sweep: 2021, 1;
+    #[ignore = \"slow\"]
+    y2021_19_beacon_scanner: 2021, 19;
+}}
+", ignore));
+        assert!(add_regression(tests, 2022, 1, "CalorieCounting").unwrap().ends_with(&format!("{}    y2022_01_calorie_counting: 2022, 1;\n}}\n", ignore)));
+        assert_eq!(add_regression(tests, 2021, 19, "BeaconScanner"), Err("2021 day 19 already has a regression test".to_string()));
+    }
+    #[test]
+    fn members_and_dependencies() {
+        let workspace = "[workspace]\nmembers = [\n    \"aoc\",\n    \"2021/*\",\n]\n";
+        assert_eq!(add_member(workspace, 2021, "2021/26_Extra").unwrap(), workspace);
+        assert_eq!(add_member(workspace, 2022, "2022/01_CalorieCounting").unwrap(),
+                   "[workspace]\nmembers = [\n    \"aoc\",\n    \"2021/*\",\n    \"2022/01_CalorieCounting\",\n]\n");
+
+        let runner = "[dependencies]\ncommon = { path = \"../common\" }\n\n[lints]\nworkspace = true\n";
+        assert_eq!(add_dependency(runner, "caloriecounting", "2022/01_CalorieCounting").unwrap(),
+                   "[dependencies]\ncommon = { path = \"../common\" }\ncaloriecounting = { path = \"../2022/01_CalorieCounting\" }\n\n[lints]\nworkspace = true\n");
+        assert!(add_dependency(runner, "common", "common").is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use std::fs;
+
+use aoc::answers::{Answers, Verdict};
+use aoc::days;
+use aoc::workspace_root;
+
+fn check(year: usize, day: usize) {
+    let day = days::find_day(year, day).expect("day isn't registered");
+    let answers = Answers::load(&workspace_root().join("answers.toml")).unwrap();
+    let input = fs::read_to_string(workspace_root().join(day.path).join("input.txt")).unwrap();
+
+    for part in 1..=2 {
+        let answer = (day.solve)(&input, part).unwrap();
+
+        match answers.check(day.year, day.day, part, &answer) {
+            Verdict::Pass => (),
+            Verdict::Fail(expected) => assert_eq!(answer.to_string(), expected, "{} day {} part {}", day.year, day.day, part),
+            Verdict::Unknown => panic!("{} day {} part {} has no recorded answer", day.year, day.day, part),
+        }
+    }
+}
+
+macro_rules! regression {
+    ($($(#[$attr:meta])* $name:ident: $year:expr, $day:expr;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() { check($year, $day); }
+        )*
+    }
+}
+
+regression! {
+    y2020_01_report_repair: 2020, 1;
+    y2020_02_password_philosophy: 2020, 2;
+    y2020_03_toboggan_trajectory: 2020, 3;
+    y2020_04_passport_processing: 2020, 4;
+    y2020_05_binary_boarding: 2020, 5;
+    y2020_06_custom_customs: 2020, 6;
+    y2020_07_handy_haversacks: 2020, 7;
+    y2020_08_handheld_halting: 2020, 8;
+    y2020_09_encoding_error: 2020, 9;
+    y2020_10_adapter_array: 2020, 10;
+    y2020_11_seating_system: 2020, 11;
+    y2020_12_rain_risk: 2020, 12;
+    y2020_13_shuttle_search: 2020, 13;
+    y2020_14_docking_data: 2020, 14;
+    y2020_15_rambunctious_recitation: 2020, 15;
+    y2020_16_ticket_translation: 2020, 16;
+    y2020_17_conway_cubes: 2020, 17;
+    y2020_18_operation_order: 2020, 18;
+    y2020_19_monster_messages: 2020, 19;
+    y2020_20_jurassic_jigsaw: 2020, 20;
+    y2020_21_allergen_assessment: 2020, 21;
+    y2021_01_sonar_sweep: 2021, 1;
+    y2021_02_dive: 2021, 2;
+    y2021_03_binary_diagnostic: 2021, 3;
+    y2021_04_giant_squid: 2021, 4;
+    y2021_05_hydrothermal_venture: 2021, 5;
+    y2021_06_lanternfish: 2021, 6;
+    y2021_07_treachery_of_whales: 2021, 7;
+    y2021_08_seven_segment_search: 2021, 8;
+    y2021_09_smoke_basin: 2021, 9;
+    y2021_10_syntax_scoring: 2021, 10;
+    y2021_11_dumbo_octopus: 2021, 11;
+    y2021_12_passage_pathing: 2021, 12;
+    y2021_13_transparent_origami: 2021, 13;
+    y2021_14_extended_polymerization: 2021, 14;
+    y2021_15_chiton: 2021, 15;
+    y2021_16_packet_decoder: 2021, 16;
+    y2021_17_trick_shot: 2021, 17;
+    y2021_18_snailfish: 2021, 18;
+    #[ignore = "takes minutes, run with --ignored"]
+    y2021_19_beacon_scanner: 2021, 19;
+    y2021_20_trench_map: 2021, 20;
+    y2021_21_dirac_dice: 2021, 21;
+    y2021_22_reactor_reboot: 2021, 22;
+    #[ignore = "takes minutes, run with --ignored"]
+    y2021_23_amphipod: 2021, 23;
+    y2021_24_arithmetic_logic_unit: 2021, 24;
+    y2021_25_sea_cucumber: 2021, 25;
+    y2022_01_calorie_counting: 2022, 1;
+    y2022_02_rock_paper_scissors: 2022, 2;
+    y2022_03_rucksack_reorganization: 2022, 3;
+    y2022_04_camp_cleanup: 2022, 4;
+    y2022_05_supply_stacks: 2022, 5;
+    y2022_06_tuning_trouble: 2022, 6;
+    y2022_07_no_space_left_on_device: 2022, 7;
+    y2022_08_treetop_tree_house: 2022, 8;
+    y2022_09_rope_bridge: 2022, 9;
+    y2022_10_cathode_ray_tube: 2022, 10;
+    y2022_11_monkey_in_the_middle: 2022, 11;
+    y2022_12_hill_climbing_algorithm: 2022, 12;
+    y2023_01_trebuchet: 2023, 1;
+    y2023_02_cube_conundrum: 2023, 2;
+    y2023_03_gear_ratios: 2023, 3;
+    y2023_06_wait_for_it: 2023, 6;
+    y2023_07_camel_cards: 2023, 7;
+    y2023_08_haunted_wasteland: 2023, 8;
+    y2023_09_mirage_maintainance: 2023, 9;
+    y2023_10_pipe_maze: 2023, 10;
+}
+
+#[test]
+fn every_day_is_covered() {
+    let answers = Answers::load(&workspace_root().join("answers.toml")).unwrap();
+
+    for day in days::DAYS {
+        assert!(answers.get(day.year, day.day, 1).is_some(), "{} day {} has no recorded answers", day.year, day.day);
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+/// The result of solving one part of a puzzle.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The puzzle has no such part, e.g. the second half of day 25.
+    None,
+}
+impl Answer {
+    pub fn is_none(&self) -> bool {
+        *self == Self::None
+    }
+}
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+            Self::None => Ok(()),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self { Self::Number(n as i128) }
+            }
+        )*
+    }
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self { Self::Text(s) }
+}
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self { Self::Text(s.to_string()) }
+}
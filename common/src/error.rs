@@ -0,0 +1,25 @@
+use std::fmt;
+
+use crate::ParseError;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Error {
+    /// The puzzle input couldn't be parsed.
+    Parse(ParseError),
+    /// Puzzles only have a part 1 and a part 2.
+    BadPart(usize),
+}
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse(error) => write!(f, "{}", error),
+            Self::BadPart(part) => write!(f, "bad part: {}", part),
+        }
+    }
+}
+impl std::error::Error for Error {}
@@ -0,0 +1,210 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use crate::{Line, ParseError};
+
+/// Reads puzzle input a line at a time from a file, stdin or a string, keeping count of line
+/// numbers for errors. Line endings (`\n` or `\r\n`) and trailing whitespace are stripped, and a
+/// line is blank if nothing is left. A read that fails ends the input there, and the failure
+/// is what `end`, `expect_line` and `finish` report rather than the input running out.
+pub struct Reader<'a> {
+    input: Box<dyn BufRead + 'a>,
+    buffer: String,
+    line_number: usize,
+    /// A blank line that ended a group or grid, left to be read again.
+    pending_blank: bool,
+    /// Why reading stopped before the end of the input, if it did.
+    failure: Option<ParseError>,
+}
+impl<'a> Reader<'a> {
+    /// Reads from anything buffered, e.g. `text.as_bytes()` for input already in memory.
+    pub fn new<R: BufRead + 'a>(input: R) -> Self {
+        Self { input: Box::new(input), buffer: String::new(), line_number: 0, pending_blank: false, failure: None }
+    }
+    pub fn open(path: &Path) -> io::Result<Reader<'static>> {
+        Ok(Reader::new(BufReader::new(File::open(path)?)))
+    }
+    pub fn stdin() -> Reader<'static> {
+        Reader::new(BufReader::new(io::stdin()))
+    }
+    /// The number of the last line read, 0 before the first.
+    pub fn line_number(&self) -> usize { self.line_number }
+    /// The error for input that ran out while `expected` was still to come, or for the read
+    /// that failed if that's why it ran out.
+    pub fn end(&self, expected: &str) -> ParseError {
+        match &self.failure {
+            Some(failure) => failure.clone(),
+            None => ParseError::unexpected_end(self.line_number+1, expected),
+        }
+    }
+    /// Checks that nothing went wrong reading the input, for callers that read until it ends.
+    pub fn finish(&self) -> Result<(), ParseError> {
+        match &self.failure {
+            Some(failure) => Err(failure.clone()),
+            None => Ok(()),
+        }
+    }
+    /// The next line whether or not it's blank, or `None` at the end of the input.
+    pub fn next_raw_line(&mut self) -> Option<Line<'_>> {
+        if self.pending_blank {
+            self.pending_blank = false;
+            return Some(Line::new(self.line_number, ""));
+        }
+
+        self.buffer.clear();
+
+        if self.failure.is_some() { return None; }
+
+        match self.input.read_line(&mut self.buffer) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.line_number += 1;
+                Some(Line::new(self.line_number, self.buffer.trim_end()))
+            },
+            Err(error) => {
+                self.failure = Some(ParseError::unreadable(self.line_number+1, &error.to_string()));
+                None
+            },
+        }
+    }
+    /// The next line that isn't blank, or `None` at the end of the input.
+    pub fn next_line(&mut self) -> Option<Line<'_>> {
+        loop {
+            let blank = self.next_raw_line()?.text().is_empty();
+
+            if !blank { return Some(Line::new(self.line_number, self.buffer.trim_end())); }
+        }
+    }
+    /// Like `next_line`, but running out of input is an error: `expected` says what was due.
+    pub fn expect_line(&mut self, expected: &str) -> Result<Line<'_>, ParseError> {
+        if self.next_line().is_none() { return Err(self.end(expected)); }
+
+        Ok(Line::new(self.line_number, self.buffer.trim_end()))
+    }
+    /// The next run of lines up to a blank line or the end of the input, skipping blank lines
+    /// before it, or `None` once there are no more.
+    pub fn next_group(&mut self) -> Option<Group> {
+        let first = self.next_line()?;
+        let mut lines = vec![(first.number(), first.text().to_string())];
+
+        while let Some(line) = self.next_raw_line() {
+            if line.text().is_empty() { break; }
+
+            lines.push((line.number(), line.text().to_string()));
+        }
+
+        Some(Group { lines })
+    }
+    /// Reads a block of one character per cell, from the next line that isn't blank up to a
+    /// blank line or the end of the input. `expected` describes a valid cell for the error
+    /// when `from_char` rejects one; rows must all be the same width.
+    pub fn grid<T, F: Fn(char) -> Option<T>>(&mut self, expected: &str, from_char: F) -> Result<Vec<Vec<T>>, ParseError> {
+        let mut rows = Vec::<Vec<T>>::new();
+
+        if let Some(first) = self.next_line() {
+            rows.push(first.map_chars(expected, &from_char)?);
+        }
+
+        while let Some(line) = self.next_raw_line() {
+            if line.text().is_empty() {
+                self.pending_blank = true;
+                break;
+            }
+
+            let row = line.map_chars(expected, &from_char)?;
+
+            if row.len() != rows[0].len() {
+                return Err(line.error(line.text(), &format!("a row {} wide", rows[0].len())));
+            }
+
+            rows.push(row);
+        }
+
+        self.finish()?;
+
+        if rows.is_empty() { Err(self.end("a row of the grid")) } else { Ok(rows) }
+    }
+}
+
+/// Consecutive non-blank lines of input, e.g. one passport or one tile.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Group {
+    lines: Vec<(usize, String)>,
+}
+impl Group {
+    pub fn lines(&self) -> impl Iterator<Item=Line<'_>> {
+        self.lines.iter().map(|(number, text)| Line::new(*number, text))
+    }
+    pub fn first(&self) -> Line<'_> {
+        let (number, text) = &self.lines[0];
+        Line::new(*number, text)
+    }
+    pub fn last(&self) -> Line<'_> {
+        let (number, text) = &self.lines[self.lines.len()-1];
+        Line::new(*number, text)
+    }
+    pub fn len(&self) -> usize { self.lines.len() }
+    pub fn is_empty(&self) -> bool { self.lines.is_empty() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_line_endings_and_trailing_whitespace() {
+        let mut reader = Reader::new("a  \r\n\r\n \t\nb\n".as_bytes());
+
+        assert_eq!(reader.next_line(), Some(Line::new(1, "a")));
+        assert_eq!(reader.next_line(), Some(Line::new(4, "b")));
+        assert_eq!(reader.next_line(), None);
+        assert_eq!(reader.expect_line("c").unwrap_err().to_string(), "line 5: input ended, expected c");
+
+        let mut reader = Reader::new("a\r\n\r\nb".as_bytes());
+
+        assert_eq!(reader.next_raw_line(), Some(Line::new(1, "a")));
+        assert_eq!(reader.next_raw_line(), Some(Line::new(2, "")));
+        assert_eq!(reader.next_raw_line(), Some(Line::new(3, "b")));
+        assert_eq!(reader.next_raw_line(), None);
+    }
+    #[test]
+    fn unreadable_input() {
+        let mut reader = Reader::new(&b"a\n\xff\nb\n"[..]);
+
+        assert_eq!(reader.next_line(), Some(Line::new(1, "a")));
+        assert_eq!(reader.next_line(), None);
+        assert_eq!(reader.next_line(), None);
+        assert_eq!(reader.expect_line("b").unwrap_err().to_string(), "line 2: couldn't read the input: stream did not contain valid UTF-8");
+        assert_eq!(reader.finish(), Err(ParseError::unreadable(2, "stream did not contain valid UTF-8")));
+        assert_eq!(Reader::new("a\n".as_bytes()).finish(), Ok(()));
+    }
+    #[test]
+    fn groups() {
+        let mut reader = Reader::new("\nab\nac\r\n  \r\n\nb\n\n".as_bytes());
+
+        let group = reader.next_group().unwrap();
+        assert_eq!(group.lines().collect::<Vec<_>>(), vec![Line::new(2, "ab"), Line::new(3, "ac")]);
+        assert_eq!(group.last(), Line::new(3, "ac"));
+
+        let group = reader.next_group().unwrap();
+        assert_eq!((group.len(), group.first()), (1, Line::new(6, "b")));
+        assert_eq!(reader.next_group(), None);
+    }
+    #[test]
+    fn grids() {
+        let digits = |c: char| c.to_digit(10);
+        let mut reader = Reader::new("12\r\n34\n\n56\n".as_bytes());
+
+        assert_eq!(reader.grid("a digit", digits), Ok(vec![vec![1, 2], vec![3, 4]]));
+        assert_eq!(reader.next_raw_line(), Some(Line::new(3, "")));
+        assert_eq!(reader.grid("a digit", digits), Ok(vec![vec![5, 6]]));
+        assert_eq!(reader.grid("a digit", digits).unwrap_err().to_string(), "line 5: input ended, expected a row of the grid");
+
+        let mut reader = Reader::new("12\n3x\n".as_bytes());
+        assert_eq!(reader.grid("a digit", digits).unwrap_err().to_string(), "line 2, column 2: expected a digit, found \"x\"");
+
+        let mut reader = Reader::new("12\n3\n".as_bytes());
+        assert_eq!(reader.grid("a digit", digits).unwrap_err().to_string(), "line 2, column 1: expected a row 2 wide, found \"3\"");
+    }
+}
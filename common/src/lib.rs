@@ -0,0 +1,11 @@
+mod answer;
+mod error;
+mod input;
+mod parse;
+mod solution;
+
+pub use answer::Answer;
+pub use error::Error;
+pub use input::{Group, Reader};
+pub use parse::{Line, ParseError};
+pub use solution::Solution;
@@ -0,0 +1,135 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Why a puzzle input couldn't be read. Lines and columns count from 1.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ParseError {
+    /// `token` doesn't have the `expected` form.
+    BadToken { line: usize, column: usize, token: String, expected: String },
+    /// The input stopped at `line` while `expected` was still to come.
+    UnexpectedEnd { line: usize, expected: String },
+    /// Reading `line` failed, for the given `reason`, e.g. an I/O error or invalid UTF-8.
+    Unreadable { line: usize, reason: String },
+}
+impl ParseError {
+    pub fn unexpected_end(line: usize, expected: &str) -> Self {
+        Self::UnexpectedEnd { line, expected: expected.to_string() }
+    }
+    pub fn unreadable(line: usize, reason: &str) -> Self {
+        Self::Unreadable { line, reason: reason.to_string() }
+    }
+    pub fn line(&self) -> usize {
+        match self {
+            Self::BadToken { line, .. } => *line,
+            Self::UnexpectedEnd { line, .. } => *line,
+            Self::Unreadable { line, .. } => *line,
+        }
+    }
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BadToken { line, column, token, expected } =>
+                write!(f, "line {}, column {}: expected {}, found {:?}", line, column, expected, token),
+            Self::UnexpectedEnd { line, expected } =>
+                write!(f, "line {}: input ended, expected {}", line, expected),
+            Self::Unreadable { line, reason } =>
+                write!(f, "line {}: couldn't read the input: {}", line, reason),
+        }
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// A line of puzzle input, used to point a [`ParseError`] at the token that caused it.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Line<'a> {
+    number: usize,
+    text: &'a str,
+}
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+    pub fn number(&self) -> usize { self.number }
+    pub fn text(&self) -> &'a str { self.text }
+    /// Where `token` starts on this line. Tokens sliced out of the line's text are
+    /// located exactly, anything else by its first occurrence.
+    pub fn column(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = token.as_ptr() as usize;
+
+        if offset >= start && offset + token.len() <= start + self.text.len() {
+            return offset - start + 1;
+        }
+
+        self.text.find(token).map(|x| x+1).unwrap_or(1)
+    }
+    pub fn error(&self, token: &str, expected: &str) -> ParseError {
+        ParseError::BadToken {
+            line: self.number,
+            column: self.column(token),
+            token: token.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+    /// Reads `token` as a `T`, or reports it as not being the `expected` form.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse::<T>().map_err(|_| self.error(token, expected))
+    }
+    /// Splits `token` in two around the first `separator`, or reports it as not being the `expected` form.
+    pub fn split_once(&self, token: &'a str, separator: &str, expected: &str) -> Result<(&'a str, &'a str), ParseError> {
+        token.split_once(separator).ok_or(self.error(token, expected))
+    }
+    /// Maps each character of the line through `f`, reporting the first one it rejects
+    /// as not being the `expected` form.
+    pub fn map_chars<T, F: Fn(char) -> Option<T>>(&self, expected: &str, f: F) -> Result<Vec<T>, ParseError> {
+        let mut result = Vec::<T>::new();
+
+        for (i, c) in self.text.char_indices() {
+            match f(c) {
+                Some(value) => result.push(value),
+                None => return Err(self.error(&self.text[i..i+c.len_utf8()], expected)),
+            }
+        }
+
+        Ok(result)
+    }
+    pub fn end(&self, expected: &str) -> ParseError {
+        ParseError::unexpected_end(self.number, expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_of_slices() {
+        let text = "1-3 a: abcde";
+        let line = Line::new(4, text);
+
+        assert_eq!(line.column(&text[4..5]), 5);
+        assert_eq!(line.column("abcde"), 8);
+        assert_eq!(line.column("zzz"), 1);
+    }
+    #[test]
+    fn reports_bad_tokens() {
+        let text = "mem[8] = eleven";
+        let line = Line::new(2, text);
+        let error = line.parse::<usize>(&text[9..], "a number").unwrap_err();
+
+        assert_eq!(error, ParseError::BadToken { line: 2, column: 10, token: "eleven".to_string(), expected: "a number".to_string() });
+        assert_eq!(error.to_string(), "line 2, column 10: expected a number, found \"eleven\"");
+    }
+    #[test]
+    fn reports_bad_characters() {
+        let line = Line::new(1, "..#x.");
+        let error = line.map_chars("'#' or '.'", |c| if c == '#' || c == '.' { Some(c) } else { None }).unwrap_err();
+
+        assert_eq!(error.to_string(), "line 1, column 4: expected '#' or '.', found \"x\"");
+    }
+    #[test]
+    fn reports_truncation() {
+        assert_eq!(ParseError::unexpected_end(7, "a ticket").to_string(), "line 7: input ended, expected a ticket");
+    }
+}
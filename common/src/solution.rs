@@ -0,0 +1,20 @@
+use crate::{Answer, Error};
+
+/// A day's puzzle: parse the input once, then solve either part from it.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Parses `input` and solves the given part (1 or 2) in one go.
+    fn solve(input: &str, part: usize) -> Result<Answer, Error> {
+        if part != 1 && part != 2 { return Err(Error::BadPart(part)); }
+
+        let parsed = Self::parse(input)?;
+
+        if part == 1 { Ok(Self::part1(&parsed)) }
+        else { Ok(Self::part2(&parsed)) }
+    }
+}
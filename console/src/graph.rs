@@ -0,0 +1,179 @@
+use super::{Address, Instruction, InstructionSet, Registers};
+
+/// Where each instruction sends execution next, and which instructions lead to the end of the
+/// program from there. Instructions are assumed to move the same way whatever the registers
+/// hold, as the console's own do; ones added with `with_branch` are followed as if every register
+/// were 0.
+#[derive(Clone, Debug)]
+pub struct ControlFlowGraph<'a> {
+    set: &'a InstructionSet,
+    program: &'a [Instruction],
+    successors: Vec<isize>,
+    reaches_end: Vec<bool>,
+}
+impl<'a> ControlFlowGraph<'a> {
+    pub fn new(set: &'a InstructionSet, program: &'a [Instruction]) -> Self {
+        let successors: Vec<isize> = (0..program.len()).map(|x| successor(set, x, program[x])).collect();
+        let mut predecessors = vec![Vec::<Address>::new(); program.len()+1];
+
+        for (address, &next) in successors.iter().enumerate() {
+            if next >= 0 && next <= program.len() as isize { predecessors[next as Address].push(address); }
+        }
+
+        // walk backwards from the end, the address just past the last instruction
+        let mut reaches_end = vec![false; program.len()+1];
+        let mut stack = vec![program.len()];
+
+        reaches_end[program.len()] = true;
+
+        while let Some(address) = stack.pop() {
+            for &from in &predecessors[address] {
+                if !reaches_end[from] {
+                    reaches_end[from] = true;
+                    stack.push(from);
+                }
+            }
+        }
+
+        Self { set, program, successors, reaches_end }
+    }
+    /// The address execution moves to after `address`, which may be outside the program.
+    pub fn successor(&self, address: Address) -> isize {
+        self.successors[address]
+    }
+    /// Whether execution starting at `address` terminates.
+    pub fn reaches_end(&self, address: Address) -> bool {
+        self.reaches_end.get(address).copied().unwrap_or(false)
+    }
+    /// The single instruction to replace so the program terminates, found by following it from
+    /// the start and trying each swap of one opcode for another, named as `(from, to)` pairs,
+    /// on the way. A swap that lands somewhere that reaches the end is the answer, since nothing
+    /// the program runs before the swap can reach the end itself.
+    pub fn terminating_patch(&self, swaps: &[(&str, &str)]) -> Option<(Address, Instruction)> {
+        let swaps: Vec<(usize, usize)> = swaps.iter()
+            .filter_map(|&(from, to)| Some((self.set.opcode(from)?, self.set.opcode(to)?)))
+            .collect();
+        let mut visited = vec![false; self.program.len()];
+        let mut address = 0isize;
+
+        while address >= 0 && (address as Address) < self.program.len() && !visited[address as Address] {
+            let here = address as Address;
+
+            visited[here] = true;
+
+            for &(from, to) in &swaps {
+                if self.program[here].opcode != from { continue; }
+
+                let patched = Instruction { opcode: to, argument: self.program[here].argument };
+                let next = successor(self.set, here, patched);
+
+                if next >= 0 && self.reaches_end(next as Address) { return Some((here, patched)); }
+            }
+
+            address = self.successors[here];
+        }
+
+        None
+    }
+    /// The graph in Graphviz's DOT language, with instructions that reach the end drawn bold.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph program {\n");
+        let mut out_of_bounds = false;
+
+        for (address, instruction) in self.program.iter().enumerate() {
+            let style = if self.reaches_end[address] { ", style=bold" } else { "" };
+
+            dot += &format!("    {} [label=\"{}: {}\"{}];\n", address, address, instruction.to_string(self.set), style);
+        }
+
+        dot += "    end [label=\"end\", shape=doublecircle, style=bold];\n";
+
+        for (address, &next) in self.successors.iter().enumerate() {
+            if next == self.program.len() as isize { dot += &format!("    {} -> end;\n", address); }
+            else if next < 0 || next > self.program.len() as isize {
+                dot += &format!("    {} -> out;\n", address);
+                out_of_bounds = true;
+            }
+            else { dot += &format!("    {} -> {};\n", address, next); }
+        }
+
+        if out_of_bounds { dot += "    out [label=\"out of bounds\", shape=box];\n"; }
+
+        dot += "}\n";
+        dot
+    }
+}
+
+fn successor(set: &InstructionSet, address: Address, instruction: Instruction) -> isize {
+    let offset = (set.operation(instruction.opcode).execute)(&mut Registers::default(), instruction.argument);
+
+    address as isize + offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{Machine, Outcome};
+    use common::Line;
+
+    const SWAPS: [(&str, &str); 2] = [("nop", "jmp"), ("jmp", "nop")];
+
+    fn program(set: &InstructionSet, text: &str) -> Vec<Instruction> {
+        text.lines().enumerate().map(|(i, x)| set.read(&Line::new(i+1, x)).unwrap()).collect()
+    }
+
+    #[test]
+    fn example_patch() {
+        let set = InstructionSet::handheld();
+        let mut program = program(&set, "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6");
+        let graph = ControlFlowGraph::new(&set, &program);
+
+        assert_eq!((0..program.len()).filter(|&x| graph.reaches_end(x)).collect::<Vec<Address>>(), vec![8]);
+
+        let (address, patched) = graph.terminating_patch(&SWAPS).unwrap();
+
+        assert_eq!((address, patched.to_string(&set)), (7, "nop -4".to_string()));
+
+        program[address] = patched;
+
+        let mut machine = Machine::new(&set, &program);
+
+        assert_eq!(machine.run(), Outcome::Terminated);
+        assert_eq!(machine.registers().accumulator, 8);
+    }
+    #[test]
+    fn long_programs() {
+        // one long loop, which only the jump back to the start can be patched out of
+        let set = InstructionSet::handheld();
+        let mut text = String::new();
+
+        for _ in 0..100_000 { text += "acc +1\njmp +2\nnop +0\n"; }
+
+        text += "jmp -300000\n";
+
+        let program = program(&set, &text);
+        let graph = ControlFlowGraph::new(&set, &program);
+
+        assert_eq!(graph.terminating_patch(&SWAPS).map(|x| x.0), Some(300_000));
+        assert_eq!(graph.terminating_patch(&[("acc", "nop")]), None);
+    }
+    #[test]
+    fn dot() {
+        let set = InstructionSet::handheld();
+        let program = program(&set, "acc +2\njmp -2\njmp -1\nnop +0");
+
+        assert_eq!(ControlFlowGraph::new(&set, &program).to_dot(), "digraph program {
+    0 [label=\"0: acc +2\"];
+    1 [label=\"1: jmp -2\"];
+    2 [label=\"2: jmp -1\"];
+    3 [label=\"3: nop +0\", style=bold];
+    end [label=\"end\", shape=doublecircle, style=bold];
+    0 -> 1;
+    1 -> out;
+    2 -> 1;
+    3 -> end;
+    out [label=\"out of bounds\", shape=box];
+}
+");
+    }
+}
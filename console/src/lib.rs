@@ -0,0 +1,262 @@
+//! The handheld game console's boot code machine, with its instruction set kept in a table so
+//! puzzles that add instructions of their own can run on it too.
+
+use std::collections::HashMap;
+use common::{Line, ParseError};
+
+mod graph;
+pub use graph::ControlFlowGraph;
+
+pub type Address = usize;
+
+/// What an instruction changes besides which one runs next.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Registers {
+    pub accumulator: isize,
+}
+
+/// One entry in an instruction set: an opcode and what it does to the registers given its
+/// argument, returning how far to move from the current address.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    pub name: &'static str,
+    pub execute: fn(&mut Registers, isize) -> isize,
+    /// Whether the offset depends on the registers as well as the argument, like a jump that's
+    /// only taken while the accumulator is zero.
+    pub branches: bool,
+}
+
+/// The opcodes a program may use.
+#[derive(Clone, Debug)]
+pub struct InstructionSet {
+    operations: Vec<Operation>,
+}
+impl InstructionSet {
+    pub fn new() -> Self {
+        Self { operations: Vec::<Operation>::new() }
+    }
+    /// The console's own instructions: `nop`, `jmp` and `acc`.
+    pub fn handheld() -> Self {
+        Self::new()
+            .with("nop", |_, _| 1)
+            .with("jmp", |_, offset| offset)
+            .with("acc", |registers, value| { registers.accumulator += value; 1 })
+    }
+    /// Adds an opcode that always moves by the same offset given the same argument, replacing
+    /// any already named `name`.
+    pub fn with(self, name: &'static str, execute: fn(&mut Registers, isize) -> isize) -> Self {
+        self.add(Operation { name, execute, branches: false })
+    }
+    /// Adds an opcode whose offset depends on the registers, replacing any already named `name`.
+    pub fn with_branch(self, name: &'static str, execute: fn(&mut Registers, isize) -> isize) -> Self {
+        self.add(Operation { name, execute, branches: true })
+    }
+    fn add(mut self, operation: Operation) -> Self {
+        match self.opcode(operation.name) {
+            Some(opcode) => self.operations[opcode] = operation,
+            None => self.operations.push(operation),
+        }
+
+        self
+    }
+    pub fn opcode(&self, name: &str) -> Option<usize> {
+        self.operations.iter().position(|x| x.name == name)
+    }
+    pub fn operation(&self, opcode: usize) -> &Operation {
+        &self.operations[opcode]
+    }
+    /// Reads an instruction like `acc +1`.
+    pub fn read(&self, line: &Line) -> Result<Instruction, ParseError> {
+        let (name, argument) = line.split_once(line.text(), " ", "an instruction like acc +1")?;
+        let argument = line.parse::<isize>(argument, "a signed offset like +1 or -3")?;
+        let opcode = self.opcode(name).ok_or_else(|| line.error(name, &self.names()))?;
+
+        Ok(Instruction { opcode, argument })
+    }
+    /// Every opcode's name, listed like `nop, jmp or acc`.
+    fn names(&self) -> String {
+        let names: Vec<&str> = self.operations.iter().map(|x| x.name).collect();
+
+        match names.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            Some((last, _)) => last.to_string(),
+            None => "an instruction".to_string(),
+        }
+    }
+}
+impl Default for InstructionSet {
+    fn default() -> Self { Self::handheld() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Instruction {
+    /// The instruction's index in its `InstructionSet`.
+    pub opcode: usize,
+    pub argument: isize,
+}
+impl Instruction {
+    pub fn to_string(&self, set: &InstructionSet) -> String {
+        format!("{} {:+}", set.operation(self.opcode).name, self.argument)
+    }
+}
+
+/// The instructions that ran before execution came back to an address it had already been to.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct LoopReport {
+    /// The addresses of the cycle in the order they ran, starting with the one revisited.
+    pub cycle: Vec<Address>,
+    /// The accumulator before each instruction of the cycle ran.
+    pub accumulators: Vec<isize>,
+}
+
+/// How a run ended.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Outcome {
+    /// Execution moved to the address just past the last instruction.
+    Terminated,
+    /// Execution jumped somewhere else outside the program.
+    OutOfBounds(isize),
+    /// An instruction was about to run a second time, so the program would run forever. With
+    /// instructions that branch on the registers, that's only once the registers are the same
+    /// as the first time too, and a run that keeps changing them needs a step limit to end.
+    Loop(LoopReport),
+    /// The step limit was reached first.
+    StepLimit,
+}
+
+/// One instruction that ran, and the state it left behind.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Step {
+    pub address: Address,
+    pub instruction: Instruction,
+    pub registers: Registers,
+}
+impl Step {
+    pub fn to_string(&self, set: &InstructionSet) -> String {
+        format!("{:5}  {:10}acc={}", self.address, self.instruction.to_string(set), self.registers.accumulator)
+    }
+}
+
+/// Runs a program from address 0 until it ends, loops or runs out of steps.
+#[derive(Clone)]
+pub struct Machine<'a> {
+    set: &'a InstructionSet,
+    program: &'a [Instruction],
+    address: isize,
+    registers: Registers,
+    step_limit: Option<usize>,
+    tracing: bool,
+    trace: Vec<Step>,
+}
+impl<'a> Machine<'a> {
+    pub fn new(set: &'a InstructionSet, program: &'a [Instruction]) -> Self {
+        Self { set, program, address: 0, registers: Registers::default(), step_limit: None, tracing: false, trace: Vec::<Step>::new() }
+    }
+    /// Stops the run after this many instructions.
+    pub fn with_step_limit(mut self, limit: usize) -> Self {
+        self.step_limit = Some(limit);
+        self
+    }
+    /// Records every instruction that runs.
+    pub fn with_trace(mut self) -> Self {
+        self.tracing = true;
+        self
+    }
+    pub fn registers(&self) -> Registers { self.registers }
+    pub fn trace(&self) -> &Vec<Step> { &self.trace }
+    pub fn run(&mut self) -> Outcome {
+        // where each address, or each address and registers if control flow depends on them,
+        // first came up in `addresses`
+        let branches = self.program.iter().any(|x| self.set.operation(x.opcode).branches);
+        let mut first_visit = vec![None; self.program.len()];
+        let mut first_state = HashMap::<(Address, Registers), usize>::new();
+        let mut addresses = Vec::<Address>::new();
+        let mut accumulators = Vec::<isize>::new();
+
+        loop {
+            if self.address == self.program.len() as isize { return Outcome::Terminated; }
+            if self.address < 0 || self.address > self.program.len() as isize { return Outcome::OutOfBounds(self.address); }
+
+            let address = self.address as Address;
+
+            let visited = if branches { first_state.insert((address, self.registers), addresses.len()) }
+                          else { first_visit[address].replace(addresses.len()) };
+
+            if let Some(start) = visited {
+                return Outcome::Loop(LoopReport { cycle: addresses.split_off(start), accumulators: accumulators.split_off(start) });
+            }
+
+            if self.step_limit.is_some_and(|x| addresses.len() >= x) { return Outcome::StepLimit; }
+
+            addresses.push(address);
+            accumulators.push(self.registers.accumulator);
+
+            let instruction = self.program[address];
+            let offset = (self.set.operation(instruction.opcode).execute)(&mut self.registers, instruction.argument);
+
+            self.address += offset;
+
+            if self.tracing { self.trace.push(Step { address, instruction, registers: self.registers }); }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(set: &InstructionSet, text: &str) -> Vec<Instruction> {
+        text.lines().enumerate().map(|(i, x)| set.read(&Line::new(i+1, x)).unwrap()).collect()
+    }
+
+    #[test]
+    fn loop_report() {
+        let set = InstructionSet::handheld();
+        let program = program(&set, "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6");
+        let mut machine = Machine::new(&set, &program).with_trace();
+
+        assert_eq!(machine.run(), Outcome::Loop(LoopReport { cycle: vec![1, 2, 6, 7, 3, 4], accumulators: vec![0, 1, 1, 2, 2, 5] }));
+        assert_eq!(machine.registers().accumulator, 5);
+        assert_eq!(machine.trace().len(), 7);
+        assert_eq!(machine.trace()[3].to_string(&set), "    6  acc +1    acc=2");
+    }
+    #[test]
+    fn step_limits_and_bounds() {
+        let set = InstructionSet::handheld();
+        let program = program(&set, "acc +1\njmp +2\nacc +5\njmp -3");
+
+        assert_eq!(Machine::new(&set, &program).with_step_limit(2).run(), Outcome::StepLimit);
+        assert_eq!(Machine::new(&set, &program[..3]).run(), Outcome::Terminated);
+        assert_eq!(Machine::new(&set, &program[..2]).run(), Outcome::OutOfBounds(3));
+        assert_eq!(set.read(&Line::new(1, "hcf +1")).unwrap_err().to_string(), "line 1, column 1: expected nop, jmp or acc, found \"hcf\"");
+    }
+    #[test]
+    fn custom_instructions() {
+        // mul multiplies the accumulator, and jz jumps only while it's zero
+        let set = InstructionSet::handheld()
+            .with("mul", |registers, value| { registers.accumulator *= value; 1 })
+            .with_branch("jz", |registers, offset| if registers.accumulator == 0 { offset } else { 1 });
+        let program = program(&set, "jz +2\nmul +0\nacc +3\nmul -2\njz -4");
+        let mut machine = Machine::new(&set, &program);
+
+        assert_eq!(machine.run(), Outcome::Terminated);
+        assert_eq!(machine.registers().accumulator, -6);
+        assert_eq!(set.read(&Line::new(1, "div +2")).unwrap_err().to_string(), "line 1, column 1: expected nop, jmp, acc, mul or jz, found \"div\"");
+    }
+    #[test]
+    fn loops_that_depend_on_the_registers() {
+        let set = InstructionSet::handheld().with_branch("jz", |registers, offset| if registers.accumulator == 0 { offset } else { 1 });
+
+        // counts down from 3, coming back to the same addresses until the accumulator is zero
+        let countdown = program(&set, "acc +3\nacc -1\njz +2\njmp -2");
+        let mut machine = Machine::new(&set, &countdown);
+        assert_eq!(machine.run(), Outcome::Terminated);
+        assert_eq!(machine.registers().accumulator, 0);
+
+        let stuck = program(&set, "nop +0\njz +0");
+        assert_eq!(Machine::new(&set, &stuck).run(), Outcome::Loop(LoopReport { cycle: vec![1], accumulators: vec![0] }));
+
+        let counting_up = program(&set, "acc +1\njz +2\njmp -2");
+        assert_eq!(Machine::new(&set, &counting_up).with_step_limit(1000).run(), Outcome::StepLimit);
+    }
+}
@@ -0,0 +1,277 @@
+//! A rectangular grid of cells, for the puzzles that play out on a map.
+
+use common::{ParseError, Reader};
+use std::ops::{Index, IndexMut};
+
+/// A cell's position, column first, counting from the top left.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Coordinate(pub usize, pub usize);
+
+/// Which of the surrounding cells count as a cell's neighbors.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Neighborhood {
+    /// Up, down, left and right.
+    Four,
+    /// The four orthogonal cells plus the diagonals.
+    Eight,
+}
+impl Neighborhood {
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Self::Four => &[(0,-1), (-1,0), (1,0), (0,1)],
+            Self::Eight => &[(-1,-1), (0,-1), (1,-1),
+                             (-1,0),          (1,0),
+                             (-1,1),  (0,1),  (1,1)],
+        }
+    }
+}
+
+/// What lies past the edge of the grid: nothing, or the opposite side.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Edges {
+    Bounded,
+    Wrapping,
+    /// Wraps left to right, but the top and bottom are hard edges.
+    WrapX,
+    /// Wraps top to bottom, but the left and right are hard edges.
+    WrapY,
+}
+impl Edges {
+    fn wraps_x(&self) -> bool { *self == Self::Wrapping || *self == Self::WrapX }
+    fn wraps_y(&self) -> bool { *self == Self::Wrapping || *self == Self::WrapY }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    edges: Edges,
+}
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self { cells: vec![fill; width * height], width, height, edges: Edges::Bounded }
+    }
+}
+impl<T> Grid<T> {
+    pub fn from_fn<F: FnMut(Coordinate) -> T>(width: usize, height: usize, f: F) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coordinate(x,y)))
+            .map(f)
+            .collect();
+
+        Self { cells, width, height, edges: Edges::Bounded }
+    }
+    /// Builds a grid from its rows, or `None` if they aren't all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+
+        if rows.iter().any(|r| r.len() != width) { return None; }
+
+        let cells = rows.into_iter().flatten().collect();
+
+        Some(Self { cells, width, height, edges: Edges::Bounded })
+    }
+    /// Reads a grid of one character per cell, from the next line that isn't blank up to a
+    /// blank line or the end of the input. `expected` describes a valid cell for the error
+    /// when `from_char` rejects one.
+    pub fn read<F>(input: &mut Reader, expected: &str, from_char: F) -> Result<Self, ParseError>
+    where F: Fn(char) -> Option<T>
+    {
+        Ok(Self::from_rows(input.grid(expected, from_char)?).unwrap())
+    }
+    pub fn with_edges(mut self, edges: Edges) -> Self {
+        self.edges = edges;
+        self
+    }
+    pub fn edges(&self) -> Edges { self.edges }
+    pub fn width(&self) -> usize { self.width }
+    pub fn height(&self) -> usize { self.height }
+    pub fn len(&self) -> usize { self.cells.len() }
+    pub fn is_empty(&self) -> bool { self.cells.is_empty() }
+    pub fn contains(&self, coord: Coordinate) -> bool {
+        coord.0 < self.width && coord.1 < self.height
+    }
+    fn index_of(&self, coord: Coordinate) -> Option<usize> {
+        if self.contains(coord) { Some(coord.1 * self.width + coord.0) } else { None }
+    }
+    pub fn get(&self, coord: Coordinate) -> Option<&T> {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+    pub fn get_mut(&mut self, coord: Coordinate) -> Option<&mut T> {
+        self.index_of(coord).map(move |i| &mut self.cells[i])
+    }
+    pub fn set(&mut self, coord: Coordinate, value: T) {
+        self[coord] = value;
+    }
+    pub fn swap(&mut self, a: Coordinate, b: Coordinate) {
+        let (a, b) = (self.index_of(a).expect("coordinate out of bounds"),
+                      self.index_of(b).expect("coordinate out of bounds"));
+        self.cells.swap(a, b);
+    }
+    /// Turns a possibly out-of-range position into a coordinate, wrapping whichever axes the
+    /// grid's edges allow. `None` means the position falls off the grid.
+    pub fn resolve(&self, x: isize, y: isize) -> Option<Coordinate> {
+        let axis = |v: isize, size: usize, wraps: bool| {
+            if size == 0 { None }
+            else if wraps { Some(v.rem_euclid(size as isize) as usize) }
+            else if v >= 0 && (v as usize) < size { Some(v as usize) }
+            else { None }
+        };
+
+        Some(Coordinate(axis(x, self.width, self.edges.wraps_x())?,
+                        axis(y, self.height, self.edges.wraps_y())?))
+    }
+    pub fn offset(&self, coord: Coordinate, dx: isize, dy: isize) -> Option<Coordinate> {
+        self.resolve(coord.0 as isize + dx, coord.1 as isize + dy)
+    }
+    pub fn neighbors(&self, coord: Coordinate, neighborhood: Neighborhood) -> Vec<Coordinate> {
+        neighborhood.offsets()
+            .iter()
+            .filter_map(|&(dx, dy)| self.offset(coord, dx, dy))
+            .collect()
+    }
+    /// The cells in a straight line from `start` (not included), until the ray leaves the grid
+    /// or, on wrapping edges, comes back around to `start`.
+    pub fn ray(&self, start: Coordinate, dx: isize, dy: isize) -> Ray<'_, T> {
+        Ray { grid: self, start, current: start, delta: (dx, dy) }
+    }
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y+1) * self.width]
+    }
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        assert!(x < self.width, "column out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+    /// Every coordinate in reading order: left to right, top to bottom.
+    pub fn coordinates(&self) -> impl Iterator<Item=Coordinate> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coordinate(x,y)))
+    }
+    pub fn iter(&self) -> impl Iterator<Item=(Coordinate, &T)> {
+        self.coordinates().zip(self.cells.iter())
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item=(Coordinate, &mut T)> {
+        self.coordinates().zip(self.cells.iter_mut())
+    }
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height, edges: self.edges }
+    }
+    /// Draws the grid one character per cell, a line per row.
+    pub fn render<F: Fn(&T) -> char>(&self, to_char: F) -> String {
+        let mut result = String::with_capacity((self.width+1) * self.height);
+
+        for row in self.rows() {
+            result.extend(row.iter().map(&to_char));
+            result.push('\n');
+        }
+
+        result
+    }
+}
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coordinate) -> &T {
+        self.get(coord).expect("coordinate out of bounds")
+    }
+}
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, coord: Coordinate) -> &mut T {
+        self.get_mut(coord).expect("coordinate out of bounds")
+    }
+}
+
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    start: Coordinate,
+    current: Coordinate,
+    delta: (isize, isize),
+}
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = Coordinate;
+
+    fn next(&mut self) -> Option<Coordinate> {
+        if self.delta == (0,0) { return None; }
+
+        let next = self.grid.offset(self.current, self.delta.0, self.delta.1)?;
+
+        if next == self.start { return None; }
+
+        self.current = next;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#..\n.#.\n..#\n#.#\n";
+
+    fn read(s: &str) -> Result<Grid<bool>, ParseError> {
+        Grid::read(&mut Reader::new(s.as_bytes()), "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn read_and_render() {
+        let grid = read(EXAMPLE).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.render(|&b| if b { '#' } else { '.' }), EXAMPLE);
+        assert_eq!(read("\n#.\r\n.#  \n").unwrap().render(|&b| if b { '#' } else { '.' }), "#.\n.#\n");
+    }
+    #[test]
+    fn malformed_grids() {
+        assert_eq!(read("#.\n.x\n").unwrap_err().to_string(), "line 2, column 2: expected '#' or '.', found \"x\"");
+        assert_eq!(read("#.\n.\n").unwrap_err().to_string(), "line 2, column 1: expected a row 2 wide, found \".\"");
+        assert_eq!(read("\n").unwrap_err().to_string(), "line 2: input ended, expected a row of the grid");
+    }
+    #[test]
+    fn neighbors() {
+        let grid = read(EXAMPLE).unwrap();
+        assert_eq!(grid.neighbors(Coordinate(0,0), Neighborhood::Four), vec![Coordinate(1,0), Coordinate(0,1)]);
+        assert_eq!(grid.neighbors(Coordinate(1,1), Neighborhood::Eight).len(), 8);
+        assert_eq!(grid.neighbors(Coordinate(0,0), Neighborhood::Eight).len(), 3);
+
+        let grid = grid.with_edges(Edges::Wrapping);
+        assert_eq!(grid.neighbors(Coordinate(0,0), Neighborhood::Four),
+                   vec![Coordinate(0,3), Coordinate(2,0), Coordinate(1,0), Coordinate(0,1)]);
+        assert_eq!(grid.neighbors(Coordinate(0,0), Neighborhood::Eight).len(), 8);
+
+        let grid = grid.with_edges(Edges::WrapX);
+        assert_eq!(grid.offset(Coordinate(2,1), 1, 1), Some(Coordinate(0,2)));
+        assert_eq!(grid.offset(Coordinate(2,3), 1, 1), None);
+    }
+    #[test]
+    fn rays() {
+        let grid = read(EXAMPLE).unwrap();
+        assert_eq!(grid.ray(Coordinate(0,0), 1, 1).collect::<Vec<_>>(), vec![Coordinate(1,1), Coordinate(2,2)]);
+        assert_eq!(grid.ray(Coordinate(0,0), -1, 0).count(), 0);
+
+        let grid = grid.with_edges(Edges::Wrapping);
+        assert_eq!(grid.ray(Coordinate(0,0), 1, 0).collect::<Vec<_>>(), vec![Coordinate(1,0), Coordinate(2,0)]);
+    }
+    #[test]
+    fn rows_and_columns() {
+        let grid = read(EXAMPLE).unwrap();
+        assert_eq!(grid.row(3), &[true, false, true]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![false, false, true, true]);
+        assert_eq!(grid.columns().map(|c| c.filter(|&&b| b).count()).collect::<Vec<_>>(), vec![2, 1, 2]);
+        assert_eq!(grid.iter().filter(|(_, &b)| b).map(|(c, _)| c).last(), Some(Coordinate(2,3)));
+
+        let built = Grid::from_fn(3, 4, |c| grid[c]);
+        assert_eq!(built, grid);
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+}